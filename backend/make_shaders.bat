@@ -6,6 +6,6 @@ echo + build +
 set arg=%1
 if "%arg%"=="" set arg=run
 
-sokol-shdc.exe -i ./src/shaders.glsl -o ./src/shaders.rs --slang hlsl5:wgsl:glsl430 -f sokol_rust
+sokol-shdc.exe -i ./sandbox-viewer/src/shaders.glsl -o ./sandbox-viewer/src/shaders.rs --slang hlsl5:wgsl:glsl430:metal_macos -f sokol_rust
 
 cargo %arg%
@@ -0,0 +1,8 @@
+#!/bin/sh
+# regenerates the checked-in sandbox-viewer/src/shaders.rs, then runs `cargo <arg>` (run by default)
+set -e
+cd "$(dirname "$0")"
+
+${SOKOL_SHDC:-sokol-shdc} -i ./sandbox-viewer/src/shaders.glsl -o ./sandbox-viewer/src/shaders.rs --slang hlsl5:wgsl:glsl430:metal_macos -f sokol_rust
+
+cargo "${1:-run}"
@@ -0,0 +1,216 @@
+//! Controllers the plant can be closed around.
+
+use std::error::Error;
+use std::ffi::{CString, c_char};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use libloading::Library;
+
+use crate::simulation::{Control, State};
+
+/// Anything that maps a plant state to a control input.
+pub trait Controller: Send {
+    fn control(&mut self, state: &State) -> Control;
+
+    /// Overrides the tunable parameter `name` (see [`crate::tunables`]).
+    fn set_tunable(&mut self, _name: &str, _value: f32) {}
+}
+
+type ControllerFn = unsafe extern "C" fn(state: &State) -> Control;
+type SetTunableFn = unsafe extern "C" fn(name: *const c_char, value: f32);
+
+// numbers the libraries of the process, each compiles into a directory of its own
+static LIBRARIES: AtomicUsize = AtomicUsize::new(0);
+
+const SANDBOX_HEADER: &str = include_str!("../../../sandbox.h");
+// headless runs have nothing to show published values on, so the shared object gets its own
+// definitions of the host functions, with a private tunable table set through
+// `sandbox_set_tunable`
+const HOST_STUBS: &str = r#"#include <string.h>
+
+#define MAX_TUNABLES 64
+
+static struct {
+    char name[64];
+    float value;
+} tunables[MAX_TUNABLES];
+static int tunable_count = 0;
+
+static int find_tunable(const char *name) {
+    for (int i = 0; i < tunable_count; i++) {
+        if (strncmp(tunables[i].name, name, sizeof tunables[i].name - 1) == 0) {
+            return i;
+        }
+    }
+    if (tunable_count == MAX_TUNABLES) {
+        return -1;
+    }
+    strncpy(tunables[tunable_count].name, name, sizeof tunables[tunable_count].name - 1);
+    return tunable_count++;
+}
+
+void sandbox_publish(const char *name, float value) {}
+
+float sandbox_tunable(const char *name, float initial, float min, float max) {
+    int count = tunable_count;
+    int i = find_tunable(name);
+    if (i < 0) {
+        return initial;
+    }
+    if (i == count) {
+        tunables[i].value = initial;
+    }
+    return tunables[i].value;
+}
+
+void sandbox_set_tunable(const char *name, float value) {
+    int i = find_tunable(name);
+    if (i >= 0) {
+        tunables[i].value = value;
+    }
+}
+"#;
+
+/// A C controller compiled to a shared object that can be instantiated many times.
+///
+/// The C controller keeps its memory in `static` globals, so every instance is loaded from its
+/// own copy of the shared object to get a private set of them.
+pub struct ControllerLibrary {
+    number: usize,
+    directory: PathBuf,
+    library: PathBuf,
+    instances: AtomicUsize,
+}
+
+/// One loaded copy of a [`ControllerLibrary`].
+pub struct SharedObjectController {
+    entry: ControllerFn,
+    set_tunable: SetTunableFn,
+    _library: Library,
+    path: PathBuf,
+}
+
+impl ControllerLibrary {
+    /// Compiles `source` with `$CC` (or `clang`) into a temporary directory of its own.
+    pub fn compile(source: &Path) -> Result<ControllerLibrary, Box<dyn Error>> {
+        let number = LIBRARIES.fetch_add(1, Ordering::Relaxed);
+        let directory =
+            std::env::temp_dir().join(format!("control-sandbox-{}-{number}", process::id()));
+        fs::create_dir_all(&directory)?;
+        let library = directory.join(format!("controller{}", std::env::consts::DLL_SUFFIX));
+        let stubs = directory.join("sandbox_host.c");
+        fs::write(directory.join("sandbox.h"), SANDBOX_HEADER)?;
+        fs::write(&stubs, HOST_STUBS)?;
+        let compiler = std::env::var("CC").unwrap_or_else(|_| String::from("clang"));
+        let status = Command::new(&compiler)
+            .args(["-shared", "-fPIC", "-O2", "-I"])
+            .arg(&directory)
+            .arg("-o")
+            .arg(&library)
+            .arg(source)
+            .arg(&stubs)
+            .status()
+            .map_err(|err| format!("failed to run `{compiler}`: {err}"))?;
+        if !status.success() {
+            return Err(format!("`{compiler}` failed to compile {}", source.display()).into());
+        }
+        Ok(ControllerLibrary {
+            number,
+            directory,
+            library,
+            instances: AtomicUsize::new(0),
+        })
+    }
+
+    /// Loads a fresh copy of the library with its own `static` state.
+    pub fn instantiate(&self) -> Result<SharedObjectController, Box<dyn Error>> {
+        let index = self.instances.fetch_add(1, Ordering::Relaxed);
+        let path = self.directory.join(format!(
+            "controller_{}_{index}{}",
+            self.number,
+            std::env::consts::DLL_SUFFIX
+        ));
+        fs::copy(&self.library, &path)?;
+        unsafe {
+            let library = Library::new(&path)?;
+            let entry = *library.get::<ControllerFn>(b"controller")?;
+            let set_tunable = *library.get::<SetTunableFn>(b"sandbox_set_tunable")?;
+            Ok(SharedObjectController {
+                entry,
+                set_tunable,
+                _library: library,
+                path,
+            })
+        }
+    }
+}
+
+impl Drop for ControllerLibrary {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.directory);
+    }
+}
+
+impl Controller for SharedObjectController {
+    fn control(&mut self, state: &State) -> Control {
+        unsafe { (self.entry)(state) }
+    }
+
+    fn set_tunable(&mut self, name: &str, value: f32) {
+        let Ok(name) = CString::new(name) else {
+            return;
+        };
+        unsafe { (self.set_tunable)(name.as_ptr(), value) }
+    }
+}
+
+impl Drop for SharedObjectController {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a controller that always pushes with `force`
+    fn constant_controller(directory: &Path, name: &str, force: f32) -> PathBuf {
+        let source = directory.join(format!("{name}.c"));
+        fs::write(
+            &source,
+            format!(
+                "#include \"sandbox.h\"\n\
+                 Control controller(const State *state) {{ Control control = {{ {force:?}f }}; \
+                 return control; }}\n"
+            ),
+        )
+        .unwrap();
+        source
+    }
+
+    #[test]
+    fn libraries_keep_their_own_controllers() {
+        let directory =
+            std::env::temp_dir().join(format!("control-sandbox-test-{}", process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let one = ControllerLibrary::compile(&constant_controller(&directory, "one", 1.)).unwrap();
+        let two = ControllerLibrary::compile(&constant_controller(&directory, "two", 2.)).unwrap();
+        let mut first = one.instantiate().unwrap();
+        let mut second = two.instantiate().unwrap();
+        let state = State::default();
+        assert_eq!(first.control(&state).force, 1.);
+        assert_eq!(second.control(&state).force, 2.);
+
+        // dropping one library leaves the other's files alone
+        drop(first);
+        drop(one);
+        let mut third = two.instantiate().unwrap();
+        assert_eq!(second.control(&state).force, 2.);
+        assert_eq!(third.control(&state).force, 2.);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,208 @@
+//! Scoring a controller against the criteria of a scenario file.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::metrics::{self, Metrics, MetricsConfig};
+use crate::recording::Trajectory;
+use crate::simulation::{Simulation, State};
+
+/// Initial conditions, run length and grading criteria, loaded from JSON.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Scenario {
+    pub name: String,
+    pub initial_position: f32,
+    #[serde(default)]
+    pub initial_velocity: f32,
+    pub delta_time: f32,
+    pub duration: f32,
+    pub criteria: Vec<Criterion>,
+    #[serde(default)]
+    pub perturbation: Perturbation,
+    /// Controller parameters overridden for every run, see [`crate::tunables`].
+    #[serde(default)]
+    pub tunables: BTreeMap<String, f32>,
+    /// Camera the viewer starts with when it runs the scenario, ignored when grading.
+    #[serde(default)]
+    pub camera: Option<String>,
+}
+
+/// Ranges randomized over by Monte Carlo batches, `None` keeps the nominal value.
+#[derive(Default, Debug, Clone, Copy, Deserialize)]
+pub struct Perturbation {
+    pub initial_position: Option<[f32; 2]>,
+    pub initial_velocity: Option<[f32; 2]>,
+    pub control_gain: Option<[f32; 2]>,
+    pub bias_force: Option<[f32; 2]>,
+    #[serde(default)]
+    pub disturbance_amplitude: f32,
+}
+
+/// A graded requirement. Each earns `weight` points when met and partial credit otherwise.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Criterion {
+    SettlingTime { band: f32, max: f32, weight: f32 },
+    Overshoot { max_percent: f32, weight: f32 },
+    SteadyStateError { max: f32, weight: f32 },
+    Ise { max: f32, weight: f32 },
+    Itae { max: f32, weight: f32 },
+    ControlEffort { max: f32, weight: f32 },
+    Envelope { min: f32, max: f32, weight: f32 },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CriterionResult {
+    pub name: &'static str,
+    pub measured: f32,
+    pub limit: f32,
+    pub passed: bool,
+    pub points: f32,
+    pub weight: f32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub scenario: String,
+    pub seed: u64,
+    pub results: Vec<CriterionResult>,
+    pub metrics: Metrics,
+    pub score: f32,
+    pub max_score: f32,
+}
+
+impl Scenario {
+    pub fn load(path: &Path) -> Result<Scenario, Box<dyn Error>> {
+        let text = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// A simulation at the scenario's initial conditions.
+    pub fn simulation(&self) -> Simulation {
+        Simulation {
+            state: State {
+                position: self.initial_position,
+                velocity: self.initial_velocity,
+                delta_time: self.delta_time,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    pub fn grade_trajectory(&self, trajectory: &Trajectory) -> Report {
+        let metrics = Metrics::compute(trajectory, &MetricsConfig::default());
+        let results: Vec<CriterionResult> = self
+            .criteria
+            .iter()
+            .map(|criterion| criterion.evaluate(trajectory, &metrics))
+            .collect();
+        Report {
+            scenario: self.name.clone(),
+            seed: trajectory.seed,
+            metrics,
+            score: results.iter().map(|result| result.points).sum(),
+            max_score: results.iter().map(|result| result.weight).sum(),
+            results,
+        }
+    }
+}
+
+impl Criterion {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Criterion::SettlingTime { .. } => "settling time",
+            Criterion::Overshoot { .. } => "overshoot",
+            Criterion::SteadyStateError { .. } => "steady-state error",
+            Criterion::Ise { .. } => "ise",
+            Criterion::Itae { .. } => "itae",
+            Criterion::ControlEffort { .. } => "control effort",
+            Criterion::Envelope { .. } => "envelope",
+        }
+    }
+
+    pub fn evaluate(&self, trajectory: &Trajectory, metrics: &Metrics) -> CriterionResult {
+        let (measured, limit, weight) = match *self {
+            Criterion::SettlingTime { band, max, weight } => (
+                metrics::settling_time(trajectory, band).unwrap_or(f32::INFINITY),
+                max,
+                weight,
+            ),
+            Criterion::Overshoot {
+                max_percent,
+                weight,
+            } => (metrics.overshoot_percent, max_percent, weight),
+            Criterion::SteadyStateError { max, weight } => {
+                (metrics.steady_state_error, max, weight)
+            }
+            Criterion::Ise { max, weight } => (metrics.ise, max, weight),
+            Criterion::Itae { max, weight } => (metrics.itae, max, weight),
+            Criterion::ControlEffort { max, weight } => (metrics.control_energy, max, weight),
+            Criterion::Envelope { min, max, weight } => {
+                let outside = trajectory
+                    .samples
+                    .iter()
+                    .filter(|sample| sample.position < min || sample.position > max)
+                    .count();
+                let fraction = outside as f32 / trajectory.samples.len().max(1) as f32;
+                let points = weight * (1. - fraction);
+                return CriterionResult {
+                    name: self.name(),
+                    measured: fraction,
+                    limit: 0.,
+                    passed: outside == 0,
+                    points,
+                    weight,
+                };
+            }
+        };
+
+        let passed = measured <= limit;
+        let points = if passed {
+            weight
+        } else if measured.is_finite() && measured > 0. {
+            weight * (limit / measured).clamp(0., 1.)
+        } else {
+            0.
+        };
+        CriterionResult {
+            name: self.name(),
+            measured,
+            limit,
+            passed,
+            points,
+            weight,
+        }
+    }
+}
+
+impl Report {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("report serialization failure")
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "scenario: {} (seed {})", self.scenario, self.seed)?;
+        for result in &self.results {
+            writeln!(
+                f,
+                "  [{}] {:<20} measured {:>12.4}  limit {:>12.4}  {:>6.2} / {:.2}",
+                if result.passed { "pass" } else { "FAIL" },
+                result.name,
+                result.measured,
+                result.limit,
+                result.points,
+                result.weight,
+            )?;
+        }
+        writeln!(f, "metrics: {}", self.metrics)?;
+        write!(f, "score: {:.2} / {:.2}", self.score, self.max_score)
+    }
+}
@@ -0,0 +1,37 @@
+//! Running a controller against the plant without a window.
+
+use crate::recording::Trajectory;
+use crate::simulation::{Control, Simulation, State};
+
+/// Steps `simulation` for `duration` seconds, querying `controller` every tick.
+pub fn run<F>(simulation: &mut Simulation, duration: f32, controller: F) -> Trajectory
+where
+    F: FnMut(&State) -> Control,
+{
+    run_disturbed(simulation, duration, controller, |_| 0.)
+}
+
+/// Like [`run`], with `disturbance` supplying an external force every tick.
+pub fn run_disturbed<F, D>(
+    simulation: &mut Simulation,
+    duration: f32,
+    mut controller: F,
+    mut disturbance: D,
+) -> Trajectory
+where
+    F: FnMut(&State) -> Control,
+    D: FnMut(&State) -> f32,
+{
+    let steps = (duration / simulation.state.delta_time).ceil() as usize;
+    let mut trajectory = Trajectory {
+        samples: Vec::with_capacity(steps),
+        ..Default::default()
+    };
+    for _ in 0..steps {
+        simulation.control = controller(&simulation.state);
+        simulation.disturbance = disturbance(&simulation.state);
+        trajectory.record(simulation, 0.);
+        simulation.step();
+    }
+    trajectory
+}
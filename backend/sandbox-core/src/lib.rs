@@ -0,0 +1,28 @@
+//! Simulation core of the control sandbox.
+//!
+//! Everything that does not need a window lives here: the plant and its integrator, loading
+//! student controllers, recording trajectories, computing performance metrics, grading runs
+//! against a scenario and Monte Carlo batches. The `sandbox-viewer` binary renders on top of it.
+//!
+//! ```no_run
+//! use sandbox_core::grading::Scenario;
+//! use sandbox_core::headless;
+//! use sandbox_core::simulation::Control;
+//!
+//! let scenario = Scenario::load("scenarios/step_response.json".as_ref()).unwrap();
+//! let mut simulation = scenario.simulation();
+//! let trajectory = headless::run(&mut simulation, scenario.duration, |state| Control {
+//!     force: -state.position,
+//! });
+//! println!("{}", scenario.grade_trajectory(&trajectory));
+//! ```
+
+pub mod controllers;
+pub mod grading;
+pub mod headless;
+pub mod metrics;
+pub mod montecarlo;
+pub mod recording;
+pub mod rng;
+pub mod simulation;
+pub mod tunables;
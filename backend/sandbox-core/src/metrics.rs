@@ -0,0 +1,412 @@
+//! Step-response performance metrics computed from a [`Trajectory`].
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::recording::{Sample, Trajectory};
+
+#[derive(Debug, Clone, Copy)]
+pub struct MetricsConfig {
+    /// Settling band as a fraction of the initial error.
+    pub settling_band: f32,
+    /// Fractions of the step that bound the rise time.
+    pub rise_low: f32,
+    pub rise_high: f32,
+    /// Trailing fraction of the run averaged for the steady-state error.
+    pub steady_state_window: f32,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> MetricsConfig {
+        MetricsConfig {
+            settling_band: 0.02,
+            rise_low: 0.1,
+            rise_high: 0.9,
+            steady_state_window: 0.05,
+        }
+    }
+}
+
+/// Summary of a run. Times are `None` when the response never reaches them.
+#[derive(Default, Debug, Clone, Copy, Serialize)]
+pub struct Metrics {
+    pub rise_time: Option<f32>,
+    pub settling_time: Option<f32>,
+    pub overshoot_percent: f32,
+    pub peak_time: Option<f32>,
+    pub steady_state_error: f32,
+    pub ise: f32,
+    pub iae: f32,
+    pub itae: f32,
+    pub control_energy: f32,
+}
+
+impl Metrics {
+    pub fn compute(trajectory: &Trajectory, config: &MetricsConfig) -> Metrics {
+        Metrics {
+            rise_time: rise_time(trajectory, config.rise_low, config.rise_high),
+            settling_time: settling_time(trajectory, config.settling_band),
+            overshoot_percent: overshoot_percent(trajectory),
+            peak_time: peak_time(trajectory),
+            steady_state_error: steady_state_error(trajectory, config.steady_state_window),
+            ise: integrate(trajectory, |_, error, _| error * error),
+            iae: integrate(trajectory, |_, error, _| error.abs()),
+            itae: integrate(trajectory, |time, error, _| time * error.abs()),
+            control_energy: integrate(trajectory, |_, _, force| force * force),
+        }
+    }
+}
+
+/// [`Metrics`] of a run that is still going, updated one sample at a time for as little as a
+/// live readout can afford every frame.
+#[derive(Default, Debug, Clone)]
+pub struct LiveMetrics {
+    config: MetricsConfig,
+    initial_error: f32,
+    previous: Option<Sample>,
+    rise_start: Option<f32>,
+    rise_end: Option<f32>,
+    settled_at: Option<f32>,
+    overshoot_percent: f32,
+    // time and progress of the largest excursion past the setpoint
+    peak: Option<(f32, f32)>,
+    // sums of the absolute error up to each sample, starting from an empty run, so any trailing
+    // window is a difference of two
+    absolute_error_sums: Vec<f64>,
+    ise: f32,
+    iae: f32,
+    itae: f32,
+    control_energy: f32,
+}
+
+impl LiveMetrics {
+    pub fn new(config: MetricsConfig) -> LiveMetrics {
+        LiveMetrics {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Takes the next sample of the run, in the order [`Trajectory::record`] appends them.
+    pub fn push(&mut self, sample: &Sample) {
+        let error = sample.setpoint - sample.position;
+        if self.previous.is_none() {
+            self.initial_error = error;
+            self.settled_at = Some(sample.time);
+            self.absolute_error_sums.push(0.);
+        }
+        let fraction = if self.initial_error == 0. {
+            1.
+        } else {
+            1. - error / self.initial_error
+        };
+        if fraction >= self.config.rise_low {
+            self.rise_start.get_or_insert(sample.time);
+        }
+        if fraction >= self.config.rise_high {
+            self.rise_end.get_or_insert(sample.time);
+        }
+        if error.abs() > self.config.settling_band * self.initial_error.abs() {
+            self.settled_at = None;
+        } else {
+            self.settled_at.get_or_insert(sample.time);
+        }
+        self.overshoot_percent = self.overshoot_percent.max((fraction - 1.) * 100.);
+        if fraction > 1.
+            && self
+                .peak
+                .is_none_or(|(_, peak)| fraction.total_cmp(&peak).is_ge())
+        {
+            self.peak = Some((sample.time, fraction));
+        }
+        let sum = self.absolute_error_sums.last().copied().unwrap_or_default();
+        self.absolute_error_sums.push(sum + error.abs() as f64);
+
+        // the same rectangles as `integrate`, each closed once the next sample gives its width
+        if let Some(previous) = self.previous {
+            let delta_time = sample.time - previous.time;
+            let error = previous.setpoint - previous.position;
+            self.ise += error * error * delta_time;
+            self.iae += error.abs() * delta_time;
+            self.itae += previous.time * error.abs() * delta_time;
+            self.control_energy += previous.force * previous.force * delta_time;
+        }
+        self.previous = Some(*sample);
+    }
+
+    pub fn metrics(&self) -> Metrics {
+        let samples = self.absolute_error_sums.len().saturating_sub(1);
+        let count = ((samples as f32 * self.config.steady_state_window).ceil() as usize).max(1);
+        let steady_state_error = if samples == 0 {
+            0.
+        } else {
+            let count = count.min(samples);
+            let tail =
+                self.absolute_error_sums[samples] - self.absolute_error_sums[samples - count];
+            (tail / count as f64) as f32
+        };
+        Metrics {
+            rise_time: self
+                .rise_start
+                .zip(self.rise_end)
+                .map(|(start, end)| end - start),
+            settling_time: self.settled_at,
+            overshoot_percent: self.overshoot_percent,
+            peak_time: self.peak.map(|(time, _)| time),
+            steady_state_error,
+            ise: self.ise,
+            iae: self.iae,
+            itae: self.itae,
+            control_energy: self.control_energy,
+        }
+    }
+}
+
+fn initial_error(trajectory: &Trajectory) -> f32 {
+    trajectory.errors().next().map_or(0., |(_, error)| error)
+}
+
+// fraction of the initial error that has been removed, 1 at the setpoint and above 1 past it
+fn progress(trajectory: &Trajectory) -> impl Iterator<Item = (f32, f32)> + '_ {
+    let initial = initial_error(trajectory);
+    trajectory.errors().map(move |(time, error)| {
+        if initial == 0. {
+            (time, 1.)
+        } else {
+            (time, 1. - error / initial)
+        }
+    })
+}
+
+/// Time from `low` to `high` of the way from the initial position to the setpoint.
+pub fn rise_time(trajectory: &Trajectory, low: f32, high: f32) -> Option<f32> {
+    let start = progress(trajectory)
+        .find(|&(_, fraction)| fraction >= low)?
+        .0;
+    let end = progress(trajectory)
+        .find(|&(_, fraction)| fraction >= high)?
+        .0;
+    Some(end - start)
+}
+
+/// Time after which the error stays within `band` of the initial error.
+pub fn settling_time(trajectory: &Trajectory, band: f32) -> Option<f32> {
+    let tolerance = band * initial_error(trajectory).abs();
+    let mut settled_at = trajectory.samples.first()?.time;
+    let mut settled = true;
+    for (time, error) in trajectory.errors() {
+        if error.abs() > tolerance {
+            settled = false;
+        } else if !settled {
+            settled = true;
+            settled_at = time;
+        }
+    }
+    settled.then_some(settled_at)
+}
+
+/// Largest excursion past the setpoint as a percentage of the initial error.
+pub fn overshoot_percent(trajectory: &Trajectory) -> f32 {
+    progress(trajectory)
+        .map(|(_, fraction)| (fraction - 1.) * 100.)
+        .fold(0., f32::max)
+}
+
+/// Time of the largest excursion past the setpoint.
+pub fn peak_time(trajectory: &Trajectory) -> Option<f32> {
+    progress(trajectory)
+        .filter(|&(_, fraction)| fraction > 1.)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(time, _)| time)
+}
+
+/// Mean absolute error over the trailing `window` fraction of the run.
+pub fn steady_state_error(trajectory: &Trajectory, window: f32) -> f32 {
+    let count = ((trajectory.samples.len() as f32 * window).ceil() as usize).max(1);
+    let tail = &trajectory.samples[trajectory.samples.len().saturating_sub(count)..];
+    if tail.is_empty() {
+        return 0.;
+    }
+    tail.iter()
+        .map(|sample| (sample.setpoint - sample.position).abs())
+        .sum::<f32>()
+        / tail.len() as f32
+}
+
+fn integrate<F>(trajectory: &Trajectory, integrand: F) -> f32
+where
+    F: Fn(f32, f32, f32) -> f32,
+{
+    trajectory
+        .samples
+        .windows(2)
+        .map(|pair| {
+            let delta_time = pair[1].time - pair[0].time;
+            let error = pair[0].setpoint - pair[0].position;
+            integrand(pair[0].time, error, pair[0].force) * delta_time
+        })
+        .sum()
+}
+
+struct Seconds(Option<f32>);
+
+impl fmt::Display for Seconds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(seconds) => write!(f, "{seconds:.2}s"),
+            None => write!(f, "--"),
+        }
+    }
+}
+
+impl fmt::Display for Metrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "rise {}  settle {}  overshoot {:.1}%  peak {}  sse {:.3}  ise {:.1}  iae {:.1}  itae {:.1}  energy {:.1}",
+            Seconds(self.rise_time),
+            Seconds(self.settling_time),
+            self.overshoot_percent,
+            Seconds(self.peak_time),
+            self.steady_state_error,
+            self.ise,
+            self.iae,
+            self.itae,
+            self.control_energy,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recording::Sample;
+
+    // one sample a second holding `setpoint`, pushed with a constant `force`
+    fn response(setpoint: f32, positions: &[f32], force: f32) -> Trajectory {
+        Trajectory {
+            seed: 0,
+            samples: positions
+                .iter()
+                .enumerate()
+                .map(|(tick, &position)| Sample {
+                    time: tick as f32,
+                    position,
+                    setpoint,
+                    force,
+                    ..Default::default()
+                })
+                .collect(),
+        }
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-3,
+            "{actual} is not {expected}"
+        );
+    }
+
+    // a step of 10 up to 3 that overshoots by 2 at 4s and settles 0.1 past it at 6s
+    fn step() -> Trajectory {
+        let errors = [10., 8., 5., 0.5, -2., -1., -0.1, -0.1, -0.1, -0.1];
+        let positions: Vec<f32> = errors.iter().map(|error| 3. - error).collect();
+        response(3., &positions, 2.)
+    }
+
+    #[test]
+    fn step_response() {
+        let metrics = Metrics::compute(&step(), &MetricsConfig::default());
+        // 10% removed at 1s, 90% at 3s
+        assert_eq!(metrics.rise_time, Some(2.));
+        assert_eq!(metrics.settling_time, Some(6.));
+        assert_close(metrics.overshoot_percent, 20.);
+        assert_eq!(metrics.peak_time, Some(4.));
+        // the last 5% of ten samples is the last one
+        assert_close(metrics.steady_state_error, 0.1);
+        // rectangles from each sample to the next
+        assert_close(metrics.ise, 100. + 64. + 25. + 0.25 + 4. + 1. + 3. * 0.01);
+        assert_close(metrics.iae, 10. + 8. + 5. + 0.5 + 2. + 1. + 3. * 0.1);
+        assert_close(
+            metrics.itae,
+            8. + 10. + 1.5 + 8. + 5. + (6. + 7. + 8.) * 0.1,
+        );
+        assert_close(metrics.control_energy, 9. * 4.);
+    }
+
+    #[test]
+    fn never_reaching_the_band() {
+        let metrics = Metrics::compute(
+            &response(0., &[-10., -9., -8., -7.], 0.),
+            &MetricsConfig::default(),
+        );
+        assert_eq!(metrics.rise_time, None);
+        assert_eq!(metrics.settling_time, None);
+        assert_eq!(metrics.overshoot_percent, 0.);
+        assert_eq!(metrics.peak_time, None);
+        assert_close(metrics.steady_state_error, 7.);
+    }
+
+    #[test]
+    fn zero_step() {
+        // starting on a zero setpoint there is nothing to rise to or overshoot
+        let metrics = Metrics::compute(&response(0., &[0.; 5], 0.), &MetricsConfig::default());
+        assert_eq!(metrics.rise_time, Some(0.));
+        assert_eq!(metrics.settling_time, Some(0.));
+        assert_eq!(metrics.overshoot_percent, 0.);
+        assert_eq!(metrics.peak_time, None);
+        assert_eq!(metrics.steady_state_error, 0.);
+        assert_eq!(metrics.ise, 0.);
+        assert_eq!(metrics.iae, 0.);
+    }
+
+    // every metric of `trajectory` once more, one sample at a time
+    fn assert_live_matches(trajectory: &Trajectory) {
+        let config = MetricsConfig::default();
+        let mut live = LiveMetrics::new(config);
+        for sample in &trajectory.samples {
+            live.push(sample);
+        }
+        let (live, full) = (live.metrics(), Metrics::compute(trajectory, &config));
+        assert_eq!(live.rise_time, full.rise_time);
+        assert_eq!(live.settling_time, full.settling_time);
+        assert_eq!(live.overshoot_percent, full.overshoot_percent);
+        assert_eq!(live.peak_time, full.peak_time);
+        assert_close(live.steady_state_error, full.steady_state_error);
+        assert_close(live.ise, full.ise);
+        assert_close(live.iae, full.iae);
+        assert_close(live.itae, full.itae);
+        assert_close(live.control_energy, full.control_energy);
+    }
+
+    #[test]
+    fn live_metrics_match_the_whole_run() {
+        assert_live_matches(&step());
+        assert_live_matches(&response(0., &[-10., -9., -8., -7.], 0.));
+        assert_live_matches(&response(0., &[0.; 5], 0.));
+        assert_live_matches(&Trajectory::default());
+        // every prefix of the step, as the readout sees it while the run goes on
+        let step = step();
+        for length in 1..step.samples.len() {
+            assert_live_matches(&Trajectory {
+                seed: 0,
+                samples: step.samples[..length].to_vec(),
+            });
+        }
+    }
+
+    #[test]
+    fn empty_trajectory() {
+        let metrics = Metrics::compute(&Trajectory::default(), &MetricsConfig::default());
+        assert_eq!(metrics.rise_time, None);
+        assert_eq!(metrics.settling_time, None);
+        assert_eq!(metrics.overshoot_percent, 0.);
+        assert_eq!(metrics.peak_time, None);
+        assert_eq!(metrics.steady_state_error, 0.);
+        assert_eq!(metrics.ise, 0.);
+        assert_eq!(metrics.iae, 0.);
+        assert_eq!(metrics.itae, 0.);
+        assert_eq!(metrics.control_energy, 0.);
+    }
+}
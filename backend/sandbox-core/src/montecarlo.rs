@@ -0,0 +1,274 @@
+//! Batches of randomized runs of one controller.
+
+use std::error::Error;
+use std::fmt;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use rand::Rng;
+use serde::Serialize;
+
+use crate::controllers::{Controller, ControllerLibrary};
+use crate::grading::{Report, Scenario};
+use crate::headless;
+use crate::recording::Trajectory;
+use crate::rng::{RngService, Subsystem};
+
+/// Everything needed to reproduce a single run of a batch.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct RunParameters {
+    pub index: usize,
+    pub seed: u64,
+    pub initial_position: f32,
+    pub initial_velocity: f32,
+    pub control_gain: f32,
+    pub bias_force: f32,
+    pub disturbance_amplitude: f32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunResult {
+    pub parameters: RunParameters,
+    pub report: Report,
+    pub passed: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Distribution {
+    pub min: f32,
+    pub mean: f32,
+    pub p50: f32,
+    pub p95: f32,
+    pub max: f32,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchReport {
+    pub scenario: String,
+    pub runs: usize,
+    pub seed: u64,
+    pub success_rate: f32,
+    pub score: Option<Distribution>,
+    pub settling_time: Option<Distribution>,
+    pub overshoot_percent: Option<Distribution>,
+    pub steady_state_error: Option<Distribution>,
+    pub itae: Option<Distribution>,
+    pub control_energy: Option<Distribution>,
+    pub worst: Option<RunResult>,
+}
+
+impl RunParameters {
+    /// Nominal initial conditions and plant, keeping only the seeded disturbance.
+    pub fn nominal(scenario: &Scenario, rng: &RngService) -> RunParameters {
+        RunParameters {
+            index: 0,
+            seed: rng.seed(),
+            initial_position: scenario.initial_position,
+            initial_velocity: scenario.initial_velocity,
+            control_gain: 1.,
+            bias_force: 0.,
+            disturbance_amplitude: scenario.perturbation.disturbance_amplitude,
+        }
+    }
+
+    pub fn sample(scenario: &Scenario, rng: &RngService, index: usize) -> RunParameters {
+        let perturbation = &scenario.perturbation;
+        let mut initial = rng.stream(Subsystem::InitialConditions, index);
+        let mut plant = rng.stream(Subsystem::PlantParameters, index);
+        let pick = |rng: &mut rand_chacha::ChaCha8Rng, range: Option<[f32; 2]>, nominal: f32| {
+            match range {
+                Some([low, high]) if high > low => rng.random_range(low..high),
+                _ => nominal,
+            }
+        };
+        RunParameters {
+            index,
+            seed: rng.seed(),
+            initial_position: pick(
+                &mut initial,
+                perturbation.initial_position,
+                scenario.initial_position,
+            ),
+            initial_velocity: pick(
+                &mut initial,
+                perturbation.initial_velocity,
+                scenario.initial_velocity,
+            ),
+            control_gain: pick(&mut plant, perturbation.control_gain, 1.),
+            bias_force: pick(&mut plant, perturbation.bias_force, 0.),
+            disturbance_amplitude: perturbation.disturbance_amplitude,
+        }
+    }
+
+    pub fn run(&self, scenario: &Scenario, controller: &mut dyn Controller) -> RunResult {
+        let trajectory = self.simulate(scenario, controller);
+        let report = scenario.grade_trajectory(&trajectory);
+        RunResult {
+            parameters: *self,
+            passed: report.results.iter().all(|result| result.passed),
+            report,
+        }
+    }
+
+    pub fn simulate(&self, scenario: &Scenario, controller: &mut dyn Controller) -> Trajectory {
+        let mut simulation = scenario.simulation();
+        simulation.state.position = self.initial_position;
+        simulation.state.velocity = self.initial_velocity;
+        simulation.plant.control_gain = self.control_gain;
+        simulation.plant.bias_force = self.bias_force;
+        for (name, value) in &scenario.tunables {
+            controller.set_tunable(name, *value);
+        }
+
+        let mut noise = RngService::new(self.seed).stream(Subsystem::Disturbance, self.index);
+        let amplitude = self.disturbance_amplitude;
+        let mut trajectory = headless::run_disturbed(
+            &mut simulation,
+            scenario.duration,
+            |state| controller.control(state),
+            |_| {
+                if amplitude > 0. {
+                    noise.random_range(-amplitude..amplitude)
+                } else {
+                    0.
+                }
+            },
+        );
+        trajectory.seed = self.seed;
+        trajectory
+    }
+}
+
+/// Runs `runs` randomized copies of `scenario` across `threads` threads.
+pub fn run_batch(
+    scenario: &Scenario,
+    library: &ControllerLibrary,
+    runs: usize,
+    rng: &RngService,
+    threads: usize,
+) -> Result<BatchReport, Box<dyn Error>> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(runs));
+    let failure = Mutex::new(None);
+
+    thread::scope(|scope| {
+        for _ in 0..threads.max(1) {
+            scope.spawn(|| {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    if index >= runs {
+                        break;
+                    }
+                    let mut controller = match library.instantiate() {
+                        Ok(controller) => controller,
+                        Err(err) => {
+                            *failure.lock().unwrap() = Some(err.to_string());
+                            next.store(runs, Ordering::Relaxed);
+                            break;
+                        }
+                    };
+                    let result =
+                        RunParameters::sample(scenario, rng, index).run(scenario, &mut controller);
+                    results.lock().unwrap().push(result);
+                }
+            });
+        }
+    });
+
+    if let Some(err) = failure.into_inner().unwrap() {
+        return Err(err.into());
+    }
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|result| result.parameters.index);
+    Ok(BatchReport::summarize(scenario, rng.seed(), results))
+}
+
+impl Distribution {
+    pub fn from_values(mut values: Vec<f32>) -> Option<Distribution> {
+        values.retain(|value| value.is_finite());
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_by(f32::total_cmp);
+        let percentile = |p: f32| values[((values.len() - 1) as f32 * p).round() as usize];
+        Some(Distribution {
+            min: values[0],
+            mean: values.iter().sum::<f32>() / values.len() as f32,
+            p50: percentile(0.5),
+            p95: percentile(0.95),
+            max: values[values.len() - 1],
+            count: values.len(),
+        })
+    }
+}
+
+impl BatchReport {
+    fn summarize(scenario: &Scenario, seed: u64, results: Vec<RunResult>) -> BatchReport {
+        let collect = |metric: fn(&RunResult) -> Option<f32>| {
+            Distribution::from_values(results.iter().filter_map(metric).collect())
+        };
+        let passed = results.iter().filter(|result| result.passed).count();
+        BatchReport {
+            scenario: scenario.name.clone(),
+            runs: results.len(),
+            seed,
+            success_rate: passed as f32 / results.len().max(1) as f32,
+            score: collect(|result| Some(result.report.score)),
+            settling_time: collect(|result| result.report.metrics.settling_time),
+            overshoot_percent: collect(|result| Some(result.report.metrics.overshoot_percent)),
+            steady_state_error: collect(|result| Some(result.report.metrics.steady_state_error)),
+            itae: collect(|result| Some(result.report.metrics.itae)),
+            control_energy: collect(|result| Some(result.report.metrics.control_energy)),
+            worst: results
+                .iter()
+                .min_by(|a, b| a.report.score.total_cmp(&b.report.score))
+                .cloned(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("report serialization failure")
+    }
+}
+
+struct Row<'a>(&'a str, &'a Option<Distribution>);
+
+impl fmt::Display for Row<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.1 {
+            Some(d) => write!(
+                f,
+                "  {:<20} min {:>10.3}  mean {:>10.3}  p50 {:>10.3}  p95 {:>10.3}  max {:>10.3}  (n={})",
+                self.0, d.min, d.mean, d.p50, d.p95, d.max, d.count
+            ),
+            None => write!(f, "  {:<20} --", self.0),
+        }
+    }
+}
+
+impl fmt::Display for BatchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "scenario: {} ({} runs, seed {})",
+            self.scenario, self.runs, self.seed
+        )?;
+        writeln!(f, "success rate: {:.1}%", self.success_rate * 100.)?;
+        writeln!(f, "{}", Row("score", &self.score))?;
+        writeln!(f, "{}", Row("settling time", &self.settling_time))?;
+        writeln!(f, "{}", Row("overshoot %", &self.overshoot_percent))?;
+        writeln!(f, "{}", Row("steady-state error", &self.steady_state_error))?;
+        writeln!(f, "{}", Row("itae", &self.itae))?;
+        write!(f, "{}", Row("control energy", &self.control_energy))?;
+        if let Some(worst) = &self.worst {
+            write!(
+                f,
+                "\nworst case: run {} score {:.2}\n{:#?}",
+                worst.parameters.index, worst.report.score, worst.parameters
+            )?;
+        }
+        Ok(())
+    }
+}
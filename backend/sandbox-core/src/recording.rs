@@ -0,0 +1,68 @@
+//! Time histories of a simulation run.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use crate::simulation::Simulation;
+
+/// Snapshot of the plant and control input at one tick.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct Sample {
+    pub time: f32,
+    pub position: f32,
+    pub velocity: f32,
+    pub acceleration: f32,
+    pub setpoint: f32,
+    pub force: f32,
+}
+
+/// Samples of a run in tick order.
+#[derive(Default, Debug, Clone)]
+pub struct Trajectory {
+    /// Master seed of the run, written to the header of every export.
+    pub seed: u64,
+    pub samples: Vec<Sample>,
+}
+
+impl Trajectory {
+    /// Appends the current state of `simulation` tracking `setpoint`.
+    pub fn record(&mut self, simulation: &Simulation, setpoint: f32) {
+        let state = &simulation.state;
+        self.samples.push(Sample {
+            time: state.tick as f32 * state.delta_time,
+            position: state.position,
+            velocity: state.velocity,
+            acceleration: state.acceleration,
+            setpoint,
+            force: simulation.control.force,
+        });
+    }
+
+    /// `(time, setpoint - position)` for every sample.
+    pub fn errors(&self) -> impl Iterator<Item = (f32, f32)> + '_ {
+        self.samples
+            .iter()
+            .map(|sample| (sample.time, sample.setpoint - sample.position))
+    }
+
+    /// Writes the samples as CSV after a `# seed` comment and a header row.
+    pub fn write_csv(&self, path: &Path) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(writer, "# seed: {}", self.seed)?;
+        writeln!(writer, "time,position,velocity,acceleration,setpoint,force")?;
+        for sample in &self.samples {
+            writeln!(
+                writer,
+                "{},{},{},{},{},{}",
+                sample.time,
+                sample.position,
+                sample.velocity,
+                sample.acceleration,
+                sample.setpoint,
+                sample.force
+            )?;
+        }
+        writer.flush()
+    }
+}
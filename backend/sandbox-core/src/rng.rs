@@ -0,0 +1,58 @@
+//! Seeded random number streams shared by every stochastic part of the sandbox.
+//!
+//! A run is fully determined by one master seed. Each subsystem draws from its own stream so
+//! adding draws to one (say, more turbulence samples) never shifts the numbers another sees,
+//! and each Monte Carlo case gets its own set of streams so results do not depend on which
+//! thread picked the case up.
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Subsystem {
+    InitialConditions,
+    PlantParameters,
+    Disturbance,
+    SensorNoise,
+    Turbulence,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RngService {
+    seed: u64,
+}
+
+impl Subsystem {
+    fn id(self) -> u64 {
+        match self {
+            Subsystem::InitialConditions => 0,
+            Subsystem::PlantParameters => 1,
+            Subsystem::Disturbance => 2,
+            Subsystem::SensorNoise => 3,
+            Subsystem::Turbulence => 4,
+        }
+    }
+}
+
+impl RngService {
+    pub fn new(seed: u64) -> RngService {
+        RngService { seed }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Independent stream for `subsystem` in Monte Carlo case `case`, 0 for single runs.
+    pub fn stream(&self, subsystem: Subsystem, case: usize) -> ChaCha8Rng {
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+        rng.set_stream(((case as u64) << 8) | subsystem.id());
+        rng
+    }
+}
+
+impl Default for RngService {
+    fn default() -> RngService {
+        RngService::new(0)
+    }
+}
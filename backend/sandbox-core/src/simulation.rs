@@ -1,23 +1,28 @@
+//! The plant being controlled and the integrator that advances it.
+
 use std::ops::{Add, Mul};
 
+/// A state that can report its time derivative under a given control input.
 pub trait Differentiable {
     fn derivative(&self, other: &Control) -> Self;
 }
 
+/// Classic fourth order Runge-Kutta integrator over a [`State`].
 pub struct RungeKutta4 {
     integrand: State,
     delta_time: f32,
 }
 
 impl RungeKutta4 {
-    fn build(state: State, delta_time: f32) -> RungeKutta4 {
+    pub fn build(state: State, delta_time: f32) -> RungeKutta4 {
         RungeKutta4 {
             integrand: state,
             delta_time,
         }
     }
 
-    fn step(&mut self, impulse: &Control) {
+    /// Advances the integrand by one `delta_time` holding `impulse` constant over the step.
+    pub fn step(&mut self, impulse: &Control) {
         let k1 = self.integrand.derivative(impulse);
         let k2 = (self.integrand + k1 * (self.delta_time / 2.)).derivative(impulse);
         let k3 = (self.integrand + k2 * (self.delta_time / 2.)).derivative(impulse);
@@ -29,8 +34,14 @@ impl RungeKutta4 {
 
         self.integrand = self.integrand + increment;
     }
+
+    pub fn integrand(&self) -> State {
+        self.integrand
+    }
 }
 
+/// Plant state, shared with the C controller by pointer so the layout is `repr(C)` and matches
+/// `State` in `sandbox.h`.
 #[repr(C)]
 #[derive(Default, Debug, Clone, Copy)]
 pub struct State {
@@ -41,6 +52,13 @@ pub struct State {
     pub delta_time: f32,
 }
 
+// the same offsets `sandbox.h` asserts on the C side
+const _: () = assert!(
+    std::mem::offset_of!(State, tick) == 12
+        && std::mem::offset_of!(State, delta_time) == 16
+        && std::mem::size_of::<State>() == 20
+);
+
 impl Differentiable for State {
     fn derivative(&self, control: &Control) -> State {
         State {
@@ -81,23 +99,52 @@ impl Mul<f32> for State {
     }
 }
 
+/// Controller output, returned by value from the C controller.
 #[repr(C)]
 #[derive(Default, Debug)]
 pub struct Control {
     pub force: f32,
 }
 
+/// Physical parameters of the plant, perturbed by Monte Carlo batches.
+#[derive(Debug, Clone, Copy)]
+pub struct PlantParameters {
+    /// Scale applied to the commanded force before it reaches the plant.
+    pub control_gain: f32,
+    /// Constant force added on top of the commanded one.
+    pub bias_force: f32,
+}
+
+impl Default for PlantParameters {
+    fn default() -> PlantParameters {
+        PlantParameters {
+            control_gain: 1.,
+            bias_force: 0.,
+        }
+    }
+}
+
+/// A plant together with the control input currently applied to it.
 #[derive(Default, Debug)]
 pub struct Simulation {
     pub state: State,
     pub control: Control,
+    pub plant: PlantParameters,
+    /// External force acting on the plant for the next step.
+    pub disturbance: f32,
 }
 
 impl Simulation {
+    /// Integrates the plant over one `state.delta_time` and advances the tick counter.
     pub fn step(&mut self) {
+        let applied = Control {
+            force: self.control.force * self.plant.control_gain
+                + self.plant.bias_force
+                + self.disturbance,
+        };
         let mut rk4 = RungeKutta4::build(self.state, self.state.delta_time);
-        rk4.step(&self.control);
-        self.state = rk4.integrand;
+        rk4.step(&applied);
+        self.state = rk4.integrand();
         self.state.tick += 1;
     }
 }
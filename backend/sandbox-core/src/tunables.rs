@@ -0,0 +1,63 @@
+//! Named controller parameters that can be changed without recompiling.
+//!
+//! A controller asks for each parameter every tick through `sandbox_tunable` (see `sandbox.h`),
+//! passing its default and range. The first call registers it, later calls return whatever the
+//! host has set since, from a scenario file, the command line or a slider in the viewer.
+
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tunable {
+    pub name: String,
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+}
+
+/// Registered parameters in registration order.
+#[derive(Debug, Clone)]
+pub struct Tunables {
+    pub entries: Vec<Tunable>,
+    // values set before the controller registered the parameter
+    pending: BTreeMap<String, f32>,
+}
+
+impl Tunables {
+    pub const fn new() -> Tunables {
+        Tunables {
+            entries: Vec::new(),
+            pending: BTreeMap::new(),
+        }
+    }
+
+    /// Current value of `name`, registering it with `initial` on first use.
+    pub fn register(&mut self, name: &str, initial: f32, min: f32, max: f32) -> f32 {
+        if let Some(tunable) = self.entries.iter().find(|tunable| tunable.name == name) {
+            return tunable.value;
+        }
+        let value = self.pending.remove(name).unwrap_or(initial);
+        self.entries.push(Tunable {
+            name: name.to_string(),
+            value,
+            min,
+            max,
+        });
+        value
+    }
+
+    /// Overrides `name`, taking effect at the controller's next call even if not registered yet.
+    pub fn set(&mut self, name: &str, value: f32) {
+        match self.entries.iter_mut().find(|tunable| tunable.name == name) {
+            Some(tunable) => tunable.value = value,
+            None => {
+                self.pending.insert(name.to_string(), value);
+            }
+        }
+    }
+}
+
+impl Default for Tunables {
+    fn default() -> Tunables {
+        Tunables::new()
+    }
+}
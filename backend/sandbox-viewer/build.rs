@@ -0,0 +1,58 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-changed=../../controller.c");
+    println!("cargo:rerun-if-changed=../../sandbox.h");
+    cc::Build::new()
+        .file("../../controller.c")
+        .include("../..")
+        .compiler("clang")
+        .compile("controller");
+
+    shaders();
+}
+
+// compiles src/shaders.glsl with sokol-shdc, found through `SOKOL_SHDC` or on the PATH, into
+// $OUT_DIR/shaders.rs, with a warning when the checked-in src/shaders.rs differs from it. without
+// it the checked-in copy is used as it is. that copy is only ever rewritten by make_shaders.sh or
+// make_shaders.bat
+fn shaders() {
+    println!("cargo:rerun-if-changed=src/shaders.glsl");
+    println!("cargo:rerun-if-changed=src/shaders.rs");
+    println!("cargo:rerun-if-env-changed=SOKOL_SHDC");
+    let shdc = env::var_os("SOKOL_SHDC").unwrap_or("sokol-shdc".into());
+    let generated = Path::new(&env::var_os("OUT_DIR").unwrap()).join("shaders.rs");
+    // run from the workspace with the arguments of the make_shaders scripts, the command line ends
+    // up in the header of the output
+    let output = Command::new(&shdc)
+        .current_dir("..")
+        .args(["-i", "./sandbox-viewer/src/shaders.glsl", "-o"])
+        .arg(&generated)
+        .args(["--slang", "hlsl5:wgsl:glsl430:metal_macos", "-f", "sokol_rust"])
+        .output();
+    let shaders = match output {
+        Ok(output) if output.status.success() => {
+            let shaders = fs::read_to_string(&generated)
+                .unwrap()
+                .replace(&generated.display().to_string(), "./sandbox-viewer/src/shaders.rs");
+            if fs::read_to_string("src/shaders.rs").ok().as_deref() != Some(shaders.as_str()) {
+                println!(
+                    "cargo:warning=src/shaders.rs is out of date with src/shaders.glsl, \
+                     regenerate it with make_shaders.sh or make_shaders.bat"
+                );
+            }
+            shaders
+        }
+        Ok(output) => panic!(
+            "sokol-shdc failed on src/shaders.glsl\n{}{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        ),
+        Err(_) => fs::read_to_string("src/shaders.rs").unwrap(),
+    };
+    // included into the `shaders` module of main.rs, which takes no inner attributes
+    fs::write(&generated, shaders.replacen("#![allow(dead_code)]", "", 1)).unwrap();
+}
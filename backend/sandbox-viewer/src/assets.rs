@@ -0,0 +1,101 @@
+use std::env;
+use std::path::{Path, PathBuf};
+
+use image::RgbaImage;
+
+use crate::ground;
+use crate::model::Model;
+use crate::surfaces::ControlSurfaces;
+
+// a directory searched before all others, overriding `--assets`
+pub const ASSET_DIR_VAR: &str = "SANDBOX_ASSETS";
+
+// finds the files the viewer ships with, such as `vendor/f35/f35.obj`, wherever it is started from.
+// relative paths are looked up in `$SANDBOX_ASSETS`, the `--assets` directory, the working
+// directory and then the executable's directory and each one above it, so a `cargo run` from
+// anywhere in the repository finds `backend/vendor`
+pub struct Assets {
+    directories: Vec<PathBuf>,
+}
+
+// what the aircraft and the ground are drawn from, with placeholders standing in for whatever
+// could not be loaded
+pub struct Scene {
+    pub model: Model,
+    pub surfaces: ControlSurfaces,
+    pub grid: RgbaImage,
+    // one line per missing or broken asset, shown on screen
+    pub warnings: Vec<String>,
+}
+
+impl Assets {
+    pub fn new(configured: Option<&Path>) -> Assets {
+        let mut directories: Vec<PathBuf> = env::var_os(ASSET_DIR_VAR)
+            .map(PathBuf::from)
+            .into_iter()
+            .chain(configured.map(Path::to_path_buf))
+            .collect();
+        directories.extend(env::current_dir());
+        if let Ok(executable) = env::current_exe() {
+            directories.extend(executable.ancestors().skip(1).map(Path::to_path_buf));
+        }
+        Assets { directories }
+    }
+
+    // the first directory holding `path`, absolute paths are taken as they are
+    pub fn resolve(&self, path: &Path) -> Result<PathBuf, String> {
+        self.directories
+            .iter()
+            .map(|directory| directory.join(path))
+            .find(|candidate| candidate.exists())
+            .ok_or_else(|| format!("{} not found", path.display()))
+    }
+
+    // never fails, a missing model becomes `Model::placeholder` and a missing grid a plain checker
+    pub fn load_scene(&self, model_path: &Path) -> Scene {
+        let mut warnings = Vec::new();
+        let mut warn = |warning: String| {
+            eprintln!("warning: {warning}");
+            warnings.push(warning);
+        };
+        let (model, surfaces) =
+            match self
+                .resolve(model_path)
+                .and_then(|path| match Model::load(&path) {
+                    Ok(model) => Ok((model, path)),
+                    Err(err) => Err(format!("{}: {err}", path.display())),
+                }) {
+                Ok((model, path)) => {
+                    let surfaces = ControlSurfaces::load(&path, &model).unwrap_or_else(|err| {
+                        warn(format!("control surfaces left still: {err}"));
+                        ControlSurfaces::default()
+                    });
+                    (model, surfaces)
+                }
+                Err(err) => {
+                    warn(format!("no model, drawing a cube: {err}"));
+                    (Model::placeholder(), ControlSurfaces::default())
+                }
+            };
+        let grid = match self.resolve(Path::new(ground::GRID_PATH)).and_then(|path| {
+            image::open(path)
+                .map(|image| image.to_rgba8())
+                .map_err(|err| err.to_string())
+        }) {
+            Ok(grid) => grid,
+            Err(err) => {
+                warn(format!("no ground grid: {err}"));
+                ground::placeholder_grid()
+            }
+        };
+        if !warnings.is_empty() {
+            eprintln!("set {ASSET_DIR_VAR} or --assets to the directory holding `vendor`");
+        }
+        Scene {
+            model,
+            surfaces,
+            grid,
+            warnings,
+        }
+    }
+}
@@ -4,10 +4,72 @@ use glam as glm;
 
 use sokol::app as sap;
 
-use crate::{HEIGHT, WIDTH};
+use crate::ground;
+use crate::{HEIGHT, Transform, WIDTH};
+
+// chase camera offset behind and above the aircraft, in world units
+const CHASE_DISTANCE: f32 = 22.;
+const CHASE_HEIGHT: f32 = 5.;
+// how quickly the chase camera catches up, per second
+const CHASE_STIFFNESS: f32 = 4.;
+const ORBIT_DISTANCE: f32 = 25.;
+const ORBIT_MIN_DISTANCE: f32 = 5.;
+const ORBIT_MAX_DISTANCE: f32 = 80.;
+// eye point in the model's body frame, just above the canopy so the mesh doesn't block the view
+const COCKPIT_EYE: glm::Vec3 = glm::Vec3::new(4.5, 1.95, 0.);
+// a ground observer off to the side and ahead of the flight path
+const TOWER_POSITION: glm::Vec3 = glm::Vec3::new(30., ground::HEIGHT + 1.8, -35.);
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CameraMode {
+    // WASD free flight
+    #[default]
+    Free,
+    // smoothed, behind the aircraft
+    Chase,
+    // around the aircraft with the mouse, W/S zoom
+    Orbit,
+    // attached to the aircraft's body frame
+    Cockpit,
+    // fixed on the ground, tracking the aircraft
+    Tower,
+}
+
+impl CameraMode {
+    pub const ALL: [CameraMode; 5] = [
+        CameraMode::Free,
+        CameraMode::Chase,
+        CameraMode::Orbit,
+        CameraMode::Cockpit,
+        CameraMode::Tower,
+    ];
+
+    pub fn parse(name: &str) -> Option<CameraMode> {
+        CameraMode::ALL.into_iter().find(|mode| mode.name() == name)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            CameraMode::Free => "free",
+            CameraMode::Chase => "chase",
+            CameraMode::Orbit => "orbit",
+            CameraMode::Cockpit => "cockpit",
+            CameraMode::Tower => "tower",
+        }
+    }
+
+    fn next(self) -> CameraMode {
+        let index = CameraMode::ALL
+            .iter()
+            .position(|mode| *mode == self)
+            .unwrap_or(0);
+        CameraMode::ALL[(index + 1) % CameraMode::ALL.len()]
+    }
+}
 
 #[derive(Default, Debug)]
 pub struct Camera {
+    pub mode: CameraMode,
     pub position: glm::Vec3,
 
     pub front: glm::Vec3,
@@ -24,6 +86,10 @@ pub struct Camera {
     pub yaw: f32,
     pub pitch: f32,
 
+    pub orbit_yaw: f32,
+    pub orbit_pitch: f32,
+    pub orbit_distance: f32,
+
     pub fov: f32,
     pub aspect_ratio: f32,
     pub near: f32,
@@ -40,6 +106,10 @@ impl Camera {
             up: glm::Vec3::new(0., 1., 0.),
             yaw: PI / 2.,
 
+            orbit_yaw: -PI / 2.,
+            orbit_pitch: 0.3,
+            orbit_distance: ORBIT_DISTANCE,
+
             mouse_sensitivity: 0.25,
             move_speed: 2.5,
 
@@ -74,12 +144,118 @@ impl Camera {
         glm::Mat4::perspective_rh_gl(self.fov, self.aspect_ratio, self.near, self.far)
     }
 
-    pub fn update(&mut self, inputs: &mut Inputs, delta_time: f32) {
+    pub fn set_mode(&mut self, mode: CameraMode) {
+        if mode == CameraMode::Free {
+            // carry on flying from wherever the last mode left the camera
+            self.yaw = self.front.z.atan2(self.front.x);
+            self.pitch = self.front.y.clamp(-1., 1.).asin();
+            self.velocity = glm::Vec3::ZERO;
+        }
+        self.mode = mode;
+    }
+
+    // points the camera at `target` from where it is, keeping the world up
+    fn look_at(&mut self, target: glm::Vec3) {
+        self.front = (target - self.position).normalize_or(self.front);
+        self.right = self.front.cross(self.world_up).normalize_or(glm::Vec3::X);
+        self.up = self.right.cross(self.front).normalize();
+    }
+
+    // `target` is the aircraft, followed by every mode but the free one
+    pub fn update(&mut self, inputs: &mut Inputs, delta_time: f32, target: &Transform) {
+        if inputs.take_press(sap::Keycode::C) {
+            self.set_mode(self.mode.next());
+        }
         let dmouse = inputs.mouse_delta / 200. * self.mouse_sensitivity;
+        inputs.mouse_delta = glm::Vec2::ZERO;
+        match self.mode {
+            CameraMode::Free => self.fly(inputs, dmouse, delta_time),
+            CameraMode::Orbit => {
+                self.orbit_yaw += dmouse.x;
+                self.orbit_pitch =
+                    (self.orbit_pitch + dmouse.y).clamp(-PI / 2. + 0.1, PI / 2. - 0.1);
+                if inputs.keys_active[sap::Keycode::W as usize] {
+                    self.orbit_distance -= self.move_speed * 4. * delta_time;
+                }
+                if inputs.keys_active[sap::Keycode::S as usize] {
+                    self.orbit_distance += self.move_speed * 4. * delta_time;
+                }
+                self.orbit_distance = self
+                    .orbit_distance
+                    .clamp(ORBIT_MIN_DISTANCE, ORBIT_MAX_DISTANCE);
+                self.follow(target, delta_time);
+            }
+            _ => self.follow(target, delta_time),
+        }
+
+        if inputs.keys_active[sap::Keycode::L as usize]
+            && !inputs.is_key_processed(sap::Keycode::L as usize)
+        {
+            sap::lock_mouse(!sap::mouse_locked());
+            inputs.set_key_processed(sap::Keycode::L as usize, true);
+        }
+        if inputs.keys_active[sap::Keycode::O as usize]
+            && !inputs.is_key_processed(sap::Keycode::O as usize)
+        {
+            sap::toggle_fullscreen();
+            inputs.set_key_processed(sap::Keycode::O as usize, true);
+        }
+        if inputs.keys_active[sap::Keycode::Escape as usize] {
+            sap::request_quit();
+        }
+
+        if inputs.keys_active[sap::Keycode::Equal as usize] {
+            self.fov += 0.02;
+        }
+        if inputs.keys_active[sap::Keycode::Minus as usize] {
+            self.fov -= 0.02;
+        }
+
+        if inputs.window_event {
+            inputs.window_event = false;
+            self.aspect_ratio = sap::widthf() / sap::heightf();
+        }
+    }
+
+    // places the camera for every mode but the free one around `target`, the aircraft. an
+    // infinite `delta_time` settles the chase camera at once
+    pub fn follow(&mut self, target: &Transform, delta_time: f32) {
+        match self.mode {
+            CameraMode::Free => {}
+            CameraMode::Chase => {
+                let forward = target.rotation * glm::Vec3::X;
+                let desired =
+                    target.position - forward * CHASE_DISTANCE + self.world_up * CHASE_HEIGHT;
+                self.position +=
+                    (desired - self.position) * (1. - (-CHASE_STIFFNESS * delta_time).exp());
+                self.look_at(target.position);
+            }
+            CameraMode::Orbit => {
+                let offset = glm::Vec3::new(
+                    self.orbit_yaw.cos() * self.orbit_pitch.cos(),
+                    self.orbit_pitch.sin(),
+                    self.orbit_yaw.sin() * self.orbit_pitch.cos(),
+                );
+                self.position = target.position + offset * self.orbit_distance;
+                self.look_at(target.position);
+            }
+            CameraMode::Cockpit => {
+                self.position = target.to_matrix().transform_point3(COCKPIT_EYE);
+                self.front = target.rotation * glm::Vec3::X;
+                self.up = target.rotation * glm::Vec3::Y;
+                self.right = target.rotation * glm::Vec3::Z;
+            }
+            CameraMode::Tower => {
+                self.position = TOWER_POSITION;
+                self.look_at(target.position);
+            }
+        }
+    }
+
+    fn fly(&mut self, inputs: &Inputs, dmouse: glm::Vec2, delta_time: f32) {
         self.yaw += dmouse.x;
         self.pitch -= dmouse.y;
         self.pitch = self.pitch.clamp(-PI / 2. + 0.5, PI / 2. - 0.5);
-        inputs.mouse_delta = glm::Vec2::ZERO;
         self.update_vectors();
 
         let right = self.right;
@@ -125,34 +301,6 @@ impl Camera {
             }
         }
         self.position += self.velocity * delta_time;
-
-        if inputs.keys_active[sap::Keycode::L as usize]
-            && !inputs.is_key_processed(sap::Keycode::L as usize)
-        {
-            sap::lock_mouse(!sap::mouse_locked());
-            inputs.set_key_processed(sap::Keycode::L as usize, true);
-        }
-        if inputs.keys_active[sap::Keycode::O as usize]
-            && !inputs.is_key_processed(sap::Keycode::O as usize)
-        {
-            sap::toggle_fullscreen();
-            inputs.set_key_processed(sap::Keycode::O as usize, true);
-        }
-        if inputs.keys_active[sap::Keycode::Escape as usize] {
-            sap::request_quit();
-        }
-
-        if inputs.keys_active[sap::Keycode::Equal as usize] {
-            self.fov += 0.02;
-        }
-        if inputs.keys_active[sap::Keycode::Minus as usize] {
-            self.fov -= 0.02;
-        }
-
-        if inputs.window_event {
-            inputs.window_event = false;
-            self.aspect_ratio = sap::widthf() / sap::heightf();
-        }
     }
 }
 
@@ -209,6 +357,16 @@ impl Inputs {
     pub fn is_key_processed(&self, key: usize) -> bool {
         self.keys_processed[key]
     }
+
+    // true once per key press, for toggles
+    pub fn take_press(&mut self, key: sap::Keycode) -> bool {
+        let key = key as usize;
+        if self.keys_active[key] && !self.is_key_processed(key) {
+            self.set_key_processed(key, true);
+            return true;
+        }
+        false
+    }
 }
 
 impl Default for Inputs {
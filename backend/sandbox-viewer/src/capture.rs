@@ -0,0 +1,391 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Sender};
+use std::thread::{self, JoinHandle};
+
+use image::RgbaImage;
+use sokol::app as sap;
+use sokol::gfx;
+use sokol::gl as sgl;
+use sokol::glue;
+
+use crate::camera::Inputs;
+use crate::readback;
+
+// screenshots and recordings started from the keyboard land here
+const DIRECTORY: &str = "captures";
+// targets with these extensions are piped to the encoder instead of written as png files
+const VIDEO_EXTENSIONS: [&str; 4] = ["mp4", "mkv", "mov", "webm"];
+const ENCODER: &str = "ffmpeg";
+// frame rate of recordings started from the keyboard, `--fps` for `--record`
+pub const DEFAULT_FPS: f32 = 30.;
+// sokol-gl layer of the quad that shows a captured frame in the window, apart from the frame's own
+const PRESENT_LAYER: i32 = 1;
+
+// while capturing, the frame is drawn into these instead of the swapchain, since only an image
+// of its own can be read back, and then shown in the window from there
+#[derive(Default)]
+struct Target {
+    width: i32,
+    height: i32,
+    color: gfx::Image,
+    // the single sampled copy of a multisampled `color`, the one read back and shown
+    resolve: Option<gfx::Image>,
+    depth: gfx::Image,
+    attachments: gfx::Attachments,
+    sampler: gfx::Sampler,
+}
+
+impl Target {
+    // made in the formats of the swapchain so every pipeline draws into it as it is
+    fn fit(&mut self, width: i32, height: i32) {
+        if (self.width, self.height) == (width, height) {
+            return;
+        }
+        self.destroy();
+        let defaults = glue::environment().defaults;
+        let image = |pixel_format, sample_count, label: &'static std::ffi::CStr| {
+            gfx::make_image(&gfx::ImageDesc {
+                render_target: true,
+                width,
+                height,
+                pixel_format,
+                sample_count,
+                label: label.as_ptr(),
+                ..Default::default()
+            })
+        };
+        self.color = image(
+            defaults.color_format,
+            defaults.sample_count,
+            c"capture color",
+        );
+        self.resolve = (defaults.sample_count > 1)
+            .then(|| image(defaults.color_format, 1, c"capture resolve"));
+        self.depth = image(
+            defaults.depth_format,
+            defaults.sample_count,
+            c"capture depth",
+        );
+        let mut attachments = gfx::AttachmentsDesc::default();
+        attachments.colors[0].image = self.color;
+        if let Some(resolve) = self.resolve {
+            attachments.resolves[0].image = resolve;
+        }
+        attachments.depth_stencil.image = self.depth;
+        self.attachments = gfx::make_attachments(&attachments);
+        self.sampler = gfx::make_sampler(&gfx::SamplerDesc {
+            min_filter: gfx::Filter::Nearest,
+            mag_filter: gfx::Filter::Nearest,
+            wrap_u: gfx::Wrap::ClampToEdge,
+            wrap_v: gfx::Wrap::ClampToEdge,
+            ..Default::default()
+        });
+        (self.width, self.height) = (width, height);
+    }
+
+    fn destroy(&mut self) {
+        if (self.width, self.height) == (0, 0) {
+            return;
+        }
+        gfx::destroy_attachments(self.attachments);
+        for image in [self.color, self.depth].into_iter().chain(self.resolve) {
+            gfx::destroy_image(image);
+        }
+        gfx::destroy_sampler(self.sampler);
+        *self = Target::default();
+    }
+
+    // the finished frame, single sampled
+    fn image(&self) -> gfx::Image {
+        self.resolve.unwrap_or(self.color)
+    }
+}
+
+struct Writer {
+    sender: Sender<RgbaImage>,
+    thread: JoinHandle<io::Result<()>>,
+    width: u32,
+    height: u32,
+}
+
+// a png sequence or a video being written, frames are encoded off the render thread
+struct Recording {
+    target: PathBuf,
+    fps: f32,
+    writer: Option<Writer>,
+    frames: usize,
+}
+
+// F12 saves a screenshot, F10 starts and stops recording the view
+#[derive(Default)]
+pub struct Capture {
+    screenshot: bool,
+    recording: Option<Recording>,
+    // `--record` stops the recording and quits after this many frames
+    pub frame_limit: Option<usize>,
+    target: Target,
+    // the current frame is being drawn into `target`
+    drawing: bool,
+}
+
+impl Capture {
+    pub fn handle_inputs(&mut self, inputs: &mut Inputs) {
+        if inputs.take_press(sap::Keycode::F12) {
+            self.screenshot = true;
+        }
+        if inputs.take_press(sap::Keycode::F10) {
+            if self.recording.is_some() {
+                self.stop();
+            } else {
+                let target = unused_path(&format!("{DIRECTORY}/frames"), "");
+                self.start(target, DEFAULT_FPS);
+            }
+        }
+    }
+
+    // `target` is a directory for a png sequence, or a video file encoded at `fps`
+    pub fn start(&mut self, target: PathBuf, fps: f32) {
+        self.stop();
+        println!("recording to {}", target.display());
+        self.recording = Some(Recording {
+            target,
+            fps,
+            writer: None,
+            frames: 0,
+        });
+    }
+
+    pub fn recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    pub fn stop(&mut self) {
+        if let Some(recording) = self.recording.take() {
+            recording.finish();
+        }
+    }
+
+    // gives up on the recording, `--record` has nothing left to do then
+    fn abort(&mut self) {
+        self.stop();
+        if self.frame_limit.is_some() {
+            sap::request_quit();
+        }
+    }
+
+    // where the frame is drawn with `action`: the swapchain, or the capture target when a
+    // screenshot or a recording wants this frame
+    pub fn pass(&mut self, action: gfx::PassAction) -> gfx::Pass {
+        self.drawing = self.screenshot || self.recording.is_some();
+        if !self.drawing {
+            return gfx::Pass {
+                action,
+                swapchain: glue::swapchain(),
+                ..Default::default()
+            };
+        }
+        self.target.fit(sap::width(), sap::height());
+        gfx::Pass {
+            action,
+            attachments: self.target.attachments,
+            ..Default::default()
+        }
+    }
+
+    // call once the frame is drawn into the pass of `pass`. a captured frame is copied into the
+    // swapchain, whose pass is left open in its place
+    pub fn present(&mut self) {
+        if !self.drawing {
+            return;
+        }
+        gfx::end_pass();
+        let mut action = gfx::PassAction::default();
+        action.colors[0].load_action = gfx::LoadAction::Dontcare;
+        action.depth.load_action = gfx::LoadAction::Dontcare;
+        gfx::begin_pass(&gfx::Pass {
+            action,
+            swapchain: glue::swapchain(),
+            ..Default::default()
+        });
+        // gl puts the first row of a render target at the bottom
+        let (top, bottom) = if gfx::query_features().origin_top_left {
+            (0., 1.)
+        } else {
+            (1., 0.)
+        };
+        sgl::layer(PRESENT_LAYER);
+        sgl::defaults();
+        sgl::enable_texture();
+        sgl::texture(self.target.image(), self.target.sampler);
+        sgl::matrix_mode_projection();
+        sgl::ortho(0., 1., 1., 0., -1., 1.);
+        sgl::begin_quads();
+        sgl::v2f_t2f(0., 0., 0., top);
+        sgl::v2f_t2f(1., 0., 1., top);
+        sgl::v2f_t2f(1., 1., 1., bottom);
+        sgl::v2f_t2f(0., 1., 0., bottom);
+        sgl::end();
+        sgl::draw_layer(PRESENT_LAYER);
+        sgl::layer(0);
+    }
+
+    // reads back a captured frame and saves or records it, call after `gfx::commit`
+    pub fn capture(&mut self) {
+        if !std::mem::take(&mut self.drawing) {
+            return;
+        }
+        let (width, height) = (self.target.width as u32, self.target.height as u32);
+        let format = glue::environment().defaults.color_format;
+        let Some(frame) = readback::read(self.target.image(), width, height, format) else {
+            eprintln!(
+                "frames cannot be read back from the {:?} backend",
+                gfx::query_backend()
+            );
+            self.screenshot = false;
+            self.abort();
+            return;
+        };
+        if std::mem::take(&mut self.screenshot) {
+            let path = unused_path(&format!("{DIRECTORY}/screenshot"), ".png");
+            match save_png(&path, &frame) {
+                Ok(()) => println!("saved {}", path.display()),
+                Err(err) => eprintln!("failed to save {}: {err}", path.display()),
+            }
+        }
+        if let Some(recording) = &mut self.recording {
+            if let Err(err) = recording.push(frame) {
+                eprintln!("stopped recording {}: {err}", recording.target.display());
+                self.abort();
+                return;
+            }
+            if self
+                .frame_limit
+                .is_some_and(|limit| recording.frames >= limit)
+            {
+                self.stop();
+                sap::request_quit();
+            }
+        }
+    }
+}
+
+impl Drop for Capture {
+    fn drop(&mut self) {
+        self.stop();
+        self.target.destroy();
+    }
+}
+
+impl Recording {
+    fn push(&mut self, frame: RgbaImage) -> io::Result<()> {
+        let writer = match &mut self.writer {
+            Some(writer) => writer,
+            None => self.writer.insert(spawn_writer(
+                &self.target,
+                frame.width(),
+                frame.height(),
+                self.fps,
+            )?),
+        };
+        // a video cannot change size halfway through
+        if frame.dimensions() != (writer.width, writer.height) {
+            return Err(io::Error::other("the window was resized"));
+        }
+        writer
+            .sender
+            .send(frame)
+            .map_err(|_| io::Error::other("the writer stopped"))?;
+        self.frames += 1;
+        Ok(())
+    }
+
+    fn finish(self) {
+        let Some(writer) = self.writer else {
+            return;
+        };
+        // closing the channel lets the writer drain and exit
+        drop(writer.sender);
+        match writer.thread.join() {
+            Ok(Ok(())) => println!("wrote {} frames to {}", self.frames, self.target.display()),
+            Ok(Err(err)) => eprintln!("failed to write {}: {err}", self.target.display()),
+            Err(_) => eprintln!(
+                "failed to write {}: the writer panicked",
+                self.target.display()
+            ),
+        }
+    }
+}
+
+fn spawn_writer(target: &Path, width: u32, height: u32, fps: f32) -> io::Result<Writer> {
+    let (sender, receiver) = mpsc::channel::<RgbaImage>();
+    let is_video = target
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| VIDEO_EXTENSIONS.contains(&extension));
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let thread = if is_video {
+        let mut encoder = Command::new(ENCODER)
+            .args([
+                "-loglevel",
+                "error",
+                "-y",
+                "-f",
+                "rawvideo",
+                "-pix_fmt",
+                "rgba",
+            ])
+            .args(["-s", &format!("{width}x{height}"), "-r", &fps.to_string()])
+            .args(["-i", "-", "-pix_fmt", "yuv420p"])
+            .arg(target)
+            .stdin(Stdio::piped())
+            .spawn()
+            .map_err(|err| io::Error::other(format!("failed to start {ENCODER}: {err}")))?;
+        let mut input = encoder.stdin.take().expect("encoder stdin is piped");
+        thread::spawn(move || {
+            for frame in receiver {
+                input.write_all(&frame)?;
+            }
+            drop(input);
+            let status = encoder.wait()?;
+            if !status.success() {
+                return Err(io::Error::other(format!("{ENCODER} exited with {status}")));
+            }
+            Ok(())
+        })
+    } else {
+        fs::create_dir_all(target)?;
+        let directory = target.to_path_buf();
+        thread::spawn(move || {
+            for (index, frame) in receiver.into_iter().enumerate() {
+                save_png(&directory.join(format!("frame_{index:06}.png")), &frame)?;
+            }
+            Ok(())
+        })
+    };
+    Ok(Writer {
+        sender,
+        thread,
+        width,
+        height,
+    })
+}
+
+fn save_png(path: &Path, frame: &RgbaImage) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    frame.save(path).map_err(io::Error::other)
+}
+
+// `<stem>-000<suffix>` with the first number not taken yet
+fn unused_path(stem: &str, suffix: &str) -> PathBuf {
+    (0..)
+        .map(|index| PathBuf::from(format!("{stem}-{index:03}{suffix}")))
+        .find(|path| !path.exists())
+        .expect("ran out of capture names")
+}
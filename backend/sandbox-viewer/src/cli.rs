@@ -0,0 +1,315 @@
+use std::path::PathBuf;
+
+use crate::camera::CameraMode;
+use crate::capture::DEFAULT_FPS;
+use crate::mapping::{MappingKind, StateMapping};
+use crate::model::MODEL_PATH;
+
+// about one and a half wingspans of the f35
+const DEFAULT_SPACING: f32 = 16.;
+
+pub enum Command {
+    View(ViewArgs),
+    Grade(GradeArgs),
+    MonteCarlo(MonteCarloArgs),
+    Render(RenderArgs),
+}
+
+pub struct ViewArgs {
+    pub mapping: StateMapping,
+    pub seed: u64,
+    pub tunables: Vec<(String, f32)>,
+    pub scenario: Option<PathBuf>,
+    pub camera: Option<CameraMode>,
+    pub record: Option<PathBuf>,
+    pub fps: f32,
+    pub duration: Option<f32>,
+    pub model: PathBuf,
+    // searched for the model and textures, see `Assets`
+    pub assets: Option<PathBuf>,
+    // flown next to the aircraft of the linked controller
+    pub vehicles: Vec<VehicleArgs>,
+    // world units between the lanes of the vehicles
+    pub spacing: f32,
+    // one viewport per vehicle, all in the same lane
+    pub compare: bool,
+}
+
+// `<controller.c>[@<start position>]` of `--vehicle`
+pub struct VehicleArgs {
+    pub controller: PathBuf,
+    // plant position the vehicle starts from, the scenario's when none is given
+    pub start: Option<f32>,
+}
+
+pub struct GradeArgs {
+    pub scenario: PathBuf,
+    // compiled and loaded like `montecarlo --controller`, the linked controller when none is given
+    pub controller: Option<PathBuf>,
+    pub seed: u64,
+    pub tunables: Vec<(String, f32)>,
+    pub json: Option<PathBuf>,
+    pub csv: Option<PathBuf>,
+}
+
+pub struct MonteCarloArgs {
+    pub scenario: PathBuf,
+    // the repository's `controller.c` found through `Assets` when none is given
+    pub controller: Option<PathBuf>,
+    pub runs: usize,
+    pub seed: u64,
+    pub threads: usize,
+    pub case: Option<usize>,
+    pub tunables: Vec<(String, f32)>,
+    pub json: Option<PathBuf>,
+    pub csv: Option<PathBuf>,
+}
+
+pub struct RenderArgs {
+    pub output: PathBuf,
+    pub model: PathBuf,
+    pub assets: Option<PathBuf>,
+    pub mapping: StateMapping,
+    pub scenario: Option<PathBuf>,
+    pub camera: Option<CameraMode>,
+    // simulated seconds before the snapshot, the scenario's duration by default
+    pub time: Option<f32>,
+    pub width: u32,
+    pub height: u32,
+    pub tunables: Vec<(String, f32)>,
+    pub golden: Option<PathBuf>,
+    // fraction of the pixels allowed to differ from the golden image
+    pub tolerance: f32,
+}
+
+const USAGE: &str = "usage:
+    sandbox-viewer [--mapping altitude|pitch|lateral] [--scale <units per plant unit>] [--seed <n>]
+                   [--scenario <scenario.json>] [--camera free|chase|orbit|cockpit|tower]
+                   [--record <directory|video.mp4> [--fps <n>] [--duration <seconds>]]
+                   [--model <model.obj|model.gltf|model.glb>] [--assets <directory>]
+                   [--vehicle <controller.c>[@<start position>]]... [--spacing <units>]
+                   [--compare] [--set <tunable>=<value>]...
+    sandbox-viewer grade <scenario.json> [--controller <controller.c>] [--seed <n>]
+                         [--set <tunable>=<value>]...
+                         [--json <report.json>] [--csv <trajectory.csv>]
+    sandbox-viewer montecarlo <scenario.json> [--runs <n>] [--seed <n>] [--threads <n>]
+                              [--controller <controller.c>] [--set <tunable>=<value>]...
+                              [--json <report.json>] [--case <index> [--csv <trajectory.csv>]]
+    sandbox-viewer render <image.png> [--mapping altitude|pitch|lateral] [--scale <n>]
+                          [--scenario <scenario.json>] [--camera free|chase|orbit|cockpit|tower]
+                          [--time <seconds>] [--size <width>x<height>] [--set <tunable>=<value>]...
+                          [--model <model.obj|model.gltf|model.glb>] [--assets <directory>]
+                          [--golden <image.png> [--tolerance <fraction>]]";
+
+impl Command {
+    pub fn parse<I>(args: I) -> Result<Command, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = args.into_iter().skip(1);
+        match args.next().as_deref() {
+            // no command at all or one starting with a flag opens the viewer
+            first if first.is_none_or(|arg| arg.starts_with("--")) => {
+                let mut view = ViewArgs {
+                    mapping: StateMapping::default(),
+                    seed: 0,
+                    tunables: Vec::new(),
+                    scenario: None,
+                    camera: None,
+                    record: None,
+                    fps: DEFAULT_FPS,
+                    duration: None,
+                    model: PathBuf::from(MODEL_PATH),
+                    assets: None,
+                    vehicles: Vec::new(),
+                    spacing: DEFAULT_SPACING,
+                    compare: false,
+                };
+                let mut scale = None;
+                let mut next = first.map(str::to_string);
+                while let Some(arg) = next {
+                    let mut value = || args.next().ok_or(USAGE);
+                    match arg.as_str() {
+                        "--mapping" => {
+                            let name = value()?;
+                            view.mapping.kind = MappingKind::parse(&name)
+                                .ok_or(format!("unknown mapping `{name}`\n{USAGE}"))?;
+                        }
+                        "--scale" => scale = Some(parse_number(&arg, &value()?)?),
+                        "--seed" => view.seed = parse_number(&arg, &value()?)?,
+                        "--set" => view.tunables.push(parse_assignment(&value()?)?),
+                        "--scenario" => view.scenario = Some(PathBuf::from(value()?)),
+                        "--record" => view.record = Some(PathBuf::from(value()?)),
+                        "--fps" => view.fps = parse_number(&arg, &value()?)?,
+                        "--duration" => view.duration = Some(parse_number(&arg, &value()?)?),
+                        "--model" => view.model = PathBuf::from(value()?),
+                        "--assets" => view.assets = Some(PathBuf::from(value()?)),
+                        "--vehicle" => view.vehicles.push(parse_vehicle(&value()?)?),
+                        "--spacing" => view.spacing = parse_number(&arg, &value()?)?,
+                        "--compare" => view.compare = true,
+                        "--camera" => {
+                            let name = value()?;
+                            view.camera = Some(
+                                CameraMode::parse(&name)
+                                    .ok_or(format!("unknown camera `{name}`\n{USAGE}"))?,
+                            );
+                        }
+                        _ => return Err(format!("unknown argument `{arg}`\n{USAGE}")),
+                    }
+                    next = args.next();
+                }
+                if view.compare && view.vehicles.is_empty() {
+                    return Err(format!(
+                        "--compare needs a --vehicle to compare with\n{USAGE}"
+                    ));
+                }
+                if view.compare && view.vehicles.iter().any(|vehicle| vehicle.start.is_some()) {
+                    return Err(format!(
+                        "--compare starts every vehicle from the same state, drop the `@` of \
+                         --vehicle\n{USAGE}"
+                    ));
+                }
+                view.mapping.scale = scale.unwrap_or(view.mapping.kind.default_scale());
+                Ok(Command::View(view))
+            }
+            Some("grade") => {
+                let mut grade = GradeArgs {
+                    scenario: PathBuf::from(args.next().ok_or(USAGE)?),
+                    controller: None,
+                    seed: 0,
+                    tunables: Vec::new(),
+                    json: None,
+                    csv: None,
+                };
+                while let Some(arg) = args.next() {
+                    let mut value = || args.next().ok_or(USAGE);
+                    match arg.as_str() {
+                        "--controller" => grade.controller = Some(PathBuf::from(value()?)),
+                        "--seed" => grade.seed = parse_number(&arg, &value()?)?,
+                        "--set" => grade.tunables.push(parse_assignment(&value()?)?),
+                        "--json" => grade.json = Some(PathBuf::from(value()?)),
+                        "--csv" => grade.csv = Some(PathBuf::from(value()?)),
+                        _ => return Err(format!("unknown argument `{arg}`\n{USAGE}")),
+                    }
+                }
+                Ok(Command::Grade(grade))
+            }
+            Some("montecarlo") => {
+                let mut monte_carlo = MonteCarloArgs {
+                    scenario: PathBuf::from(args.next().ok_or(USAGE)?),
+                    controller: None,
+                    runs: 100,
+                    seed: 0,
+                    threads: std::thread::available_parallelism().map_or(1, |n| n.get()),
+                    case: None,
+                    tunables: Vec::new(),
+                    json: None,
+                    csv: None,
+                };
+                while let Some(arg) = args.next() {
+                    let mut value = || args.next().ok_or(USAGE);
+                    match arg.as_str() {
+                        "--controller" => monte_carlo.controller = Some(PathBuf::from(value()?)),
+                        "--runs" => monte_carlo.runs = parse_number(&arg, &value()?)?,
+                        "--seed" => monte_carlo.seed = parse_number(&arg, &value()?)?,
+                        "--threads" => monte_carlo.threads = parse_number(&arg, &value()?)?,
+                        "--case" => monte_carlo.case = Some(parse_number(&arg, &value()?)?),
+                        "--set" => monte_carlo.tunables.push(parse_assignment(&value()?)?),
+                        "--json" => monte_carlo.json = Some(PathBuf::from(value()?)),
+                        "--csv" => monte_carlo.csv = Some(PathBuf::from(value()?)),
+                        _ => return Err(format!("unknown argument `{arg}`\n{USAGE}")),
+                    }
+                }
+                if monte_carlo.csv.is_some() && monte_carlo.case.is_none() {
+                    return Err(format!(
+                        "--csv writes the trajectory of a single run, pick one with --case\n{USAGE}"
+                    ));
+                }
+                Ok(Command::MonteCarlo(monte_carlo))
+            }
+            Some("render") => {
+                let mut render = RenderArgs {
+                    output: PathBuf::from(args.next().ok_or(USAGE)?),
+                    model: PathBuf::from(MODEL_PATH),
+                    assets: None,
+                    mapping: StateMapping::default(),
+                    scenario: None,
+                    camera: None,
+                    time: None,
+                    width: 800,
+                    height: 600,
+                    tunables: Vec::new(),
+                    golden: None,
+                    tolerance: 0.001,
+                };
+                let mut scale = None;
+                while let Some(arg) = args.next() {
+                    let mut value = || args.next().ok_or(USAGE);
+                    match arg.as_str() {
+                        "--mapping" => {
+                            let name = value()?;
+                            render.mapping.kind = MappingKind::parse(&name)
+                                .ok_or(format!("unknown mapping `{name}`\n{USAGE}"))?;
+                        }
+                        "--scale" => scale = Some(parse_number(&arg, &value()?)?),
+                        "--scenario" => render.scenario = Some(PathBuf::from(value()?)),
+                        "--camera" => {
+                            let name = value()?;
+                            render.camera = Some(
+                                CameraMode::parse(&name)
+                                    .ok_or(format!("unknown camera `{name}`\n{USAGE}"))?,
+                            );
+                        }
+                        "--time" => render.time = Some(parse_number(&arg, &value()?)?),
+                        "--size" => {
+                            let size = value()?;
+                            let (width, height) = size.split_once('x').ok_or(format!(
+                                "expected `<width>x<height>`, got `{size}`\n{USAGE}"
+                            ))?;
+                            render.width = parse_number(&arg, width)?;
+                            render.height = parse_number(&arg, height)?;
+                        }
+                        "--set" => render.tunables.push(parse_assignment(&value()?)?),
+                        "--model" => render.model = PathBuf::from(value()?),
+                        "--assets" => render.assets = Some(PathBuf::from(value()?)),
+                        "--golden" => render.golden = Some(PathBuf::from(value()?)),
+                        "--tolerance" => render.tolerance = parse_number(&arg, &value()?)?,
+                        _ => return Err(format!("unknown argument `{arg}`\n{USAGE}")),
+                    }
+                }
+                render.mapping.scale = scale.unwrap_or(render.mapping.kind.default_scale());
+                Ok(Command::Render(render))
+            }
+            // `None` opened the viewer above
+            arg => Err(format!(
+                "unknown command `{}`\n{USAGE}",
+                arg.unwrap_or_default()
+            )),
+        }
+    }
+}
+
+fn parse_vehicle(text: &str) -> Result<VehicleArgs, String> {
+    let (controller, start) = match text.rsplit_once('@') {
+        Some((controller, start)) => (controller, Some(parse_number("--vehicle", start)?)),
+        None => (text, None),
+    };
+    Ok(VehicleArgs {
+        controller: PathBuf::from(controller),
+        start,
+    })
+}
+
+// `name=value` of `--set`
+fn parse_assignment(text: &str) -> Result<(String, f32), String> {
+    let (name, value) = text.split_once('=').ok_or(format!(
+        "expected `<tunable>=<value>`, got `{text}`\n{USAGE}"
+    ))?;
+    Ok((name.to_string(), parse_number("--set", value)?))
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value `{value}` for `{flag}`\n{USAGE}"))
+}
@@ -0,0 +1,257 @@
+use std::fmt::Display;
+use std::path::Path;
+use std::process;
+
+use image::RgbaImage;
+use sandbox_core::controllers::{Controller, ControllerLibrary};
+use sandbox_core::grading::Scenario;
+use sandbox_core::headless;
+use sandbox_core::montecarlo::{self, RunParameters};
+use sandbox_core::recording::Trajectory;
+use sandbox_core::rng::RngService;
+use sandbox_core::simulation::{Simulation, State};
+
+use crate::Transform;
+use crate::assets::Assets;
+use crate::camera::{Camera, CameraMode};
+use crate::cli::{GradeArgs, MonteCarloArgs, RenderArgs};
+use crate::foreign_functions::LinkedController;
+use crate::lighting::Lighting;
+use crate::raster::{self, Canvas};
+
+// the controller linked into the viewer, at the root of the repository
+const DEFAULT_CONTROLLER: &str = "controller.c";
+
+pub fn grade(args: &GradeArgs) {
+    let scenario = load_scenario(&args.scenario, &args.tunables);
+    let rng = RngService::new(args.seed);
+    let parameters = RunParameters::nominal(&scenario, &rng);
+    let trajectory = match &args.controller {
+        Some(path) => {
+            let library = ControllerLibrary::compile(path)
+                .unwrap_or_else(|err| fail("failed to build controller", path, err));
+            let mut controller = library
+                .instantiate()
+                .unwrap_or_else(|err| fail("failed to load controller", path, err));
+            parameters.simulate(&scenario, &mut controller)
+        }
+        None => parameters.simulate(&scenario, &mut LinkedController),
+    };
+    let report = scenario.grade_trajectory(&trajectory);
+    println!("{report}");
+    write_outputs(
+        &trajectory,
+        args.csv.as_deref(),
+        &report.to_json(),
+        args.json.as_deref(),
+    );
+}
+
+pub fn monte_carlo(args: &MonteCarloArgs) {
+    let scenario = load_scenario(&args.scenario, &args.tunables);
+    let rng = RngService::new(args.seed);
+    let source = match &args.controller {
+        Some(path) => path.clone(),
+        None => Assets::new(None)
+            .resolve(Path::new(DEFAULT_CONTROLLER))
+            .unwrap_or_else(|err| {
+                eprintln!("no controller to run, pass --controller: {err}");
+                process::exit(1);
+            }),
+    };
+    let library = ControllerLibrary::compile(&source)
+        .unwrap_or_else(|err| fail("failed to build controller", &source, err));
+
+    if let Some(case) = args.case {
+        let mut controller = library
+            .instantiate()
+            .unwrap_or_else(|err| fail("failed to load controller", &source, err));
+        let parameters = RunParameters::sample(&scenario, &rng, case);
+        let trajectory = parameters.simulate(&scenario, &mut controller);
+        let report = scenario.grade_trajectory(&trajectory);
+        println!("case {case}: {parameters:#?}\n{report}");
+        write_outputs(
+            &trajectory,
+            args.csv.as_deref(),
+            &report.to_json(),
+            args.json.as_deref(),
+        );
+        return;
+    }
+
+    let report = montecarlo::run_batch(&scenario, &library, args.runs, &rng, args.threads)
+        .unwrap_or_else(|err| {
+            eprintln!("monte carlo batch failed: {err}");
+            process::exit(1);
+        });
+    println!("{report}");
+    if let Some(json) = &args.json {
+        std::fs::write(json, report.to_json())
+            .unwrap_or_else(|err| fail("failed to write report", json, err));
+    }
+}
+
+// one frame of the scene on the cpu, compared against a golden image when given one
+pub fn render(args: &RenderArgs) {
+    let image = frame(args);
+    image
+        .save(&args.output)
+        .unwrap_or_else(|err| fail("failed to write image", &args.output, err));
+    println!("rendered {}", args.output.display());
+
+    if let Some(path) = &args.golden {
+        let golden = image::open(path)
+            .unwrap_or_else(|err| fail("failed to load golden image", path, err))
+            .to_rgba8();
+        let Some(difference) = raster::difference(&image, &golden) else {
+            fail(
+                "golden image has a different size",
+                path,
+                format!("{:?} against {:?}", golden.dimensions(), image.dimensions()),
+            );
+        };
+        println!(
+            "{:.3}% of the pixels differ from {}",
+            difference * 100.,
+            path.display()
+        );
+        if difference > args.tolerance {
+            process::exit(1);
+        }
+    }
+}
+
+// the scene after `args.time` of the scenario, drawn with the software rasterizer
+fn frame(args: &RenderArgs) -> RgbaImage {
+    let mut simulation = Simulation {
+        state: State {
+            position: 70.,
+            delta_time: 0.01,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut time = 0.;
+    let mut tunables = args.tunables.clone();
+    let mut camera = Camera::new();
+    if let Some(path) = &args.scenario {
+        let scenario = load_scenario(path, &args.tunables);
+        simulation = scenario.simulation();
+        time = scenario.duration;
+        tunables = scenario.tunables.into_iter().collect();
+        if let Some(name) = &scenario.camera {
+            camera.mode = CameraMode::parse(name).unwrap_or_else(|| {
+                eprintln!("unknown camera `{name}` in {}", path.display());
+                process::exit(2);
+            });
+        }
+    }
+    if let Some(mode) = args.camera {
+        camera.mode = mode;
+    }
+    let mut controller = LinkedController;
+    for (name, value) in &tunables {
+        controller.set_tunable(name, *value);
+    }
+    headless::run(&mut simulation, args.time.unwrap_or(time), |state| {
+        controller.control(state)
+    });
+
+    let state = simulation.state;
+    let mut transform = Transform::default();
+    args.mapping.apply(&state, &mut transform);
+    let travel = state.tick as f32 * state.delta_time * args.mapping.velocity(&state).x;
+    camera.aspect_ratio = args.width as f32 / args.height as f32;
+    if camera.mode == CameraMode::Free {
+        camera.update_vectors();
+    } else {
+        // settled, as if the camera had been following all along
+        camera.follow(&transform, f32::INFINITY);
+    }
+
+    // missing assets are warned about and drawn as placeholders, as in the window
+    let mut scene = Assets::new(args.assets.as_deref()).load_scene(&args.model);
+    scene
+        .surfaces
+        .update(&simulation, &args.mapping, &mut scene.model);
+
+    let lighting = Lighting::default();
+    let mut canvas = Canvas::new(args.width, args.height);
+    canvas.sky(&camera, &lighting);
+    canvas.ground(&camera, travel, &scene.grid, &lighting);
+    canvas.model(&camera, &scene.model, &transform, &lighting);
+    canvas.into_image()
+}
+
+// command line `--set` values win over the scenario's tunables
+pub fn load_scenario(path: &Path, tunables: &[(String, f32)]) -> Scenario {
+    let mut scenario =
+        Scenario::load(path).unwrap_or_else(|err| fail("failed to load scenario", path, err));
+    scenario.tunables.extend(tunables.iter().cloned());
+    scenario
+}
+
+fn write_outputs(trajectory: &Trajectory, csv: Option<&Path>, report: &str, json: Option<&Path>) {
+    if let Some(csv) = csv {
+        trajectory
+            .write_csv(csv)
+            .unwrap_or_else(|err| fail("failed to write trajectory", csv, err));
+    }
+    if let Some(json) = json {
+        std::fs::write(json, report)
+            .unwrap_or_else(|err| fail("failed to write report", json, err));
+    }
+}
+
+fn fail(what: &str, path: &Path, err: impl Display) -> ! {
+    eprintln!("{what} {}: {err}", path.display());
+    process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::Command;
+
+    // the f35 at its starting state behind the chase camera, rewritten by
+    // `UPDATE_GOLDEN=1 cargo test`
+    const GOLDEN: &str = "golden/chase.png";
+    // room for float math rounding a little differently on other platforms
+    const TOLERANCE: f32 = 0.005;
+
+    #[test]
+    fn chase_frame_matches_golden_image() {
+        let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let assets = manifest.join("..");
+        // no time passes, so the frame does not depend on the linked controller
+        let command = [
+            "sandbox-viewer",
+            "render",
+            "unused.png",
+            "--camera",
+            "chase",
+            "--time",
+            "0",
+            "--size",
+            "320x180",
+            "--assets",
+            assets.to_str().unwrap(),
+        ];
+        let Ok(Command::Render(args)) = Command::parse(command.map(String::from)) else {
+            panic!("not a render command");
+        };
+        let image = frame(&args);
+        let golden = manifest.join(GOLDEN);
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            image.save(&golden).unwrap();
+        }
+        let golden = image::open(&golden).unwrap().to_rgba8();
+        let difference =
+            raster::difference(&image, &golden).expect("golden image has a different size");
+        assert!(
+            difference <= TOLERANCE,
+            "{:.3}% of the pixels differ from {GOLDEN}",
+            difference * 100.
+        );
+    }
+}
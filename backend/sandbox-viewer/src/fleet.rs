@@ -0,0 +1,237 @@
+use std::error::Error;
+use std::path::PathBuf;
+
+use glam as glm;
+
+use sandbox_core::controllers::{Controller, ControllerLibrary};
+use sandbox_core::metrics::LiveMetrics;
+use sandbox_core::recording::Trajectory;
+use sandbox_core::simulation::{Simulation, State};
+use sokol::app as sap;
+
+use crate::Transform;
+use crate::camera::Inputs;
+use crate::cli::VehicleArgs;
+use crate::foreign_functions::LinkedController;
+use crate::mapping::StateMapping;
+use crate::panels::SimControls;
+
+// multiplied into the model's colors, the first vehicle looks like the model itself
+const TINTS: [[f32; 3]; 5] = [
+    [1., 1., 1.],
+    [1., 0.45, 0.4],
+    [0.45, 0.65, 1.],
+    [0.5, 1., 0.5],
+    [1., 0.9, 0.35],
+];
+
+// one aircraft with its own plant and controller
+pub struct Vehicle {
+    pub name: String,
+    pub simulation: Simulation,
+    pub controller: Box<dyn Controller>,
+    pub transform: Transform,
+    pub tint: [f32; 3],
+    pub trajectory: Trajectory,
+    // of `trajectory` so far, for the readout in the gui
+    pub metrics: LiveMetrics,
+    // index into the fleet's libraries, none for the linked controller
+    library: Option<usize>,
+    // plant position at a restart, the scenario's when none is given
+    start: Option<f32>,
+    // world units off the first vehicle's lane
+    lane: f32,
+}
+
+impl Vehicle {
+    // lanes alternate to either side of the first vehicle's, `spacing` apart
+    fn new(
+        name: String,
+        controller: Box<dyn Controller>,
+        library: Option<usize>,
+        start: Option<f32>,
+        index: usize,
+        spacing: f32,
+    ) -> Vehicle {
+        let side = if index % 2 == 1 { 1. } else { -1. };
+        Vehicle {
+            name,
+            simulation: Simulation::default(),
+            controller,
+            transform: Transform::default(),
+            tint: TINTS[index % TINTS.len()],
+            trajectory: Trajectory::default(),
+            metrics: LiveMetrics::default(),
+            library,
+            start,
+            lane: index.div_ceil(2) as f32 * side * spacing,
+        }
+    }
+
+    pub fn lane_offset(&self, mapping: &StateMapping) -> glm::Vec3 {
+        mapping.lane_axis() * self.lane
+    }
+}
+
+// the aircraft of the scene, stepped together with the same disturbance. the first is flown by the
+// controller linked into the viewer, the one the gui tunes, the others by `--vehicle` controllers
+// each loaded from its own copy of a shared object so their `static` state stays apart
+pub struct Fleet {
+    pub vehicles: Vec<Vehicle>,
+    // the vehicle the camera, the hud and the panels follow
+    pub focus: usize,
+    // declared after the vehicles so their controllers are dropped first
+    libraries: Vec<(PathBuf, ControllerLibrary)>,
+    // the `--set` values, set again on every controller loaded at a restart
+    tunables: Vec<(String, f32)>,
+}
+
+impl Default for Fleet {
+    fn default() -> Fleet {
+        Fleet {
+            vehicles: vec![Vehicle::new(
+                String::from("controller"),
+                Box::new(LinkedController),
+                None,
+                None,
+                0,
+                0.,
+            )],
+            focus: 0,
+            libraries: Vec::new(),
+            tunables: Vec::new(),
+        }
+    }
+}
+
+impl Fleet {
+    // compiles each distinct controller once, `tunables` are set on every vehicle
+    pub fn new(
+        vehicles: &[VehicleArgs],
+        spacing: f32,
+        tunables: &[(String, f32)],
+    ) -> Result<Fleet, Box<dyn Error>> {
+        let mut fleet = Fleet {
+            tunables: tunables.to_vec(),
+            ..Fleet::default()
+        };
+        for args in vehicles {
+            let library = match fleet
+                .libraries
+                .iter()
+                .position(|(path, _)| *path == args.controller)
+            {
+                Some(index) => index,
+                None => {
+                    let library = ControllerLibrary::compile(&args.controller)
+                        .map_err(|err| format!("{}: {err}", args.controller.display()))?;
+                    fleet.libraries.push((args.controller.clone(), library));
+                    fleet.libraries.len() - 1
+                }
+            };
+            let controller = fleet.instantiate(library)?;
+            let stem = args
+                .controller
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy();
+            let index = fleet.vehicles.len();
+            let name = if fleet.vehicles.iter().any(|vehicle| vehicle.name == stem) {
+                format!("{stem} {}", index + 1)
+            } else {
+                stem.into_owned()
+            };
+            fleet.vehicles.push(Vehicle::new(
+                name,
+                controller,
+                Some(library),
+                args.start,
+                index,
+                spacing,
+            ));
+        }
+        Ok(fleet)
+    }
+
+    // a fresh copy of a library's controller with the `--set` values applied
+    fn instantiate(&self, library: usize) -> Result<Box<dyn Controller>, Box<dyn Error>> {
+        let mut controller = self.libraries[library].1.instantiate()?;
+        for (name, value) in &self.tunables {
+            controller.set_tunable(name, *value);
+        }
+        Ok(Box::new(controller))
+    }
+
+    pub fn focused(&self) -> &Vehicle {
+        &self.vehicles[self.focus]
+    }
+
+    pub fn focused_mut(&mut self) -> &mut Vehicle {
+        &mut self.vehicles[self.focus]
+    }
+
+    pub fn handle_inputs(&mut self, inputs: &mut Inputs) {
+        if inputs.take_press(sap::Keycode::N) {
+            self.focus = (self.focus + 1) % self.vehicles.len();
+        }
+    }
+
+    // back to the initial state of `controls`, each vehicle from its own start position. loaded
+    // controllers are replaced by fresh copies so their `static` state starts over too
+    pub fn restart(&mut self, controls: &mut SimControls) {
+        for index in 0..self.vehicles.len() {
+            if let Some(library) = self.vehicles[index].library {
+                match self.instantiate(library) {
+                    Ok(controller) => self.vehicles[index].controller = controller,
+                    Err(err) => eprintln!(
+                        "failed to reload the controller of {}: {err}",
+                        self.vehicles[index].name
+                    ),
+                }
+            }
+            let vehicle = &mut self.vehicles[index];
+            controls.restart(&mut vehicle.simulation);
+            if let Some(start) = vehicle.start {
+                vehicle.simulation.state.position = start;
+            }
+            vehicle.trajectory = Trajectory {
+                seed: controls.seed(),
+                ..Default::default()
+            };
+            vehicle.metrics = LiveMetrics::default();
+        }
+    }
+
+    // one tick of every vehicle, all pushed by the same `disturbance`
+    pub fn tick(&mut self, disturbance: f32, setpoint: f32) {
+        for vehicle in &mut self.vehicles {
+            let simulation = &mut vehicle.simulation;
+            simulation.control = vehicle.controller.control(&simulation.state);
+            simulation.disturbance = disturbance;
+            vehicle.trajectory.record(simulation, setpoint);
+            if let Some(sample) = vehicle.trajectory.samples.last() {
+                vehicle.metrics.push(sample);
+            }
+            simulation.step();
+        }
+    }
+
+    // places every vehicle in its lane
+    pub fn place(&mut self, mapping: &StateMapping) {
+        for vehicle in &mut self.vehicles {
+            mapping.apply(&vehicle.simulation.state, &mut vehicle.transform);
+            vehicle.transform.position += vehicle.lane_offset(mapping);
+        }
+    }
+
+    // the state each vehicle starts from, `initial` with the `--vehicle` start positions applied
+    pub fn start(&mut self, initial: State, seed: u64) {
+        for vehicle in &mut self.vehicles {
+            vehicle.simulation.state = State {
+                position: vehicle.start.unwrap_or(initial.position),
+                ..initial
+            };
+            vehicle.trajectory.seed = seed;
+        }
+    }
+}
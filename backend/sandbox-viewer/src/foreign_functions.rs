@@ -0,0 +1,107 @@
+use std::ffi::{CStr, c_char, c_void};
+use std::sync::{Mutex, MutexGuard};
+
+use sokol::app as sap;
+use sokol::debugtext as sdtx;
+use sokol::gfx;
+use sokol::gl as sgl;
+
+use crate::GlobalState;
+use sandbox_core::controllers::Controller;
+use sandbox_core::simulation::{Control, State};
+use sandbox_core::tunables::Tunables;
+
+pub fn call_controller(state: &State) -> Control {
+    unsafe { controller(state) }
+}
+
+unsafe extern "C" {
+    fn controller(state: &State) -> Control;
+}
+
+// values handed out by the controller through `sandbox_publish`, latest value per name
+static PUBLISHED: Mutex<Vec<(String, f32)>> = Mutex::new(Vec::new());
+
+#[unsafe(no_mangle)]
+pub extern "C" fn sandbox_publish(name: *const c_char, value: f32) {
+    if name.is_null() {
+        return;
+    }
+    let name = unsafe { CStr::from_ptr(name) }.to_string_lossy();
+    let mut published = PUBLISHED.lock().unwrap();
+    match published.iter_mut().find(|(existing, _)| *existing == name) {
+        Some(entry) => entry.1 = value,
+        None => published.push((name.into_owned(), value)),
+    }
+}
+
+pub fn published_values() -> Vec<(String, f32)> {
+    PUBLISHED.lock().unwrap().clone()
+}
+
+// parameters the controller reads through `sandbox_tunable`, edited by the gui
+static TUNABLES: Mutex<Tunables> = Mutex::new(Tunables::new());
+
+#[unsafe(no_mangle)]
+pub extern "C" fn sandbox_tunable(name: *const c_char, initial: f32, min: f32, max: f32) -> f32 {
+    if name.is_null() {
+        return initial;
+    }
+    let name = unsafe { CStr::from_ptr(name) }.to_string_lossy();
+    tunables().register(&name, initial, min, max)
+}
+
+pub fn tunables() -> MutexGuard<'static, Tunables> {
+    TUNABLES.lock().unwrap()
+}
+
+// the controller linked into the binary, its `static` state lives as long as the process
+pub struct LinkedController;
+
+impl Controller for LinkedController {
+    fn control(&mut self, state: &State) -> Control {
+        call_controller(state)
+    }
+
+    fn set_tunable(&mut self, name: &str, value: f32) {
+        tunables().set(name, value);
+    }
+}
+
+pub extern "C" fn ffi_cb_init(user_data: *mut c_void) {
+    let global_state: &mut GlobalState;
+    unsafe {
+        global_state = &mut *(user_data as *mut GlobalState);
+    }
+    global_state.callback_init(user_data);
+}
+
+pub extern "C" fn ffi_cb_event(raw_event: *const sap::Event, user_data: *mut c_void) {
+    let event: &sap::Event;
+    let global_state: &mut GlobalState;
+    unsafe {
+        event = &*raw_event;
+        global_state = &mut *(user_data as *mut GlobalState);
+    }
+    global_state.callback_event(event);
+}
+
+pub extern "C" fn ffi_cb_frame(user_data: *mut c_void) {
+    let global_state: &mut GlobalState;
+    unsafe {
+        global_state = &mut *(user_data as *mut GlobalState);
+    }
+    global_state.callback_frame();
+}
+
+pub extern "C" fn ffi_cb_cleanup(user_data: *mut c_void) {
+    sdtx::shutdown();
+    sgl::shutdown();
+    gfx::shutdown();
+    unsafe {
+        if !user_data.is_null() {
+            // dropped as the state it is, so an open recording gets finished
+            drop(Box::from_raw(user_data as *mut GlobalState));
+        }
+    }
+}
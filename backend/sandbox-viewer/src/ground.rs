@@ -0,0 +1,122 @@
+use image::RgbaImage;
+use sokol::gfx;
+
+use crate::camera::Camera;
+use crate::lighting::Lighting;
+use crate::shaders;
+use crate::shadows::ShadowMap;
+use crate::sky;
+
+// the aircraft flies above the plane, a plant position of 0 keeps it this far up
+pub const HEIGHT: f32 = -2.;
+// the plane follows the camera so it reaches past the far plane wherever the camera goes
+pub const EXTENT: f32 = 200.;
+// world units covered by one repeat of the grid texture
+pub const TILE_SIZE: f32 = 16.;
+pub const GRID_PATH: &str = "vendor/diagnostic/num_grid.png";
+
+// a gray checkerboard of four squares per tile, for when the grid texture is missing
+pub fn placeholder_grid() -> RgbaImage {
+    RgbaImage::from_fn(64, 64, |x, y| {
+        let shade = if (x / 32 + y / 32) % 2 == 0 { 150 } else { 110 };
+        image::Rgba([shade, shade, shade, 255])
+    })
+}
+
+#[derive(Default)]
+pub struct Ground {
+    pipeline: gfx::Pipeline,
+    bindings: gfx::Bindings,
+}
+
+impl Ground {
+    pub fn init(&mut self, shadow_map: &ShadowMap, grid: &RgbaImage) {
+        #[rustfmt::skip]
+        let vertices: [f32; 18] = [
+            -EXTENT, HEIGHT, -EXTENT,
+             EXTENT, HEIGHT, -EXTENT,
+             EXTENT, HEIGHT,  EXTENT,
+             EXTENT, HEIGHT,  EXTENT,
+            -EXTENT, HEIGHT,  EXTENT,
+            -EXTENT, HEIGHT, -EXTENT,
+        ];
+        self.bindings.vertex_buffers[0] = gfx::make_buffer(&gfx::BufferDesc {
+            data: gfx::slice_as_range(&vertices),
+            label: c"ground verts".as_ptr(),
+            ..Default::default()
+        });
+        self.bindings.images[shaders::IMG_SHADOW_MAP] = shadow_map.image;
+        self.bindings.samplers[shaders::SMP_SHADOW_SAMPLER] = shadow_map.sampler;
+
+        let (width, height) = grid.dimensions();
+        self.bindings.images[shaders::IMG_GRID] = gfx::make_image(&gfx::ImageDesc {
+            width: width as i32,
+            height: height as i32,
+            pixel_format: gfx::PixelFormat::Rgba8,
+            data: {
+                let mut subimage = gfx::ImageData::new();
+                subimage.subimage[0][0] = gfx::slice_as_range(grid.as_raw());
+                subimage
+            },
+            label: c"ground grid".as_ptr(),
+            ..Default::default()
+        });
+        self.bindings.samplers[shaders::SMP_GRID_SAMPLER] = gfx::make_sampler(&gfx::SamplerDesc {
+            min_filter: gfx::Filter::Linear,
+            mag_filter: gfx::Filter::Linear,
+            wrap_u: gfx::Wrap::Repeat,
+            wrap_v: gfx::Wrap::Repeat,
+            ..Default::default()
+        });
+        self.pipeline = gfx::make_pipeline(&gfx::PipelineDesc {
+            shader: gfx::make_shader(&shaders::ground_shader_desc(gfx::query_backend())),
+            layout: {
+                let mut layout = gfx::VertexLayoutState::new();
+                layout.attrs[shaders::ATTR_GROUND_POSITION].format = gfx::VertexFormat::Float3;
+                layout
+            },
+            depth: gfx::DepthState {
+                compare: gfx::CompareFunc::Less,
+                write_enabled: true,
+                ..Default::default()
+            },
+            cull_mode: gfx::CullMode::None,
+            ..Default::default()
+        });
+    }
+
+    // `travel` is how far the aircraft has flown along +x, the grid slides back by as much
+    pub fn draw(&self, camera: &Camera, travel: f32, shadow_map: &ShadowMap, lighting: &Lighting) {
+        let view_projection = camera.projection_matrix() * camera.view_matrix();
+        let vs_params = shaders::GroundVsParams {
+            view_projection: view_projection.to_cols_array(),
+            light_view_projection: shadow_map.light_view_projection.to_cols_array(),
+            origin: [camera.position.x, 0., camera.position.z],
+            tile_size: TILE_SIZE,
+            scroll: [travel, 0.],
+            _pad_152: [0; 8],
+        };
+        let fs_params = shaders::GroundFsParams {
+            eye_position: camera.position.to_array(),
+            ambient: lighting.ambient,
+            light_direction: lighting.direction().to_array(),
+            bias: 0.0005,
+            light_color: lighting.color,
+            // fully faded just before the far plane cuts the plane off
+            fog_distance: camera.far * 0.9,
+            fog_color: sky::HORIZON,
+            _pad_60: [0; 4],
+        };
+        gfx::apply_pipeline(self.pipeline);
+        gfx::apply_bindings(&self.bindings);
+        gfx::apply_uniforms(
+            shaders::UB_GROUND_VS_PARAMS,
+            &gfx::value_as_range(&vs_params),
+        );
+        gfx::apply_uniforms(
+            shaders::UB_GROUND_FS_PARAMS,
+            &gfx::value_as_range(&fs_params),
+        );
+        gfx::draw(0, 6, 1);
+    }
+}
@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+
+use egui::epaint::{ImageDelta, Primitive};
+use sokol::app as sap;
+use sokol::gfx;
+use sokol::gl as sgl;
+use sokol::time;
+
+// egui drawn through sokol-gl, one textured triangle list per clipped mesh
+#[derive(Default)]
+pub struct Gui {
+    pub context: egui::Context,
+    events: Vec<egui::Event>,
+    modifiers: egui::Modifiers,
+    textures: HashMap<egui::TextureId, Texture>,
+    sampler: gfx::Sampler,
+    pipeline: sgl::Pipeline,
+}
+
+// sokol images are immutable, so a cpu copy is kept to apply partial updates and re-upload
+struct Texture {
+    size: [usize; 2],
+    pixels: Vec<egui::Color32>,
+    image: gfx::Image,
+}
+
+impl Gui {
+    pub fn init(&mut self) {
+        self.sampler = gfx::make_sampler(&gfx::SamplerDesc {
+            min_filter: gfx::Filter::Linear,
+            mag_filter: gfx::Filter::Linear,
+            wrap_u: gfx::Wrap::ClampToEdge,
+            wrap_v: gfx::Wrap::ClampToEdge,
+            ..Default::default()
+        });
+        self.pipeline = sgl::make_pipeline(&gfx::PipelineDesc {
+            colors: {
+                let mut colors = [gfx::ColorTargetState::default(); 4];
+                colors[0].blend = gfx::BlendState {
+                    enabled: true,
+                    src_factor_rgb: gfx::BlendFactor::One,
+                    dst_factor_rgb: gfx::BlendFactor::OneMinusSrcAlpha,
+                    src_factor_alpha: gfx::BlendFactor::OneMinusDstAlpha,
+                    dst_factor_alpha: gfx::BlendFactor::One,
+                };
+                colors
+            },
+            ..Default::default()
+        });
+    }
+
+    // returns true when egui takes the event and the camera should not see it
+    pub fn handle_event(&mut self, event: &sap::Event) -> bool {
+        let scale = sap::dpi_scale();
+        let pos = egui::pos2(event.mouse_x / scale, event.mouse_y / scale);
+        self.modifiers = egui::Modifiers {
+            alt: event.modifiers & sap::MODIFIER_ALT != 0,
+            ctrl: event.modifiers & sap::MODIFIER_CTRL != 0,
+            shift: event.modifiers & sap::MODIFIER_SHIFT != 0,
+            mac_cmd: false,
+            command: event.modifiers & sap::MODIFIER_CTRL != 0,
+        };
+        match event._type {
+            sap::EventType::MouseMove => {
+                self.events.push(egui::Event::PointerMoved(pos));
+                self.context.wants_pointer_input()
+            }
+            sap::EventType::MouseDown | sap::EventType::MouseUp => {
+                let button = match event.mouse_button {
+                    sap::Mousebutton::Left => egui::PointerButton::Primary,
+                    sap::Mousebutton::Right => egui::PointerButton::Secondary,
+                    _ => egui::PointerButton::Middle,
+                };
+                self.events.push(egui::Event::PointerButton {
+                    pos,
+                    button,
+                    pressed: event._type == sap::EventType::MouseDown,
+                    modifiers: self.modifiers,
+                });
+                self.context.wants_pointer_input()
+            }
+            sap::EventType::MouseScroll => {
+                self.events.push(egui::Event::MouseWheel {
+                    unit: egui::MouseWheelUnit::Line,
+                    delta: egui::vec2(event.scroll_x, event.scroll_y),
+                    modifiers: self.modifiers,
+                });
+                self.context.wants_pointer_input()
+            }
+            sap::EventType::MouseLeave => {
+                self.events.push(egui::Event::PointerGone);
+                false
+            }
+            sap::EventType::Char => {
+                if let Some(c) = char::from_u32(event.char_code).filter(|c| !c.is_control()) {
+                    self.events.push(egui::Event::Text(c.to_string()));
+                }
+                self.context.wants_keyboard_input()
+            }
+            sap::EventType::KeyDown | sap::EventType::KeyUp => {
+                let pressed = event._type == sap::EventType::KeyDown;
+                if let Some(key) = egui_key(event.key_code) {
+                    self.events.push(egui::Event::Key {
+                        key,
+                        physical_key: None,
+                        pressed,
+                        repeat: event.key_repeat,
+                        modifiers: self.modifiers,
+                    });
+                }
+                // releases always reach the camera so no key gets stuck down
+                pressed && self.context.wants_keyboard_input()
+            }
+            _ => false,
+        }
+    }
+
+    pub fn run(&mut self, build: impl FnMut(&egui::Context)) {
+        let scale = sap::dpi_scale();
+        let mut input = egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(
+                egui::Pos2::ZERO,
+                egui::vec2(sap::widthf() / scale, sap::heightf() / scale),
+            )),
+            time: Some(time::sec(time::now())),
+            modifiers: self.modifiers,
+            events: std::mem::take(&mut self.events),
+            ..Default::default()
+        };
+        input
+            .viewports
+            .entry(egui::ViewportId::ROOT)
+            .or_default()
+            .native_pixels_per_point = Some(scale);
+
+        let output = self.context.run(input, build);
+        for (id, delta) in &output.textures_delta.set {
+            self.update_texture(*id, delta);
+        }
+        let primitives = self
+            .context
+            .tessellate(output.shapes, output.pixels_per_point);
+        self.paint(&primitives, output.pixels_per_point);
+        for id in &output.textures_delta.free {
+            if let Some(texture) = self.textures.remove(id) {
+                gfx::destroy_image(texture.image);
+            }
+        }
+    }
+
+    fn update_texture(&mut self, id: egui::TextureId, delta: &ImageDelta) {
+        let size = delta.image.size();
+        let pixels: Vec<egui::Color32> = match &delta.image {
+            egui::ImageData::Color(image) => image.pixels.clone(),
+            egui::ImageData::Font(image) => image.srgba_pixels(None).collect(),
+        };
+        let texture = self.textures.entry(id).or_insert_with(|| Texture {
+            size,
+            pixels: Vec::new(),
+            image: gfx::Image::default(),
+        });
+        match delta.pos {
+            Some([x, y]) => {
+                for row in 0..size[1] {
+                    let target = (y + row) * texture.size[0] + x;
+                    texture.pixels[target..target + size[0]]
+                        .copy_from_slice(&pixels[row * size[0]..(row + 1) * size[0]]);
+                }
+            }
+            None => {
+                texture.size = size;
+                texture.pixels = pixels;
+            }
+        }
+
+        gfx::destroy_image(texture.image);
+        texture.image = gfx::make_image(&gfx::ImageDesc {
+            width: texture.size[0] as i32,
+            height: texture.size[1] as i32,
+            pixel_format: gfx::PixelFormat::Rgba8,
+            data: {
+                let mut data = gfx::ImageData::new();
+                data.subimage[0][0] = gfx::slice_as_range(&texture.pixels);
+                data
+            },
+            ..Default::default()
+        });
+    }
+
+    fn paint(&self, primitives: &[egui::ClippedPrimitive], pixels_per_point: f32) {
+        let (width, height) = (sap::widthf(), sap::heightf());
+        sgl::defaults();
+        sgl::load_pipeline(self.pipeline);
+        sgl::matrix_mode_projection();
+        sgl::ortho(
+            0.,
+            width / pixels_per_point,
+            height / pixels_per_point,
+            0.,
+            -1.,
+            1.,
+        );
+        sgl::enable_texture();
+
+        for primitive in primitives {
+            let Primitive::Mesh(mesh) = &primitive.primitive else {
+                continue;
+            };
+            let Some(texture) = self.textures.get(&mesh.texture_id) else {
+                continue;
+            };
+            let clip = primitive.clip_rect;
+            let min_x = (clip.min.x * pixels_per_point).clamp(0., width);
+            let min_y = (clip.min.y * pixels_per_point).clamp(0., height);
+            let max_x = (clip.max.x * pixels_per_point).clamp(min_x, width);
+            let max_y = (clip.max.y * pixels_per_point).clamp(min_y, height);
+            sgl::scissor_rect(
+                min_x as i32,
+                min_y as i32,
+                (max_x - min_x).round() as i32,
+                (max_y - min_y).round() as i32,
+                true,
+            );
+            sgl::texture(texture.image, self.sampler);
+            sgl::begin_triangles();
+            for &index in &mesh.indices {
+                let vertex = &mesh.vertices[index as usize];
+                let [r, g, b, a] = vertex.color.to_array();
+                sgl::v2f_t2f_c4b(
+                    vertex.pos.x,
+                    vertex.pos.y,
+                    vertex.uv.x,
+                    vertex.uv.y,
+                    r,
+                    g,
+                    b,
+                    a,
+                );
+            }
+            sgl::end();
+        }
+
+        sgl::disable_texture();
+        sgl::scissor_rect(0, 0, width as i32, height as i32, true);
+    }
+}
+
+fn egui_key(key: sap::Keycode) -> Option<egui::Key> {
+    Some(match key {
+        sap::Keycode::Enter => egui::Key::Enter,
+        sap::Keycode::Tab => egui::Key::Tab,
+        sap::Keycode::Backspace => egui::Key::Backspace,
+        sap::Keycode::Delete => egui::Key::Delete,
+        sap::Keycode::Escape => egui::Key::Escape,
+        sap::Keycode::Left => egui::Key::ArrowLeft,
+        sap::Keycode::Right => egui::Key::ArrowRight,
+        sap::Keycode::Up => egui::Key::ArrowUp,
+        sap::Keycode::Down => egui::Key::ArrowDown,
+        sap::Keycode::Home => egui::Key::Home,
+        sap::Keycode::End => egui::Key::End,
+        sap::Keycode::A => egui::Key::A,
+        sap::Keycode::C => egui::Key::C,
+        sap::Keycode::V => egui::Key::V,
+        sap::Keycode::X => egui::Key::X,
+        sap::Keycode::Z => egui::Key::Z,
+        _ => return None,
+    })
+}
@@ -0,0 +1,217 @@
+use glam as glm;
+
+use sandbox_core::simulation::State;
+use sokol::app as sap;
+use sokol::debugtext as sdtx;
+use sokol::gl as sgl;
+
+use crate::Transform;
+use crate::camera::Inputs;
+
+// size of a debugtext character cell in pixels, as in the strip chart
+const CELL: f32 = 16.;
+const GREEN: [f32; 3] = [0.3, 1., 0.45];
+const RED: [f32; 3] = [1., 0.35, 0.3];
+// spacing of the pitch ladder rungs in degrees
+const LADDER_STEP: i32 = 10;
+
+// flight instruments derived from the aircraft transform, angles in degrees
+#[derive(Default, Debug, Clone, Copy)]
+pub struct Instruments {
+    pub altitude: f32,
+    pub airspeed: f32,
+    pub pitch: f32,
+    pub roll: f32,
+    pub heading: f32,
+    pub angle_of_attack: f32,
+    pub tick: i32,
+    pub time: f32,
+}
+
+pub struct Hud {
+    pub visible: bool,
+}
+
+impl Default for Hud {
+    fn default() -> Hud {
+        Hud { visible: true }
+    }
+}
+
+impl Instruments {
+    // `velocity` is the world-space velocity of the aircraft, nose along +x and right wing on +z
+    pub fn measure(state: &State, transform: &Transform, velocity: glm::Vec3) -> Instruments {
+        let forward = transform.rotation * glm::Vec3::X;
+        let up = transform.rotation * glm::Vec3::Y;
+        let right = transform.rotation * glm::Vec3::Z;
+        Instruments {
+            altitude: transform.position.y,
+            airspeed: velocity.length(),
+            pitch: forward.y.clamp(-1., 1.).asin().to_degrees(),
+            roll: (-right.y).atan2(up.y).to_degrees(),
+            heading: forward.z.atan2(forward.x).to_degrees().rem_euclid(360.),
+            angle_of_attack: if velocity.length_squared() > 0. {
+                (-velocity.dot(up))
+                    .atan2(velocity.dot(forward))
+                    .to_degrees()
+            } else {
+                0.
+            },
+            tick: state.tick,
+            time: state.tick as f32 * state.delta_time,
+        }
+    }
+}
+
+impl Hud {
+    pub fn handle_inputs(&mut self, inputs: &mut Inputs) {
+        if inputs.take_press(sap::Keycode::H) {
+            self.visible = !self.visible;
+        }
+    }
+
+    // queues sokol-gl and debugtext commands, drawn by the `sgl::draw` and `sdtx::draw` of the pass.
+    // `status` is the controller line, shown in red when `healthy` is false
+    pub fn draw(
+        &self,
+        width: f32,
+        height: f32,
+        instruments: &Instruments,
+        status: &str,
+        healthy: bool,
+    ) {
+        if !self.visible {
+            return;
+        }
+        let center = glm::Vec2::new(width / 2., height * 0.32);
+        let radius = width.min(height) * 0.22;
+        let pixels_per_degree = radius / 30.;
+
+        sgl::defaults();
+        sgl::load_default_pipeline();
+        sgl::matrix_mode_projection();
+        sgl::ortho(0., width, height, 0., -1., 1.);
+        sgl::scissor_rect(
+            (center.x - radius) as i32,
+            (center.y - radius) as i32,
+            (radius * 2.) as i32,
+            (radius * 2.) as i32,
+            true,
+        );
+
+        // horizon and ladder turn with the roll and slide with the pitch
+        let roll = instruments.roll.to_radians();
+        let along = glm::Vec2::new(roll.cos(), -roll.sin());
+        let normal = glm::Vec2::new(roll.sin(), roll.cos());
+        let rung = |degrees: f32, half_width: f32, gap: f32| {
+            let middle = center + normal * (instruments.pitch - degrees) * pixels_per_degree;
+            for side in [-1., 1.] {
+                let inner = middle + along * gap * side;
+                let outer = middle + along * half_width * side;
+                sgl::v2f(inner.x, inner.y);
+                sgl::v2f(outer.x, outer.y);
+            }
+        };
+        let [r, g, b] = GREEN;
+        sgl::begin_lines();
+        sgl::c3f(r, g, b);
+        rung(0., radius * 2., radius * 0.15);
+        let nearest = (instruments.pitch / LADDER_STEP as f32).round() as i32 * LADDER_STEP;
+        for degrees in (nearest - 3 * LADDER_STEP..=nearest + 3 * LADDER_STEP)
+            .step_by(LADDER_STEP as usize)
+            .filter(|degrees| *degrees != 0 && degrees.abs() <= 90)
+        {
+            rung(degrees as f32, radius * 0.35, radius * 0.12);
+        }
+        sgl::end();
+        sgl::scissor_rect(0, 0, width as i32, height as i32, true);
+
+        // fixed aircraft symbol
+        sgl::begin_line_strip();
+        sgl::c3f(r, g, b);
+        for (x, y) in [(-0.3, 0.), (-0.1, 0.), (0., 0.06), (0.1, 0.), (0.3, 0.)] {
+            sgl::v2f(center.x + x * radius, center.y + y * radius);
+        }
+        sgl::end();
+
+        sdtx::canvas(width / 2., height / 2.);
+        sdtx::origin(0., 0.);
+        sdtx::color3f(r, g, b);
+        let text = |x: f32, y: f32, line: &str| {
+            sdtx::pos(x / CELL, y / CELL);
+            sdtx::puts(line);
+        };
+        text(
+            center.x - 4. * CELL,
+            center.y - radius - 1.5 * CELL,
+            &format!("HDG {:03.0}", instruments.heading),
+        );
+        text(
+            center.x - radius - 9. * CELL,
+            center.y - CELL,
+            &format!("SPD{:6.1}", instruments.airspeed),
+        );
+        text(
+            center.x - radius - 9. * CELL,
+            center.y + CELL,
+            &format!("AOA{:6.1}", instruments.angle_of_attack),
+        );
+        text(
+            center.x + radius + CELL,
+            center.y - CELL,
+            &format!("ALT{:7.2}", instruments.altitude),
+        );
+        text(
+            center.x + radius + CELL,
+            center.y + CELL,
+            &format!("PIT{:7.1}", instruments.pitch),
+        );
+        let centered = |y: f32, line: &str| text(center.x - line.len() as f32 * CELL / 2., y, line);
+        centered(
+            center.y + radius + 0.5 * CELL,
+            &format!("TICK {:>6}  T {:>7.2}s", instruments.tick, instruments.time),
+        );
+        let [r, g, b] = if healthy { GREEN } else { RED };
+        sdtx::color3f(r, g, b);
+        centered(center.y + radius + 1.5 * CELL, status);
+    }
+}
+
+// red lines across the top of the screen, drawn whether or not the hud is visible. the characters
+// are half the usual size so paths fit
+pub fn warnings(width: f32, height: f32, lines: &[String]) {
+    let [r, g, b] = RED;
+    sdtx::canvas(width, height);
+    sdtx::origin(0., 0.);
+    sdtx::color3f(r, g, b);
+    for (row, line) in lines.iter().enumerate() {
+        let x = ((width * 2. / CELL - line.len() as f32) / 2.).max(0.);
+        sdtx::pos(x, 0.5 + row as f32);
+        sdtx::puts(line);
+    }
+}
+
+// names the vehicle a split-screen viewport shows in its tint, with a dividing line along the
+// viewport's left edge. `column` is the viewport's left edge and width in the window
+pub fn caption(width: f32, height: f32, column: (f32, f32), name: &str, [r, g, b]: [f32; 3]) {
+    let (left, column_width) = column;
+    sgl::defaults();
+    sgl::load_default_pipeline();
+    sgl::matrix_mode_projection();
+    sgl::ortho(0., width, height, 0., -1., 1.);
+    if left > 0. {
+        sgl::begin_lines();
+        sgl::c3f(0.05, 0.05, 0.05);
+        sgl::v2f(left, 0.);
+        sgl::v2f(left, height);
+        sgl::end();
+    }
+    sdtx::canvas(width / 2., height / 2.);
+    sdtx::origin(0., 0.);
+    sdtx::color3f(r, g, b);
+    sdtx::pos(
+        (left + column_width / 2.) / CELL - name.len() as f32 / 2.,
+        1.,
+    );
+    sdtx::puts(name);
+}
@@ -0,0 +1,54 @@
+use glam as glm;
+
+use crate::model::Material;
+use crate::shaders;
+
+// directional sun light, angles in degrees
+pub struct Lighting {
+    pub azimuth: f32,
+    pub elevation: f32,
+    pub color: [f32; 3],
+    pub ambient: f32,
+    pub specular: f32,
+    pub shininess: f32,
+}
+
+impl Default for Lighting {
+    fn default() -> Lighting {
+        Lighting {
+            azimuth: 35.,
+            elevation: 50.,
+            color: [1., 0.97, 0.9],
+            ambient: 0.3,
+            specular: 0.4,
+            shininess: 32.,
+        }
+    }
+}
+
+impl Lighting {
+    // direction the light travels in, from the sun towards the scene
+    pub fn direction(&self) -> glm::Vec3 {
+        let (azimuth, elevation) = (self.azimuth.to_radians(), self.elevation.to_radians());
+        -glm::Vec3::new(
+            elevation.cos() * azimuth.sin(),
+            elevation.sin(),
+            elevation.cos() * azimuth.cos(),
+        )
+    }
+
+    // blinn-phong standing in for the metallic-roughness model: rougher materials spread the
+    // highlight wider, metals reflect more of the light. a roughness of 0.5 on a dielectric keeps
+    // the sliders as they are
+    pub fn fs_params(&self, eye: glm::Vec3, material: &Material) -> shaders::FsParams {
+        shaders::FsParams {
+            light_direction: self.direction().to_array(),
+            ambient: self.ambient,
+            eye_position: eye.to_array(),
+            shininess: self.shininess * 4f32.powf(1. - 2. * material.roughness),
+            light_color: self.color,
+            specular: self.specular * (1. + 2. * material.metallic),
+            base_color: material.base_color,
+        }
+    }
+}
@@ -0,0 +1,667 @@
+mod assets;
+mod camera;
+mod capture;
+mod cli;
+mod commands;
+mod fleet;
+mod foreign_functions;
+mod ground;
+mod gui;
+mod hud;
+mod lighting;
+mod mapping;
+mod markers;
+mod mesh;
+mod model;
+mod panels;
+mod plots;
+mod raster;
+mod readback;
+#[allow(dead_code)]
+mod shaders {
+    // src/shaders.glsl compiled by build.rs
+    include!(concat!(env!("OUT_DIR"), "/shaders.rs"));
+}
+mod shadows;
+mod sky;
+mod surfaces;
+mod trails;
+mod vectors;
+
+use std::ffi::c_void;
+use std::ops::Range;
+use std::process;
+
+use assets::Assets;
+use camera::Inputs;
+use camera::{Camera, CameraMode};
+use capture::Capture;
+use cli::{Command, ViewArgs};
+use fleet::{Fleet, Vehicle};
+use ground::Ground;
+use gui::Gui;
+use hud::{Hud, Instruments};
+use lighting::Lighting;
+use mapping::StateMapping;
+use markers::Markers;
+use model::Model;
+use panels::SimControls;
+use plots::StripChart;
+use sandbox_core::rng::RngService;
+use shadows::ShadowMap;
+use sky::Sky;
+use sokol::app as sap;
+use sokol::debugtext as sdtx;
+use sokol::gfx;
+use sokol::gl as sgl;
+use sokol::glue;
+use sokol::log;
+use surfaces::ControlSurfaces;
+use trails::Trails;
+use vectors::Vectors;
+
+use glam as glm;
+
+use foreign_functions::*;
+use sandbox_core::simulation::State;
+use sokol::time;
+
+const HEIGHT: i32 = 600;
+const WIDTH: i32 = 800;
+// plant position the controller is asked to hold
+const SETPOINT: f32 = 0.;
+
+fn main() {
+    let command = Command::parse(std::env::args()).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(2);
+    });
+    match command {
+        Command::View(args) => view(&args),
+        Command::Grade(args) => commands::grade(&args),
+        Command::MonteCarlo(args) => commands::monte_carlo(&args),
+        Command::Render(args) => commands::render(&args),
+    }
+}
+
+fn view(args: &ViewArgs) {
+    let mut initial_state = State {
+        position: 70.,
+        delta_time: 0.01,
+        ..Default::default()
+    };
+    let mut camera = Camera::new();
+    let mut tunable_values = args.tunables.clone();
+    if let Some(path) = &args.scenario {
+        let scenario = commands::load_scenario(path, &args.tunables);
+        initial_state = scenario.simulation().state;
+        tunable_values = scenario.tunables.into_iter().collect();
+        if let Some(name) = &scenario.camera {
+            camera.mode = CameraMode::parse(name).unwrap_or_else(|| {
+                eprintln!("unknown camera `{name}` in {}", path.display());
+                process::exit(2);
+            });
+        }
+    }
+    if let Some(mode) = args.camera {
+        camera.mode = mode;
+    }
+    let scene = Assets::new(args.assets.as_deref()).load_scene(&args.model);
+    let mut controls = SimControls::new(initial_state, RngService::new(args.seed));
+    let mut capture = Capture::default();
+    if let Some(target) = &args.record {
+        // one video frame per rendered frame, stepping the simulation to play back in real time
+        // however long each frame takes to draw
+        controls.recording_rate = Some(1. / (args.fps * initial_state.delta_time));
+        capture.start(target.clone(), args.fps);
+        capture.frame_limit = args
+            .duration
+            .map(|duration| (duration * args.fps).ceil() as usize);
+    }
+    // compared vehicles fly the same lane, each in its own viewport
+    let spacing = if args.compare { 0. } else { args.spacing };
+    let mut fleet = Fleet::new(&args.vehicles, spacing, &tunable_values).unwrap_or_else(|err| {
+        eprintln!("failed to load a vehicle: {err}");
+        process::exit(1);
+    });
+    fleet.start(initial_state, args.seed);
+    let global_state = Box::new(GlobalState {
+        fleet,
+        compare: args.compare,
+        camera,
+        model: scene.model,
+        surfaces: scene.surfaces,
+        grid: scene.grid,
+        warnings: scene.warnings,
+        mapping: args.mapping,
+        controls,
+        capture,
+        ..Default::default()
+    });
+    for (name, value) in &tunable_values {
+        tunables().set(name, *value);
+    }
+    let user_data = Box::into_raw(global_state) as *mut c_void;
+
+    sap::run(&sap::Desc {
+        user_data,
+        init_userdata_cb: Some(ffi_cb_init),
+        event_userdata_cb: Some(ffi_cb_event),
+        frame_userdata_cb: Some(ffi_cb_frame),
+        cleanup_userdata_cb: Some(ffi_cb_cleanup),
+        width: 800,
+        height: 600,
+        window_title: c"control sandbox".as_ptr(),
+        fullscreen: false,
+        high_dpi: true,
+        logger: sap::Logger {
+            func: Some(log::slog_func),
+            user_data,
+        },
+        icon: sap::IconDesc {
+            sokol_default: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+}
+
+struct Transform {
+    position: glm::Vec3,
+    rotation: glm::Quat,
+    scale: glm::Vec3,
+}
+
+impl Default for Transform {
+    fn default() -> Transform {
+        Transform {
+            position: glm::Vec3::ZERO,
+            rotation: glm::Quat::IDENTITY,
+            scale: glm::Vec3::ONE,
+        }
+    }
+}
+
+impl Transform {
+    fn to_matrix(&self) -> glm::Mat4 {
+        glm::Mat4::from_scale_rotation_translation(self.scale, self.rotation, self.position)
+    }
+}
+
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+struct Vertex {
+    position: [f32; 3],
+    normal: [f32; 3],
+    texcoord: [f32; 2],
+    // along increasing u, w is 1 or -1 for the direction of increasing v across the normal
+    tangent: [f32; 4],
+}
+
+#[derive(Default)]
+struct GlobalState {
+    fleet: Fleet,
+    // one viewport per vehicle instead of all of them in one
+    compare: bool,
+    pipeline: gfx::Pipeline,
+    bindings: gfx::Bindings,
+    pass_action: gfx::PassAction,
+    model: Model,
+    surfaces: ControlSurfaces,
+    // one per material of the model
+    textures: Vec<gfx::Image>,
+    // the ground texture, until it is uploaded
+    grid: image::RgbaImage,
+    // assets that were missing, shown on screen for as long as the viewer runs
+    warnings: Vec<String>,
+    camera: Camera,
+    inputs: Inputs,
+    mapping: StateMapping,
+    chart: StripChart,
+    gui: Gui,
+    controls: SimControls,
+    hud: Hud,
+    lighting: Lighting,
+    shadow_map: ShadowMap,
+    ground: Ground,
+    sky: Sky,
+    markers: Markers,
+    trails: Trails,
+    vectors: Vectors,
+    capture: Capture,
+}
+
+impl GlobalState {
+    fn callback_init(&mut self, self_c_ptr: *mut c_void) {
+        time::setup();
+        gfx::setup(&gfx::Desc {
+            environment: glue::environment(),
+            logger: gfx::Logger {
+                func: Some(log::slog_func),
+                user_data: self_c_ptr,
+            },
+            ..Default::default()
+        });
+        sgl::setup(&sgl::Desc {
+            // room for the gui meshes on top of the chart
+            max_vertices: 1 << 17,
+            max_commands: 1 << 14,
+            logger: sgl::Logger {
+                func: Some(log::slog_func),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        sdtx::setup(&sdtx::Desc {
+            fonts: {
+                let mut fonts = [sdtx::FontDesc::default(); 8];
+                fonts[0] = sdtx::font_kc853();
+                fonts
+            },
+            logger: sdtx::Logger {
+                func: Some(log::slog_func),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        self.chart.init();
+        self.gui.init();
+
+        self.bindings.vertex_buffers[0] = gfx::make_buffer(&gfx::BufferDesc {
+            data: gfx::slice_as_range(&self.model.vertices),
+            label: c"model vertices".as_ptr(),
+            ..Default::default()
+        });
+        self.bindings.index_buffer = gfx::make_buffer(&gfx::BufferDesc {
+            _type: gfx::BufferType::Indexbuffer,
+            data: gfx::slice_as_range(&self.model.indices),
+            label: c"model indices".as_ptr(),
+            ..Default::default()
+        });
+
+        self.shadow_map
+            .init(self.bindings.vertex_buffers[0], self.bindings.index_buffer);
+        self.ground.init(&self.shadow_map, &self.grid);
+        self.sky.init();
+        self.markers.init();
+        self.trails.init(
+            self.bindings.vertex_buffers[0],
+            self.bindings.index_buffer,
+            self.fleet.vehicles.len(),
+        );
+
+        // materials without a texture sample plain white, leaving just their base color
+        let white = image::RgbaImage::from_pixel(1, 1, image::Rgba([255; 4]));
+        for material in &self.model.materials {
+            let img = material.texture.as_ref().unwrap_or(&white);
+            let (width, height) = img.dimensions();
+            self.textures.push(gfx::make_image(&gfx::ImageDesc {
+                width: width as i32,
+                height: height as i32,
+                pixel_format: gfx::PixelFormat::Rgba8,
+                data: {
+                    let mut subimage = gfx::ImageData::new();
+                    subimage.subimage[0][0] = gfx::slice_as_range(img.as_raw());
+                    subimage
+                },
+                ..Default::default()
+            }));
+        }
+        let sampler = gfx::make_sampler(&gfx::SamplerDesc {
+            min_filter: gfx::Filter::Linear,
+            mag_filter: gfx::Filter::Linear,
+            ..Default::default()
+        });
+        self.bindings.samplers[shaders::SMP_SAMP] = sampler;
+        self.pipeline = gfx::make_pipeline(&gfx::PipelineDesc {
+            shader: gfx::make_shader(&shaders::texture_shader_desc(gfx::query_backend())),
+            primitive_type: gfx::PrimitiveType::Triangles,
+            index_type: gfx::IndexType::Uint32,
+            cull_mode: gfx::CullMode::None,
+            depth: gfx::DepthState {
+                compare: gfx::CompareFunc::Less,
+                write_enabled: true,
+                ..Default::default()
+            },
+            layout: {
+                let mut layout = gfx::VertexLayoutState::new();
+                layout.buffers[0].stride = std::mem::size_of::<Vertex>() as i32;
+                layout.attrs[shaders::ATTR_TEXTURE_POSITION].format = gfx::VertexFormat::Float3;
+                layout.attrs[shaders::ATTR_TEXTURE_V_NORMAL].format = gfx::VertexFormat::Float3;
+                layout.attrs[shaders::ATTR_TEXTURE_V_TEXCOORD].format = gfx::VertexFormat::Float2;
+                layout
+            },
+            ..Default::default()
+        });
+        self.pass_action.colors[0] = gfx::ColorAttachmentAction {
+            load_action: gfx::LoadAction::Clear,
+            clear_value: gfx::Color {
+                r: 0.3,
+                g: 0.6,
+                b: 0.9,
+                a: 1.,
+            },
+            ..Default::default()
+        };
+    }
+
+    fn callback_event(&mut self, event: &sap::Event) {
+        if !self.gui.handle_event(event) {
+            self.inputs.get_inputs(event);
+        }
+    }
+
+    fn tick(&mut self) {
+        let disturbance = self.controls.disturbance();
+        self.fleet.tick(disturbance, SETPOINT);
+
+        let vehicles = &self.fleet.vehicles;
+        let sample = vehicles[0]
+            .trajectory
+            .samples
+            .last()
+            .copied()
+            .unwrap_or_default();
+        if self.compare {
+            // the controllers' runs overlaid against the setpoint they share
+            self.chart.push("setpoint", sample.time, sample.setpoint);
+            for vehicle in vehicles {
+                let sample = vehicle
+                    .trajectory
+                    .samples
+                    .last()
+                    .copied()
+                    .unwrap_or_default();
+                self.chart.push(
+                    &format!("{} position", vehicle.name),
+                    sample.time,
+                    sample.position,
+                );
+                self.chart.push(
+                    &format!("{} force", vehicle.name),
+                    sample.time,
+                    sample.force,
+                );
+            }
+            for (name, value) in published_values() {
+                self.chart.push(&name, sample.time, value);
+            }
+        } else {
+            // the linked controller's run in full, only the positions of the others next to it
+            self.chart.push("position", sample.time, sample.position);
+            self.chart.push("velocity", sample.time, sample.velocity);
+            self.chart.push("setpoint", sample.time, sample.setpoint);
+            self.chart.push("force", sample.time, sample.force);
+            for (name, value) in published_values() {
+                self.chart.push(&name, sample.time, value);
+            }
+            for vehicle in &vehicles[1..] {
+                let sample = vehicle
+                    .trajectory
+                    .samples
+                    .last()
+                    .copied()
+                    .unwrap_or_default();
+                self.chart.push(
+                    &format!("{} position", vehicle.name),
+                    sample.time,
+                    sample.position,
+                );
+            }
+        }
+    }
+
+    // one line about the focused controller's output for the hud, unhealthy once it stops being
+    // finite
+    fn controller_status(&self) -> (String, bool) {
+        let focused = self.fleet.focused();
+        let force = focused.simulation.control.force;
+        let mut mode = if self.controls.paused {
+            String::from("paused")
+        } else {
+            format!("x{:.1}", self.controls.time_scale)
+        };
+        if self.fleet.vehicles.len() > 1 {
+            mode = format!("{mode}  {}", focused.name);
+        }
+        if force.is_finite() {
+            (format!("CTRL OK  F {force:>8.3}  {mode}"), true)
+        } else {
+            (format!("CTRL FAULT  F {force}  {mode}"), false)
+        }
+    }
+
+    // the sky, the ground and the vehicles in `shown` with their trails into the current viewport,
+    // the markers and vectors of `subject`. `column` is the viewport's left edge and width
+    fn draw_scene(
+        &mut self,
+        shown: Range<usize>,
+        subject: usize,
+        column: (i32, i32),
+        travel: f32,
+        setpoint: &Transform,
+        transforms: &[Vec<glm::Mat4>],
+    ) {
+        let projection = self.camera.projection_matrix();
+        let view = self.camera.view_matrix();
+        self.sky.draw(&self.camera, &self.lighting);
+        self.ground
+            .draw(&self.camera, travel, &self.shadow_map, &self.lighting);
+        gfx::apply_pipeline(self.pipeline);
+        for (vehicle, transforms) in self.fleet.vehicles[shown.clone()]
+            .iter()
+            .zip(&transforms[shown])
+        {
+            for (mesh, transform) in self.model.meshes.iter().zip(transforms) {
+                self.bindings.images[shaders::IMG_TEX] = self.textures[mesh.material];
+                gfx::apply_bindings(&self.bindings);
+                let vs_params = [*transform, view, projection];
+                gfx::apply_uniforms(shaders::UB_VS_PARAMS, &gfx::slice_as_range(&vs_params));
+                let mut fs_params = self
+                    .lighting
+                    .fs_params(self.camera.position, &self.model.materials[mesh.material]);
+                for (channel, tint) in fs_params.base_color.iter_mut().zip(vehicle.tint) {
+                    *channel *= tint;
+                }
+                gfx::apply_uniforms(shaders::UB_FS_PARAMS, &gfx::value_as_range(&fs_params));
+                gfx::draw(mesh.first, mesh.count, 1);
+            }
+        }
+        self.trails
+            .draw(&self.camera, setpoint, &self.model, &self.lighting);
+
+        let subject = &self.fleet.vehicles[subject];
+        self.markers
+            .draw(&self.camera, travel, &subject.transform, setpoint);
+        self.vectors.draw(
+            &self.camera,
+            (column.0 as f32, column.1 as f32),
+            sap::heightf(),
+            &subject.simulation,
+            &self.mapping,
+            &subject.transform,
+        );
+    }
+
+    fn callback_frame(&mut self) {
+        if std::mem::take(&mut self.controls.reset) {
+            self.fleet.restart(&mut self.controls);
+            self.chart.clear();
+            self.trails.clear();
+        }
+        let steps = self.controls.steps();
+        for _ in 0..steps {
+            self.tick();
+        }
+        self.chart.handle_inputs(&mut self.inputs);
+        self.hud.handle_inputs(&mut self.inputs);
+        self.markers.handle_inputs(&mut self.inputs);
+        self.trails.handle_inputs(&mut self.inputs);
+        self.vectors.handle_inputs(&mut self.inputs);
+        self.capture.handle_inputs(&mut self.inputs);
+        if !self.capture.recording() {
+            self.controls.recording_rate = None;
+        }
+        self.fleet.handle_inputs(&mut self.inputs);
+
+        self.fleet.place(&self.mapping);
+        let focused = self.fleet.focused();
+        let state = focused.simulation.state;
+        self.camera
+            .update(&mut self.inputs, 0.01, &focused.transform);
+        let mut setpoint = Transform::default();
+        let settled = State {
+            position: SETPOINT,
+            velocity: 0.,
+            ..state
+        };
+        self.mapping.apply(&settled, &mut setpoint);
+        setpoint.position += focused.lane_offset(&self.mapping);
+        let travel = state.tick as f32 * state.delta_time * self.mapping.velocity(&state).x;
+        if steps > 0 {
+            let aircraft = self
+                .fleet
+                .vehicles
+                .iter()
+                .map(|vehicle| vehicle.transform.position)
+                .collect();
+            self.trails.push(travel, aircraft, &setpoint);
+        }
+
+        // each aircraft with its surfaces deflected by its own controller
+        let transforms: Vec<Vec<glm::Mat4>> = self
+            .fleet
+            .vehicles
+            .iter()
+            .map(|vehicle| {
+                self.surfaces
+                    .update(&vehicle.simulation, &self.mapping, &mut self.model);
+                self.model.mesh_transforms(&vehicle.transform)
+            })
+            .collect();
+
+        let positions: Vec<glm::Vec3> = self
+            .fleet
+            .vehicles
+            .iter()
+            .map(|vehicle| vehicle.transform.position)
+            .collect();
+        self.shadow_map.update(&self.lighting, &positions);
+        self.shadow_map.draw(&self.model, &transforms);
+
+        gfx::begin_pass(&self.capture.pass(self.pass_action));
+        self.trails.update(travel, &setpoint);
+        // side by side columns of the same camera, one per vehicle when comparing
+        let columns = if self.compare {
+            self.fleet.vehicles.len()
+        } else {
+            1
+        };
+        let aspect_ratio = self.camera.aspect_ratio;
+        for column in 0..columns {
+            let left = sap::width() * column as i32 / columns as i32;
+            let width = sap::width() * (column as i32 + 1) / columns as i32 - left;
+            gfx::apply_viewport(left, 0, width, sap::height(), false);
+            sgl::viewport(left, 0, width, sap::height(), false);
+            self.camera.aspect_ratio = width as f32 / sap::heightf();
+            let (shown, subject) = if self.compare {
+                (column..column + 1, column)
+            } else {
+                (0..self.fleet.vehicles.len(), self.fleet.focus)
+            };
+            self.draw_scene(
+                shown,
+                subject,
+                (left, width),
+                travel,
+                &setpoint,
+                &transforms,
+            );
+        }
+        self.camera.aspect_ratio = aspect_ratio;
+        gfx::apply_viewport(0, 0, sap::width(), sap::height(), false);
+        sgl::viewport(0, 0, sap::width(), sap::height(), false);
+        if self.compare {
+            for (column, vehicle) in self.fleet.vehicles.iter().enumerate() {
+                let left = sap::widthf() * column as f32 / columns as f32;
+                hud::caption(
+                    sap::widthf(),
+                    sap::heightf(),
+                    (left, sap::widthf() / columns as f32),
+                    &vehicle.name,
+                    vehicle.tint,
+                );
+            }
+        }
+
+        let focused = self.fleet.focused();
+        let instruments = Instruments::measure(
+            &focused.simulation.state,
+            &focused.transform,
+            self.mapping.velocity(&focused.simulation.state),
+        );
+        let (status, healthy) = self.controller_status();
+        self.hud.draw(
+            sap::widthf(),
+            sap::heightf(),
+            &instruments,
+            &status,
+            healthy,
+        );
+        self.chart.draw(sap::widthf(), sap::heightf());
+        hud::warnings(sap::widthf(), sap::heightf(), &self.warnings);
+        let published = published_values();
+        let compare = self.compare;
+        let (controls, fleet, lighting, vectors) = (
+            &mut self.controls,
+            &mut self.fleet,
+            &mut self.lighting,
+            &mut self.vectors,
+        );
+        self.gui.run(|ctx| {
+            egui::Window::new("sandbox")
+                .default_pos([10., 10.])
+                .default_width(260.)
+                .show(ctx, |ui| {
+                    if fleet.vehicles.len() > 1 {
+                        egui::CollapsingHeader::new("vehicles")
+                            .default_open(true)
+                            .show(ui, |ui| panels::vehicles(ui, fleet));
+                    }
+                    // every vehicle's while comparing, the focused one's otherwise
+                    let (title, shown): (&str, Vec<&Vehicle>) = if compare {
+                        ("comparison", fleet.vehicles.iter().collect())
+                    } else {
+                        ("metrics", vec![fleet.focused()])
+                    };
+                    egui::CollapsingHeader::new(title)
+                        .default_open(true)
+                        .show(ui, |ui| panels::metrics(ui, &shown));
+                    let simulation = &mut fleet.focused_mut().simulation;
+                    egui::CollapsingHeader::new("simulation")
+                        .default_open(true)
+                        .show(ui, |ui| {
+                            panels::sim_controls(ui, controls, &simulation.state)
+                        });
+                    egui::CollapsingHeader::new("plant")
+                        .show(ui, |ui| panels::plant(ui, simulation));
+                    egui::CollapsingHeader::new("disturbance")
+                        .show(ui, |ui| panels::disturbance(ui, controls));
+                    egui::CollapsingHeader::new("lighting")
+                        .show(ui, |ui| panels::lighting(ui, lighting));
+                    egui::CollapsingHeader::new("vectors")
+                        .show(ui, |ui| panels::vectors(ui, vectors));
+                    egui::CollapsingHeader::new("controller")
+                        .default_open(true)
+                        .show(ui, |ui| panels::controller(ui, &mut tunables(), &published));
+                });
+        });
+        sgl::draw();
+        sdtx::draw();
+        self.capture.present();
+
+        gfx::end_pass();
+        gfx::commit();
+        self.capture.capture();
+    }
+}
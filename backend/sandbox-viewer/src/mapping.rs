@@ -0,0 +1,118 @@
+use glam as glm;
+
+use sandbox_core::simulation::State;
+
+use crate::Transform;
+use crate::surfaces::Axis;
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MappingKind {
+    #[default]
+    Altitude,
+    Pitch,
+    Lateral,
+}
+
+// how the 1-d plant state is shown on the aircraft, `scale` converts plant units to world units
+// (or to degrees for the pitch mapping). the model's nose points along +x with the right wing on +z
+#[derive(Debug, Clone, Copy)]
+pub struct StateMapping {
+    pub kind: MappingKind,
+    pub scale: f32,
+}
+
+// forward speed used to turn a vertical or sideways rate into a flight path angle
+const CRUISE_SPEED: f32 = 20.;
+// distance from the model's origin to its nose, in model units
+const NOSE: f32 = 9.;
+
+impl Default for StateMapping {
+    fn default() -> StateMapping {
+        StateMapping {
+            kind: MappingKind::Altitude,
+            scale: MappingKind::Altitude.default_scale(),
+        }
+    }
+}
+
+impl MappingKind {
+    pub fn parse(name: &str) -> Option<MappingKind> {
+        match name {
+            "altitude" => Some(MappingKind::Altitude),
+            "pitch" => Some(MappingKind::Pitch),
+            "lateral" => Some(MappingKind::Lateral),
+            _ => None,
+        }
+    }
+
+    pub fn default_scale(self) -> f32 {
+        match self {
+            MappingKind::Altitude | MappingKind::Lateral => 0.05,
+            MappingKind::Pitch => 1.,
+        }
+    }
+}
+
+impl StateMapping {
+    pub fn apply(&self, state: &State, transform: &mut Transform) {
+        match self.kind {
+            MappingKind::Altitude => {
+                transform.position = glm::Vec3::new(0., state.position * self.scale, 0.);
+                let climb = (state.velocity * self.scale).atan2(CRUISE_SPEED * self.scale);
+                transform.rotation = glm::Quat::from_rotation_z(climb);
+            }
+            MappingKind::Pitch => {
+                transform.position = glm::Vec3::ZERO;
+                let pitch = (state.position * self.scale).to_radians();
+                transform.rotation = glm::Quat::from_rotation_z(pitch);
+            }
+            MappingKind::Lateral => {
+                transform.position = glm::Vec3::new(0., 0., state.position * self.scale);
+                let bank = (state.velocity * self.scale).atan2(CRUISE_SPEED * self.scale);
+                transform.rotation = glm::Quat::from_rotation_x(bank);
+            }
+        }
+    }
+
+    // where a positive plant force acts on the aircraft and the world direction it pushes in. for
+    // the pitch mapping the force is a pitching moment, shown lifting the nose
+    pub fn force_axis(&self, transform: &Transform) -> (glm::Vec3, glm::Vec3) {
+        match self.kind {
+            MappingKind::Altitude => (transform.position, glm::Vec3::Y),
+            MappingKind::Pitch => (
+                transform.position + transform.rotation * glm::Vec3::X * NOSE * transform.scale.x,
+                transform.rotation * glm::Vec3::Y,
+            ),
+            MappingKind::Lateral => (transform.position, glm::Vec3::Z),
+        }
+    }
+
+    // the part of a plant force the control surfaces on `axis` act on: the elevators pitch the
+    // aircraft up and down, sideways it banks and yaws into the turn
+    pub fn surface_command(&self, axis: Axis, force: f32) -> f32 {
+        match (self.kind, axis) {
+            (MappingKind::Altitude | MappingKind::Pitch, Axis::Pitch) => force,
+            (MappingKind::Lateral, Axis::Roll | Axis::Yaw) => force,
+            _ => 0.,
+        }
+    }
+
+    // the direction the plant state never moves the aircraft in besides forward, where vehicles
+    // flying together are spread apart
+    pub fn lane_axis(&self) -> glm::Vec3 {
+        match self.kind {
+            MappingKind::Altitude | MappingKind::Pitch => glm::Vec3::Z,
+            MappingKind::Lateral => glm::Vec3::Y,
+        }
+    }
+
+    // world-space velocity of the aircraft, flying along +x at the cruise speed
+    pub fn velocity(&self, state: &State) -> glm::Vec3 {
+        let cruise = CRUISE_SPEED * self.scale;
+        match self.kind {
+            MappingKind::Altitude => glm::Vec3::new(cruise, state.velocity * self.scale, 0.),
+            MappingKind::Pitch => glm::Vec3::new(cruise, 0., 0.),
+            MappingKind::Lateral => glm::Vec3::new(cruise, 0., state.velocity * self.scale),
+        }
+    }
+}
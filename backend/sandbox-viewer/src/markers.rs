@@ -0,0 +1,131 @@
+use glam as glm;
+
+use sokol::app as sap;
+use sokol::gfx;
+use sokol::gl as sgl;
+
+use crate::Transform;
+use crate::camera::{Camera, Inputs};
+use crate::ground;
+
+const POLE_SPACING: f32 = 10.;
+const POLE_HEIGHT: f32 = 20.;
+// distance of the two pole rows either side of the flight path
+const POLE_ROW: f32 = 15.;
+const POLES: i32 = 6;
+// every fifth altitude tick is drawn longer
+const MAJOR_TICK: i32 = 5;
+// how far ahead of the aircraft the setpoint gate and the boresight reach
+const AHEAD: f32 = 15.;
+const GATE_SIZE: f32 = 2.5;
+
+const POLE_COLOR: [f32; 3] = [0.95, 0.95, 0.95];
+const GATE_COLOR: [f32; 3] = [1., 0.8, 0.2];
+const BORESIGHT_COLOR: [f32; 3] = [0.3, 1., 0.45];
+const ERROR_COLOR: [f32; 3] = [1., 0.35, 0.3];
+
+// world-space reference lines: altitude poles passing by at the cruise speed, a gate where the
+// setpoint would put the aircraft and a line showing the error to it
+pub struct Markers {
+    pub visible: bool,
+    pipeline: sgl::Pipeline,
+}
+
+impl Default for Markers {
+    fn default() -> Markers {
+        Markers {
+            visible: true,
+            pipeline: sgl::Pipeline::default(),
+        }
+    }
+}
+
+impl Markers {
+    pub fn init(&mut self) {
+        // depth tested so the aircraft hides the lines behind it, without writing depth itself
+        self.pipeline = sgl::make_pipeline(&gfx::PipelineDesc {
+            depth: gfx::DepthState {
+                compare: gfx::CompareFunc::LessEqual,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+    }
+
+    pub fn handle_inputs(&mut self, inputs: &mut Inputs) {
+        if inputs.take_press(sap::Keycode::M) {
+            self.visible = !self.visible;
+        }
+    }
+
+    // queues sokol-gl commands, drawn by the `sgl::draw` of the pass. `setpoint` is where the
+    // mapping puts the aircraft when the plant sits exactly on its setpoint
+    pub fn draw(&self, camera: &Camera, travel: f32, aircraft: &Transform, setpoint: &Transform) {
+        if !self.visible {
+            return;
+        }
+        sgl::defaults();
+        sgl::load_pipeline(self.pipeline);
+        sgl::matrix_mode_projection();
+        sgl::perspective(camera.fov, camera.aspect_ratio, camera.near, camera.far);
+        sgl::matrix_mode_modelview();
+        let center = camera.position + camera.front;
+        sgl::lookat(
+            camera.position.x,
+            camera.position.y,
+            camera.position.z,
+            center.x,
+            center.y,
+            center.z,
+            camera.up.x,
+            camera.up.y,
+            camera.up.z,
+        );
+
+        sgl::begin_lines();
+        let line = |from: glm::Vec3, to: glm::Vec3, [r, g, b]: [f32; 3]| {
+            sgl::v3f_c3f(from.x, from.y, from.z, r, g, b);
+            sgl::v3f_c3f(to.x, to.y, to.z, r, g, b);
+        };
+
+        // poles around the camera, sliding back as the aircraft flies on
+        let first = (camera.position.x / POLE_SPACING).round() as i32 - POLES;
+        let offset = travel.rem_euclid(POLE_SPACING);
+        let bottom = ground::HEIGHT.ceil() as i32;
+        for row in [-POLE_ROW, POLE_ROW] {
+            for pole in first..=first + 2 * POLES {
+                let x = pole as f32 * POLE_SPACING - offset;
+                let base = glm::Vec3::new(x, ground::HEIGHT, row);
+                line(base, base + glm::Vec3::Y * POLE_HEIGHT, POLE_COLOR);
+                // ticks on whole world heights, so they line up across poles and with the gate
+                for height in bottom..=bottom + POLE_HEIGHT as i32 {
+                    let length = if height % MAJOR_TICK == 0 { 0.8 } else { 0.3 };
+                    let tick = glm::Vec3::new(x, height as f32, row);
+                    line(tick, tick + glm::Vec3::X * length, POLE_COLOR);
+                }
+            }
+        }
+
+        // square gate across the setpoint's flight path
+        let forward = setpoint.rotation * glm::Vec3::X;
+        let up = setpoint.rotation * glm::Vec3::Y * GATE_SIZE;
+        let right = setpoint.rotation * glm::Vec3::Z * GATE_SIZE;
+        let gate = setpoint.position + forward * AHEAD;
+        let corners = [
+            gate + up + right,
+            gate + up - right,
+            gate - up - right,
+            gate - up + right,
+        ];
+        for (i, corner) in corners.iter().enumerate() {
+            line(*corner, corners[(i + 1) % corners.len()], GATE_COLOR);
+        }
+        line(setpoint.position, gate, GATE_COLOR);
+
+        // where the aircraft points, and how far it is from the setpoint
+        let boresight = aircraft.position + aircraft.rotation * glm::Vec3::X * AHEAD;
+        line(aircraft.position, boresight, BORESIGHT_COLOR);
+        line(aircraft.position, setpoint.position, ERROR_COLOR);
+        sgl::end();
+    }
+}
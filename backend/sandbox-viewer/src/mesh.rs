@@ -0,0 +1,618 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use glam as glm;
+
+use crate::Vertex;
+use crate::model::{self, Material, Mesh, Model, Node};
+
+const CACHE_MAGIC: &[u8; 8] = b"sbxmesh\0";
+// bumped whenever the layout of the cache files changes
+const CACHE_VERSION: u32 = 2;
+
+// indexed triangles, vertices that agree in every attribute are stored once
+#[derive(Default)]
+pub struct Geometry {
+    vertices: Vec<Vertex>,
+    indices: Vec<u32>,
+    lookup: HashMap<[u32; 12], u32>,
+}
+
+impl Geometry {
+    // appends a triangle list, three vertices per triangle, and returns where its indices start
+    // and how many there are. a zero normal or tangent marks one the file left out, see `finish`
+    pub fn push(&mut self, triangles: impl IntoIterator<Item = Vertex>) -> (usize, usize) {
+        let first = self.indices.len();
+        for vertex in triangles {
+            let index = *self.lookup.entry(key(&vertex)).or_insert_with(|| {
+                self.vertices.push(vertex);
+                (self.vertices.len() - 1) as u32
+            });
+            self.indices.push(index);
+        }
+        (first, self.indices.len() - first)
+    }
+
+    // fills in the missing normals, smoothed across every face sharing the position, and the
+    // missing tangents
+    pub fn finish(mut self) -> (Vec<Vertex>, Vec<u32>) {
+        smooth_normals(&mut self.vertices, &self.indices);
+        tangents(&mut self.vertices, &self.indices);
+        (self.vertices, self.indices)
+    }
+}
+
+fn key(vertex: &Vertex) -> [u32; 12] {
+    let mut key = [0; 12];
+    let attributes = vertex
+        .position
+        .iter()
+        .chain(&vertex.normal)
+        .chain(&vertex.texcoord)
+        .chain(&vertex.tangent);
+    for (bits, value) in key.iter_mut().zip(attributes) {
+        *bits = value.to_bits();
+    }
+    key
+}
+
+fn smooth_normals(vertices: &mut [Vertex], indices: &[u32]) {
+    let missing = |vertex: &Vertex| vertex.normal == [0.; 3];
+    if !vertices.iter().any(missing) {
+        return;
+    }
+    // the cross product is as long as the face is large, bigger faces weigh more
+    let mut sums: HashMap<[u32; 3], glm::Vec3> = HashMap::new();
+    for triangle in indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| glm::Vec3::from(vertices[triangle[i] as usize].position));
+        let face = (b - a).cross(c - a);
+        for &index in triangle {
+            let position = vertices[index as usize].position.map(f32::to_bits);
+            *sums.entry(position).or_default() += face;
+        }
+    }
+    for vertex in vertices.iter_mut().filter(|vertex| missing(vertex)) {
+        let sum = sums[&vertex.position.map(f32::to_bits)];
+        vertex.normal = sum.normalize_or(glm::Vec3::Y).to_array();
+    }
+}
+
+// along increasing u in the plane of the normal, with the handedness of increasing v in w
+fn tangents(vertices: &mut [Vertex], indices: &[u32]) {
+    let missing = |vertex: &Vertex| vertex.tangent[3] == 0.;
+    if !vertices.iter().any(missing) {
+        return;
+    }
+    let mut sums = vec![(glm::Vec3::ZERO, glm::Vec3::ZERO); vertices.len()];
+    for triangle in indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| &vertices[triangle[i] as usize]);
+        let edges = [b, c].map(|corner| {
+            (
+                glm::Vec3::from(corner.position) - glm::Vec3::from(a.position),
+                glm::Vec2::from(corner.texcoord) - glm::Vec2::from(a.texcoord),
+            )
+        });
+        let [(edge1, uv1), (edge2, uv2)] = edges;
+        let determinant = uv1.x * uv2.y - uv2.x * uv1.y;
+        if determinant.abs() < f32::EPSILON {
+            continue;
+        }
+        let tangent = (edge1 * uv2.y - edge2 * uv1.y) / determinant;
+        let bitangent = (edge2 * uv1.x - edge1 * uv2.x) / determinant;
+        for &index in triangle {
+            sums[index as usize].0 += tangent;
+            sums[index as usize].1 += bitangent;
+        }
+    }
+    for (vertex, (tangent, bitangent)) in vertices.iter_mut().zip(sums) {
+        if !missing(vertex) {
+            continue;
+        }
+        let normal = glm::Vec3::from(vertex.normal).normalize_or(glm::Vec3::Y);
+        // without texture coordinates to follow, any direction in the plane does
+        let tangent = (tangent - normal * normal.dot(tangent))
+            .try_normalize()
+            .unwrap_or_else(|| normal.any_orthonormal_vector());
+        let handedness = if normal.cross(tangent).dot(bitangent) < 0. {
+            -1.
+        } else {
+            1.
+        };
+        vertex.tangent = tangent.extend(handedness).to_array();
+    }
+}
+
+// preprocessed models are kept in the temp directory, keyed by the path of the source file and
+// checked against the files it is made of, see `stamp`. textures are read again from where they
+// were, relative to the model, and one that cannot be read sends the model back to `load`
+pub fn load_cached(
+    path: &Path,
+    load: impl FnOnce(&Path) -> Result<Model, Box<dyn Error>>,
+) -> Result<Model, Box<dyn Error>> {
+    let stamp = stamp(path)?;
+    let cache = cache_path(path);
+    let directory = path.parent().unwrap_or(Path::new("."));
+    let cached = fs::read(&cache)
+        .ok()
+        .and_then(|bytes| read_cache(&bytes, stamp, directory).ok())
+        .and_then(|mut model| {
+            for material in &mut model.materials {
+                material.texture = material
+                    .texture_path
+                    .as_deref()
+                    .map(model::load_texture)
+                    .transpose()
+                    .ok()?;
+            }
+            Some(model)
+        });
+    if let Some(model) = cached {
+        return Ok(model);
+    }
+    let model = load(path)?;
+    if let Err(err) = write_cache(&cache, &model, stamp, directory) {
+        eprintln!("could not cache {}: {err}", path.display());
+    }
+    Ok(model)
+}
+
+// the source file and everything it pulls in, its material libraries, their textures and the
+// `<stem>_texture` next to it, hashed by size and modification time. missing files are hashed as
+// missing, so adding one invalidates the cache as well
+fn stamp(path: &Path) -> Result<u64, Box<dyn Error>> {
+    let mut hasher = Fnv::default();
+    let (length, modified) = file_stamp(path)?;
+    hasher.u64(length);
+    hasher.u64(modified);
+    for dependency in dependencies(path) {
+        hasher.path(&dependency);
+        match file_stamp(&dependency) {
+            Ok((length, modified)) => {
+                hasher.write(&[1]);
+                hasher.u64(length);
+                hasher.u64(modified);
+            }
+            Err(_) => hasher.write(&[0]),
+        }
+    }
+    Ok(hasher.0)
+}
+
+// size and modification time in nanoseconds
+fn file_stamp(path: &Path) -> Result<(u64, u64), Box<dyn Error>> {
+    let metadata = fs::metadata(path)?;
+    let modified = metadata.modified()?.duration_since(UNIX_EPOCH)?;
+    Ok((metadata.len(), modified.as_nanos() as u64))
+}
+
+// the files an obj reads besides itself, found the way `model::load_obj` finds them
+fn dependencies(path: &Path) -> Vec<PathBuf> {
+    let directory = path.parent().unwrap_or(Path::new("."));
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let mut files: Vec<PathBuf> = ["jpg", "png"]
+        .iter()
+        .map(|extension| directory.join(format!("{stem}_texture.{extension}")))
+        .collect();
+    for library in statements(path, "mtllib") {
+        let library = directory.join(library);
+        files.extend(
+            statements(&library, "map_Kd")
+                .into_iter()
+                .map(|texture| directory.join(texture)),
+        );
+        files.push(library);
+    }
+    files
+}
+
+// the argument of every `keyword` line of an obj or mtl file, none when it cannot be read
+fn statements(path: &Path, keyword: &str) -> Vec<String> {
+    let Ok(bytes) = fs::read(path) else {
+        return Vec::new();
+    };
+    String::from_utf8_lossy(&bytes)
+        .lines()
+        .filter_map(|line| {
+            let argument = line.trim_start().strip_prefix(keyword)?;
+            argument
+                .starts_with(char::is_whitespace)
+                .then(|| argument.trim().to_string())
+        })
+        .collect()
+}
+
+fn cache_path(path: &Path) -> PathBuf {
+    let mut hasher = Fnv::default();
+    hasher.path(&fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf()));
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    std::env::temp_dir()
+        .join("sandbox-viewer")
+        .join(format!("{stem}-{:016x}.mesh", hasher.0))
+}
+
+// 64 bit fnv-1a, the same for every build and run unlike the std hashers, so the cache names and
+// stamps stay put across toolchains
+struct Fnv(u64);
+
+impl Default for Fnv {
+    fn default() -> Fnv {
+        Fnv(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    // length first, so consecutive paths cannot run into each other
+    fn path(&mut self, path: &Path) {
+        let bytes = path.as_os_str().as_encoded_bytes();
+        self.u64(bytes.len() as u64);
+        self.write(bytes);
+    }
+}
+
+// little endian, a header followed by the nodes, materials, meshes, vertices and indices, each
+// preceded by their count
+fn write_cache(
+    cache: &Path,
+    model: &Model,
+    stamp: u64,
+    directory: &Path,
+) -> Result<(), Box<dyn Error>> {
+    let mut writer = Writer::default();
+    writer.bytes(CACHE_MAGIC);
+    writer.u32(CACHE_VERSION);
+    writer.u64(stamp);
+
+    writer.u32(model.nodes.len() as u32);
+    for node in &model.nodes {
+        writer.string(&node.name);
+        writer.u32(node.parent.map_or(u32::MAX, |parent| parent as u32));
+        writer.f32s(&node.local.to_cols_array());
+    }
+    writer.u32(model.materials.len() as u32);
+    for material in &model.materials {
+        if material.texture.is_some() && material.texture_path.is_none() {
+            return Err("a texture was not read from a file".into());
+        }
+        writer.f32s(&material.base_color);
+        writer.f32s(&[material.metallic, material.roughness]);
+        // relative to the model, so the cache survives the assets moving together
+        let texture = match &material.texture_path {
+            Some(path) => path
+                .strip_prefix(directory)
+                .unwrap_or(path)
+                .to_str()
+                .ok_or("a texture path is not utf-8")?
+                .to_string(),
+            None => String::new(),
+        };
+        writer.string(&texture);
+    }
+    writer.u32(model.meshes.len() as u32);
+    for mesh in &model.meshes {
+        for value in [mesh.node, mesh.material, mesh.first, mesh.count] {
+            writer.u32(value as u32);
+        }
+    }
+    writer.u32(model.vertices.len() as u32);
+    for vertex in &model.vertices {
+        writer.f32s(&vertex.position);
+        writer.f32s(&vertex.normal);
+        writer.f32s(&vertex.texcoord);
+        writer.f32s(&vertex.tangent);
+    }
+    writer.u32(model.indices.len() as u32);
+    for &index in &model.indices {
+        writer.u32(index);
+    }
+
+    fs::create_dir_all(cache.parent().unwrap_or(Path::new(".")))?;
+    fs::write(cache, writer.0)?;
+    Ok(())
+}
+
+// fails on a cache from another version or of another state of the source files
+fn read_cache(bytes: &[u8], stamp: u64, directory: &Path) -> Result<Model, Box<dyn Error>> {
+    let mut reader = Reader { bytes };
+    if reader.take(CACHE_MAGIC.len())? != CACHE_MAGIC || reader.u32()? != CACHE_VERSION {
+        return Err("not a cache file of this version".into());
+    }
+    if reader.u64()? != stamp {
+        return Err("the model changed since it was cached".into());
+    }
+
+    let mut model = Model::default();
+    for _ in 0..reader.u32()? {
+        let name = reader.string()?;
+        let parent = reader.u32()?;
+        model.nodes.push(Node::new(
+            &name,
+            (parent != u32::MAX).then_some(parent as usize),
+            glm::Mat4::from_cols_array(&reader.f32s()?),
+        ));
+    }
+    for _ in 0..reader.u32()? {
+        let base_color = reader.f32s()?;
+        let [metallic, roughness] = reader.f32s()?;
+        let texture = reader.string()?;
+        model.materials.push(Material {
+            base_color,
+            metallic,
+            roughness,
+            texture: None,
+            texture_path: (!texture.is_empty()).then(|| directory.join(texture)),
+        });
+    }
+    for _ in 0..reader.u32()? {
+        let [node, material, first, count] =
+            [(); 4].map(|_| reader.u32().map(|value| value as usize));
+        model.meshes.push(Mesh {
+            node: node?,
+            material: material?,
+            first: first?,
+            count: count?,
+        });
+    }
+    for _ in 0..reader.u32()? {
+        model.vertices.push(Vertex {
+            position: reader.f32s()?,
+            normal: reader.f32s()?,
+            texcoord: reader.f32s()?,
+            tangent: reader.f32s()?,
+        });
+    }
+    for _ in 0..reader.u32()? {
+        model.indices.push(reader.u32()?);
+    }
+
+    let in_range = model
+        .nodes
+        .iter()
+        .enumerate()
+        .all(|(index, node)| node.parent.is_none_or(|parent| parent < index))
+        && model.meshes.iter().all(|mesh| {
+            mesh.node < model.nodes.len()
+                && mesh.material < model.materials.len()
+                && mesh.first + mesh.count <= model.indices.len()
+        })
+        && model
+            .indices
+            .iter()
+            .all(|&index| (index as usize) < model.vertices.len());
+    if !in_range || !reader.bytes.is_empty() {
+        return Err("the cache file is damaged".into());
+    }
+    Ok(model)
+}
+
+#[derive(Default)]
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn f32s(&mut self, values: &[f32]) {
+        for value in values {
+            self.bytes(&value.to_le_bytes());
+        }
+    }
+
+    fn string(&mut self, value: &str) {
+        self.u32(value.len() as u32);
+        self.bytes(value.as_bytes());
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], Box<dyn Error>> {
+        if count > self.bytes.len() {
+            return Err("the cache file is truncated".into());
+        }
+        let (taken, rest) = self.bytes.split_at(count);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn u32(&mut self) -> Result<u32, Box<dyn Error>> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into()?))
+    }
+
+    fn u64(&mut self) -> Result<u64, Box<dyn Error>> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into()?))
+    }
+
+    fn f32s<const N: usize>(&mut self) -> Result<[f32; N], Box<dyn Error>> {
+        let mut values = [0.; N];
+        for value in &mut values {
+            *value = f32::from_le_bytes(self.take(4)?.try_into()?);
+        }
+        Ok(values)
+    }
+
+    fn string(&mut self) -> Result<String, Box<dyn Error>> {
+        let length = self.u32()? as usize;
+        Ok(String::from_utf8(self.take(length)?.to_vec())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vertex(position: [f32; 3], texcoord: [f32; 2]) -> Vertex {
+        Vertex {
+            position,
+            texcoord,
+            ..Default::default()
+        }
+    }
+
+    fn assert_close(actual: [f32; 3], expected: [f32; 3]) {
+        let distance = glm::Vec3::from(actual).distance(glm::Vec3::from(expected));
+        assert!(distance < 1e-5, "{actual:?} is not {expected:?}");
+    }
+
+    #[test]
+    fn shared_edge_is_stored_once() {
+        let corners = [
+            vertex([0., 0., 0.], [0., 0.]),
+            vertex([1., 0., 0.], [1., 0.]),
+            vertex([1., 1., 0.], [1., 1.]),
+            vertex([0., 1., 0.], [0., 1.]),
+        ];
+        let mut geometry = Geometry::default();
+        let quad = [0, 1, 2, 0, 2, 3].map(|corner| corners[corner]);
+        assert_eq!(geometry.push(quad), (0, 6));
+        let (vertices, indices) = geometry.finish();
+        assert_eq!(vertices.len(), 4);
+        assert_eq!(indices, [0, 1, 2, 0, 2, 3]);
+    }
+
+    #[test]
+    fn missing_normals_and_tangents_are_filled_in() {
+        // counter-clockwise seen from +z, u along +x and v along +y
+        let triangle = [
+            vertex([0., 0., 0.], [0., 0.]),
+            vertex([2., 0., 0.], [1., 0.]),
+            vertex([0., 2., 0.], [0., 1.]),
+        ];
+        let mut geometry = Geometry::default();
+        geometry.push(triangle);
+        let (vertices, _) = geometry.finish();
+        for vertex in vertices {
+            assert_close(vertex.normal, [0., 0., 1.]);
+            let [x, y, z, w] = vertex.tangent;
+            assert_close([x, y, z], [1., 0., 0.]);
+            assert_eq!(w, 1.);
+        }
+
+        // v along -y flips the handedness
+        let mirrored = triangle
+            .map(|corner| vertex(corner.position, [corner.texcoord[0], -corner.texcoord[1]]));
+        let mut geometry = Geometry::default();
+        geometry.push(mirrored);
+        let (vertices, _) = geometry.finish();
+        assert!(vertices.iter().all(|vertex| vertex.tangent[3] == -1.));
+    }
+
+    fn model(directory: &Path) -> Model {
+        let mut geometry = Geometry::default();
+        geometry.push([
+            vertex([0., 0., 0.], [0., 0.]),
+            vertex([1., 0., 0.], [1., 0.]),
+            vertex([0., 1., 0.], [0., 1.]),
+        ]);
+        let (vertices, indices) = geometry.finish();
+        Model {
+            vertices,
+            indices,
+            meshes: vec![Mesh {
+                node: 1,
+                material: 0,
+                first: 0,
+                count: 3,
+            }],
+            nodes: vec![
+                Node::new("root", None, glm::Mat4::IDENTITY),
+                Node::new(
+                    "wing",
+                    Some(0),
+                    glm::Mat4::from_translation(glm::Vec3::new(1., 2., 3.)),
+                ),
+            ],
+            materials: vec![Material {
+                base_color: [0.5, 0.25, 1., 1.],
+                metallic: 0.2,
+                roughness: 0.7,
+                texture: None,
+                texture_path: Some(directory.join("textures").join("skin.png")),
+            }],
+        }
+    }
+
+    #[test]
+    fn cache_roundtrip() {
+        let directory =
+            std::env::temp_dir().join(format!("sandbox-mesh-test-{}", std::process::id()));
+        let cache = directory.join("model.mesh");
+        let original = model(&directory);
+        write_cache(&cache, &original, 42, &directory).unwrap();
+        let bytes = fs::read(&cache).unwrap();
+        fs::remove_dir_all(&directory).unwrap();
+
+        // the texture follows the model to wherever it is read from
+        let moved = Path::new("elsewhere");
+        let model = read_cache(&bytes, 42, moved).unwrap();
+        assert_eq!(model.indices, original.indices);
+        assert_eq!(model.vertices.len(), original.vertices.len());
+        for (read, written) in model.vertices.iter().zip(&original.vertices) {
+            assert_eq!(key(read), key(written));
+        }
+        let names: Vec<_> = model
+            .nodes
+            .iter()
+            .map(|node| (node.name.as_str(), node.parent))
+            .collect();
+        assert_eq!(names, [("root", None), ("wing", Some(0))]);
+        assert_eq!(model.nodes[1].local, original.nodes[1].local);
+        let [mesh] = &model.meshes[..] else {
+            panic!("expected one mesh");
+        };
+        assert_eq!(
+            (mesh.node, mesh.material, mesh.first, mesh.count),
+            (1, 0, 0, 3)
+        );
+        let [material] = &model.materials[..] else {
+            panic!("expected one material");
+        };
+        assert_eq!(material.base_color, [0.5, 0.25, 1., 1.]);
+        assert_eq!((material.metallic, material.roughness), (0.2, 0.7));
+        assert_eq!(
+            material.texture_path.as_deref(),
+            Some(moved.join("textures").join("skin.png").as_path())
+        );
+
+        assert!(read_cache(&bytes, 43, moved).is_err());
+        let mut magic = bytes.clone();
+        magic[0] ^= 1;
+        assert!(read_cache(&magic, 42, moved).is_err());
+        let mut version = bytes.clone();
+        version[CACHE_MAGIC.len()..][..4].copy_from_slice(&(CACHE_VERSION + 1).to_le_bytes());
+        assert!(read_cache(&version, 42, moved).is_err());
+        assert!(read_cache(&bytes[..bytes.len() - 1], 42, moved).is_err());
+    }
+
+    #[test]
+    fn fnv_matches_reference_values() {
+        let mut hasher = Fnv::default();
+        assert_eq!(hasher.0, 0xcbf2_9ce4_8422_2325);
+        hasher.write(b"a");
+        assert_eq!(hasher.0, 0xaf63_dc4c_8601_ec8c);
+    }
+}
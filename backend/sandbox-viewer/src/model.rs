@@ -0,0 +1,412 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+use glam as glm;
+
+use crate::mesh::{self, Geometry};
+use crate::{Transform, Vertex};
+
+pub const MODEL_PATH: &str = "vendor/f35/f35.obj";
+
+// a metallic-roughness material as glTF describes it, obj materials are mapped onto the same
+pub struct Material {
+    pub base_color: [f32; 4],
+    pub metallic: f32,
+    pub roughness: f32,
+    // rgba with the first row at v = 0, the way the texture coordinates address it
+    pub texture: Option<image::RgbaImage>,
+    // where `texture` was read from, none when it came embedded in the file
+    pub texture_path: Option<PathBuf>,
+}
+
+impl Default for Material {
+    fn default() -> Material {
+        Material {
+            base_color: [1.; 4],
+            metallic: 0.,
+            roughness: 0.5,
+            texture: None,
+            texture_path: None,
+        }
+    }
+}
+
+// a node of the hierarchy, placed relative to its parent. parents come before their children
+pub struct Node {
+    pub name: String,
+    pub parent: Option<usize>,
+    pub local: glm::Mat4,
+    // moves an articulated part within the node, such as a control surface about its hinge
+    pub pose: glm::Mat4,
+}
+
+impl Node {
+    pub fn new(name: &str, parent: Option<usize>, local: glm::Mat4) -> Node {
+        Node {
+            name: name.to_string(),
+            parent,
+            local,
+            pose: glm::Mat4::IDENTITY,
+        }
+    }
+}
+
+// a run of triangles in `Model::indices` drawn with one material, attached to a node
+pub struct Mesh {
+    pub node: usize,
+    pub material: usize,
+    pub first: usize,
+    pub count: usize,
+}
+
+// everything the aircraft is drawn from, shared by the window and the software renderer. the nose
+// points along +x and up is +y
+#[derive(Default)]
+pub struct Model {
+    pub vertices: Vec<Vertex>,
+    // three per triangle, into `vertices`
+    pub indices: Vec<u32>,
+    pub meshes: Vec<Mesh>,
+    pub nodes: Vec<Node>,
+    pub materials: Vec<Material>,
+}
+
+impl Model {
+    // `.gltf` and `.glb` files are read as glTF 2.0, anything else as obj. parsing the text of an
+    // obj is slow, what comes out of it is cached, see `mesh::load_cached`
+    pub fn load(path: &Path) -> Result<Model, Box<dyn Error>> {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("gltf" | "glb") => load_gltf(path),
+            _ => mesh::load_cached(path, load_obj),
+        }
+    }
+
+    // where each mesh is drawn with the whole model placed at `transform`
+    pub fn mesh_transforms(&self, transform: &Transform) -> Vec<glm::Mat4> {
+        let mut nodes: Vec<glm::Mat4> = Vec::with_capacity(self.nodes.len());
+        for node in &self.nodes {
+            let parent = node
+                .parent
+                .map_or(glm::Mat4::IDENTITY, |parent| nodes[parent]);
+            nodes.push(parent * node.local * node.pose);
+        }
+        let model = transform.to_matrix();
+        self.meshes
+            .iter()
+            .map(|mesh| model * nodes[mesh.node])
+            .collect()
+    }
+
+    // a magenta box about the size of the aircraft, drawn when the model cannot be loaded
+    pub fn placeholder() -> Model {
+        let mut geometry = Geometry::default();
+        let (first, count) = geometry.push(cube_verts_uv_normal());
+        let (vertices, indices) = geometry.finish();
+        Model {
+            vertices,
+            indices,
+            meshes: vec![Mesh {
+                node: 0,
+                material: 0,
+                first,
+                count,
+            }],
+            nodes: vec![Node::new(
+                "",
+                None,
+                glm::Mat4::from_scale(glm::Vec3::new(8., 2., 6.)),
+            )],
+            materials: vec![Material {
+                base_color: [1., 0., 1., 1.],
+                ..Default::default()
+            }],
+        }
+    }
+
+    pub fn find_node(&self, name: &str) -> Option<usize> {
+        self.nodes.iter().position(|node| node.name == name)
+    }
+
+    pub fn triangles(&self, mesh: &Mesh) -> impl Iterator<Item = [&Vertex; 3]> {
+        self.indices[mesh.first..mesh.first + mesh.count]
+            .chunks_exact(3)
+            .map(|triangle| [0, 1, 2].map(|i| &self.vertices[triangle[i] as usize]))
+    }
+
+    // a material for meshes that name none
+    fn default_material(&mut self) -> usize {
+        self.materials.push(Material::default());
+        self.materials.len() - 1
+    }
+}
+
+// a unit cube, as position, texture coordinates and normal per vertex
+fn cube_verts_uv_normal() -> Vec<Vertex> {
+    #[rustfmt::skip]
+    let cube_verts: [f32; 288] = [
+        -0.5, -0.5, -0.5,  0.0, 0.0,  0., 0., -1.,
+         0.5, -0.5, -0.5,  1.0, 0.0,  0., 0., -1.,
+         0.5,  0.5, -0.5,  1.0, 1.0,  0., 0., -1.,
+         0.5,  0.5, -0.5,  1.0, 1.0,  0., 0., -1.,
+        -0.5,  0.5, -0.5,  0.0, 1.0,  0., 0., -1.,
+        -0.5, -0.5, -0.5,  0.0, 0.0,  0., 0., -1.,
+
+        -0.5, -0.5,  0.5,  0.0, 0.0,  0., 0., 1.,
+         0.5, -0.5,  0.5,  1.0, 0.0,  0., 0., 1.,
+         0.5,  0.5,  0.5,  1.0, 1.0,  0., 0., 1.,
+         0.5,  0.5,  0.5,  1.0, 1.0,  0., 0., 1.,
+        -0.5,  0.5,  0.5,  0.0, 1.0,  0., 0., 1.,
+        -0.5, -0.5,  0.5,  0.0, 0.0,  0., 0., 1.,
+
+        -0.5,  0.5,  0.5,  1.0, 0.0,  -1., 0., 0.,
+        -0.5,  0.5, -0.5,  1.0, 1.0,  -1., 0., 0.,
+        -0.5, -0.5, -0.5,  0.0, 1.0,  -1., 0., 0.,
+        -0.5, -0.5, -0.5,  0.0, 1.0,  -1., 0., 0.,
+        -0.5, -0.5,  0.5,  0.0, 0.0,  -1., 0., 0.,
+        -0.5,  0.5,  0.5,  1.0, 0.0,  -1., 0., 0.,
+
+         0.5,  0.5,  0.5,  1.0, 0.0,  1., 0., 0.,
+         0.5,  0.5, -0.5,  1.0, 1.0,  1., 0., 0.,
+         0.5, -0.5, -0.5,  0.0, 1.0,  1., 0., 0.,
+         0.5, -0.5, -0.5,  0.0, 1.0,  1., 0., 0.,
+         0.5, -0.5,  0.5,  0.0, 0.0,  1., 0., 0.,
+         0.5,  0.5,  0.5,  1.0, 0.0,  1., 0., 0.,
+
+        -0.5, -0.5, -0.5,  0.0, 1.0,  0., -1., 0.,
+         0.5, -0.5, -0.5,  1.0, 1.0,  0., -1., 0.,
+         0.5, -0.5,  0.5,  1.0, 0.0,  0., -1., 0.,
+         0.5, -0.5,  0.5,  1.0, 0.0,  0., -1., 0.,
+        -0.5, -0.5,  0.5,  0.0, 0.0,  0., -1., 0.,
+        -0.5, -0.5, -0.5,  0.0, 1.0,  0., -1., 0.,
+
+        -0.5,  0.5, -0.5,  0.0, 1.0,  0., 1., 0.,
+         0.5,  0.5, -0.5,  1.0, 1.0,  0., 1., 0.,
+         0.5,  0.5,  0.5,  1.0, 0.0,  0., 1., 0.,
+         0.5,  0.5,  0.5,  1.0, 0.0,  0., 1., 0.,
+        -0.5,  0.5,  0.5,  0.0, 0.0,  0., 1., 0.,
+        -0.5,  0.5, -0.5,  0.0, 1.0,  0., 1., 0.,
+    ];
+    cube_verts
+        .chunks_exact(8)
+        .map(|vertex| Vertex {
+            position: [vertex[0], vertex[1], vertex[2]],
+            texcoord: [vertex[3], vertex[4]],
+            normal: [vertex[5], vertex[6], vertex[7]],
+            tangent: [0.; 4],
+        })
+        .collect()
+}
+
+// rgba with the first row at v = 0, the way the texture coordinates address it
+pub fn load_texture(path: &Path) -> Result<image::RgbaImage, image::ImageError> {
+    Ok(image::open(path)?.flipv().to_rgba8())
+}
+
+// one node and mesh per object of the file, named after it. without a material library the whole
+// model gets `<name>_texture.jpg` or `.png` from next to it, if there is one
+fn load_obj(path: &Path) -> Result<Model, Box<dyn Error>> {
+    let (objects, materials) = tobj::load_obj(
+        path,
+        &tobj::LoadOptions {
+            triangulate: true,
+            single_index: true,
+            ..Default::default()
+        },
+    )?;
+    let directory = path.parent().unwrap_or(Path::new("."));
+
+    let mut model = Model::default();
+    model.nodes.push(Node::new("", None, glm::Mat4::IDENTITY));
+    for material in materials.unwrap_or_default() {
+        let [r, g, b] = material.diffuse.unwrap_or([1.; 3]);
+        let texture_path = material
+            .diffuse_texture
+            .as_ref()
+            .map(|name| directory.join(name));
+        let texture = texture_path.as_deref().map(load_texture).transpose()?;
+        model.materials.push(Material {
+            base_color: [r, g, b, material.dissolve.unwrap_or(1.)],
+            // the usual blinn-phong exponent to roughness conversion
+            roughness: material
+                .shininess
+                .map_or(0.5, |shininess| (2. / (shininess + 2.)).sqrt()),
+            texture,
+            texture_path,
+            ..Default::default()
+        });
+    }
+    let fallback = model.default_material();
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let texture_path = ["jpg", "png"]
+        .iter()
+        .map(|extension| directory.join(format!("{stem}_texture.{extension}")))
+        .find(|texture| texture.exists());
+    model.materials[fallback].texture = texture_path.as_deref().map(load_texture).transpose()?;
+    model.materials[fallback].texture_path = texture_path;
+
+    let mut geometry = Geometry::default();
+
+    for object in &objects {
+        let mesh = &object.mesh;
+        let node = model.nodes.len();
+        model
+            .nodes
+            .push(Node::new(&object.name, Some(0), glm::Mat4::IDENTITY));
+        let (first, count) = geometry.push(mesh.indices.iter().map(|&index| {
+            let index = index as usize;
+            Vertex {
+                position: [
+                    mesh.positions[index * 3],
+                    mesh.positions[index * 3 + 1],
+                    mesh.positions[index * 3 + 2],
+                ],
+                normal: if !mesh.normals.is_empty() {
+                    [
+                        mesh.normals[index * 3],
+                        mesh.normals[index * 3 + 1],
+                        mesh.normals[index * 3 + 2],
+                    ]
+                } else {
+                    [0.; 3]
+                },
+                texcoord: if !mesh.texcoords.is_empty() {
+                    [mesh.texcoords[index * 2], mesh.texcoords[index * 2 + 1]]
+                } else {
+                    [0., 0.]
+                },
+                tangent: [0.; 4],
+            }
+        }));
+        model.meshes.push(Mesh {
+            node,
+            material: mesh
+                .material_id
+                .filter(|&material| material < fallback)
+                .unwrap_or(fallback),
+            first,
+            count,
+        });
+    }
+    (model.vertices, model.indices) = geometry.finish();
+    Ok(model)
+}
+
+// the default scene with its node hierarchy, triangle primitives and base color textures
+fn load_gltf(path: &Path) -> Result<Model, Box<dyn Error>> {
+    let (document, buffers, images) = gltf::import(path)?;
+    let mut model = Model::default();
+    for material in document.materials() {
+        let pbr = material.pbr_metallic_roughness();
+        let texture = match pbr.base_color_texture() {
+            Some(info) => Some(gltf_texture(&images[info.texture().source().index()])?),
+            None => None,
+        };
+        model.materials.push(Material {
+            base_color: pbr.base_color_factor(),
+            metallic: pbr.metallic_factor(),
+            roughness: pbr.roughness_factor(),
+            texture,
+            texture_path: None,
+        });
+    }
+    let fallback = model.default_material();
+    let mut geometry = Geometry::default();
+    let scene = document
+        .default_scene()
+        .or_else(|| document.scenes().next())
+        .ok_or("the file has no scene")?;
+    // depth first, so parents land before their children
+    let mut pending: Vec<(gltf::Node, Option<usize>)> =
+        scene.nodes().map(|node| (node, None)).collect();
+    pending.reverse();
+    while let Some((node, parent)) = pending.pop() {
+        let index = model.nodes.len();
+        model.nodes.push(Node::new(
+            node.name().unwrap_or_default(),
+            parent,
+            glm::Mat4::from_cols_array_2d(&node.transform().matrix()),
+        ));
+        if let Some(mesh) = node.mesh() {
+            for primitive in mesh.primitives() {
+                if primitive.mode() != gltf::mesh::Mode::Triangles {
+                    continue;
+                }
+                let (first, count) = geometry.push(gltf_primitive(&primitive, &buffers)?);
+                model.meshes.push(Mesh {
+                    node: index,
+                    material: primitive.material().index().unwrap_or(fallback),
+                    first,
+                    count,
+                });
+            }
+        }
+        let children: Vec<gltf::Node> = node.children().collect();
+        pending.extend(children.into_iter().rev().map(|child| (child, Some(index))));
+    }
+    (model.vertices, model.indices) = geometry.finish();
+    Ok(model)
+}
+
+// expands the primitive into a triangle list, flat shaded when it has no normals as the
+// specification asks
+fn gltf_primitive(
+    primitive: &gltf::Primitive,
+    buffers: &[gltf::buffer::Data],
+) -> Result<Vec<Vertex>, Box<dyn Error>> {
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    let positions: Vec<[f32; 3]> = reader
+        .read_positions()
+        .ok_or("a primitive has no positions")?
+        .collect();
+    let normals: Option<Vec<[f32; 3]>> = reader.read_normals().map(Iterator::collect);
+    let texcoords: Option<Vec<[f32; 2]>> = reader
+        .read_tex_coords(0)
+        .map(|texcoords| texcoords.into_f32().collect());
+    let tangents: Option<Vec<[f32; 4]>> = reader.read_tangents().map(Iterator::collect);
+    let indices: Vec<usize> = match reader.read_indices() {
+        Some(indices) => indices.into_u32().map(|index| index as usize).collect(),
+        None => (0..positions.len()).collect(),
+    };
+    let mut vertices = Vec::with_capacity(indices.len());
+    for triangle in indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| glm::Vec3::from(positions[triangle[i]]));
+        let flat = (b - a).cross(c - a).normalize_or_zero().to_array();
+        for &index in triangle {
+            // glTF puts v = 0 at the top of the image, the textures are stored bottom up
+            let [u, v] = texcoords
+                .as_ref()
+                .map_or([0., 0.], |texcoords| texcoords[index]);
+            vertices.push(Vertex {
+                position: positions[index],
+                normal: normals.as_ref().map_or(flat, |normals| normals[index]),
+                texcoord: [u, 1. - v],
+                // flipping v flips the bitangent
+                tangent: tangents.as_ref().map_or([0.; 4], |tangents| {
+                    let [x, y, z, w] = tangents[index];
+                    [x, y, z, -w]
+                }),
+            });
+        }
+    }
+    Ok(vertices)
+}
+
+fn gltf_texture(data: &gltf::image::Data) -> Result<image::RgbaImage, Box<dyn Error>> {
+    let pixels: Vec<u8> = match data.format {
+        gltf::image::Format::R8G8B8A8 => data.pixels.clone(),
+        gltf::image::Format::R8G8B8 => data
+            .pixels
+            .chunks_exact(3)
+            .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], 255])
+            .collect(),
+        gltf::image::Format::R8 => data
+            .pixels
+            .iter()
+            .flat_map(|&gray| [gray, gray, gray, 255])
+            .collect(),
+        format => return Err(format!("unsupported texture format {format:?}").into()),
+    };
+    let image = image::RgbaImage::from_raw(data.width, data.height, pixels)
+        .ok_or("texture data does not match its size")?;
+    Ok(image::imageops::flip_vertical(&image))
+}
@@ -0,0 +1,276 @@
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use sandbox_core::metrics::Metrics;
+use sandbox_core::rng::{RngService, Subsystem};
+use sandbox_core::simulation::{Simulation, State};
+use sandbox_core::tunables::Tunables;
+
+use crate::fleet::{Fleet, Vehicle};
+use crate::lighting::Lighting;
+use crate::vectors::{VectorKind, Vectors};
+
+// simulated ticks per rendered frame are capped so a large time scale cannot stall the window
+const MAX_TIME_SCALE: f32 = 10.;
+
+pub struct SimControls {
+    pub paused: bool,
+    pub step_once: bool,
+    pub reset: bool,
+    pub time_scale: f32,
+    // ticks per frame of a `--record` run, in place of `time_scale` until the recording stops
+    pub recording_rate: Option<f32>,
+    pub disturbance_enabled: bool,
+    pub disturbance_amplitude: f32,
+    pub initial_state: State,
+    rng: RngService,
+    noise: ChaCha8Rng,
+    // fractional ticks carried between frames when the time scale is not a whole number
+    budget: f32,
+}
+
+impl Default for SimControls {
+    fn default() -> SimControls {
+        SimControls::new(State::default(), RngService::default())
+    }
+}
+
+impl SimControls {
+    pub fn new(initial_state: State, rng: RngService) -> SimControls {
+        SimControls {
+            paused: false,
+            step_once: false,
+            reset: false,
+            time_scale: 1.,
+            recording_rate: None,
+            disturbance_enabled: false,
+            disturbance_amplitude: 1.,
+            initial_state,
+            rng,
+            noise: rng.stream(Subsystem::Disturbance, 0),
+            budget: 0.,
+        }
+    }
+
+    // number of simulation ticks to run this frame
+    pub fn steps(&mut self) -> usize {
+        if std::mem::take(&mut self.step_once) {
+            return 1;
+        }
+        if self.paused {
+            return 0;
+        }
+        self.budget += self.recording_rate.unwrap_or(self.time_scale);
+        let steps = self.budget.floor();
+        self.budget -= steps;
+        steps as usize
+    }
+
+    pub fn disturbance(&mut self) -> f32 {
+        let amplitude = self.disturbance_amplitude;
+        if self.disturbance_enabled && amplitude > 0. {
+            self.noise.random_range(-amplitude..amplitude)
+        } else {
+            0.
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    pub fn restart(&mut self, simulation: &mut Simulation) {
+        simulation.state = self.initial_state;
+        simulation.control = Default::default();
+        simulation.disturbance = 0.;
+        self.noise = self.rng.stream(Subsystem::Disturbance, 0);
+        self.budget = 0.;
+    }
+}
+
+pub fn sim_controls(ui: &mut egui::Ui, controls: &mut SimControls, state: &State) {
+    ui.horizontal(|ui| {
+        let label = if controls.paused { "run" } else { "pause" };
+        if ui.button(label).clicked() {
+            controls.paused = !controls.paused;
+        }
+        if ui
+            .add_enabled(controls.paused, egui::Button::new("step"))
+            .clicked()
+        {
+            controls.step_once = true;
+        }
+        if ui.button("reset").clicked() {
+            controls.reset = true;
+        }
+    });
+    ui.add_enabled(
+        controls.recording_rate.is_none(),
+        egui::Slider::new(&mut controls.time_scale, 0.1..=MAX_TIME_SCALE)
+            .logarithmic(true)
+            .text("time scale"),
+    )
+    .on_disabled_hover_text("the recording plays back in real time");
+    ui.label(format!(
+        "tick {}  t = {:.2} s",
+        state.tick,
+        state.tick as f32 * state.delta_time
+    ));
+    ui.label(format!(
+        "position {:.3}  velocity {:.3}",
+        state.position, state.velocity
+    ));
+}
+
+pub fn plant(ui: &mut egui::Ui, simulation: &mut Simulation) {
+    ui.add(egui::Slider::new(&mut simulation.plant.control_gain, 0.0..=3.).text("control gain"));
+    ui.add(egui::Slider::new(&mut simulation.plant.bias_force, -5.0..=5.).text("bias force"));
+    if ui.button("defaults").clicked() {
+        simulation.plant = Default::default();
+    }
+}
+
+// picks the vehicle the camera, the hud and the panels below follow
+pub fn vehicles(ui: &mut egui::Ui, fleet: &mut Fleet) {
+    for (index, vehicle) in fleet.vehicles.iter().enumerate() {
+        ui.horizontal(|ui| {
+            ui.colored_label(tint_color(vehicle.tint), "■");
+            ui.radio_value(&mut fleet.focus, index, &vehicle.name);
+        });
+    }
+    ui.label("N cycles the focus");
+}
+
+// a metric by name, `None` when the run has not reached it
+type MetricRow = (&'static str, fn(&Metrics) -> Option<f32>);
+
+// each vehicle's metrics side by side, the others' with their difference to the first's. all of
+// them are better lower
+pub fn metrics(ui: &mut egui::Ui, vehicles: &[&Vehicle]) {
+    let metrics: Vec<Metrics> = vehicles
+        .iter()
+        .map(|vehicle| vehicle.metrics.metrics())
+        .collect();
+    let rows: [MetricRow; 9] = [
+        ("rise time", |metrics| metrics.rise_time),
+        ("settling time", |metrics| metrics.settling_time),
+        ("overshoot %", |metrics| Some(metrics.overshoot_percent)),
+        ("peak time", |metrics| metrics.peak_time),
+        ("steady state error", |metrics| {
+            Some(metrics.steady_state_error.abs())
+        }),
+        ("ise", |metrics| Some(metrics.ise)),
+        ("iae", |metrics| Some(metrics.iae)),
+        ("itae", |metrics| Some(metrics.itae)),
+        ("control energy", |metrics| Some(metrics.control_energy)),
+    ];
+    egui::Grid::new("metrics").striped(true).show(ui, |ui| {
+        ui.label("");
+        for vehicle in vehicles {
+            ui.colored_label(tint_color(vehicle.tint), &vehicle.name);
+        }
+        ui.end_row();
+        for (name, value) in rows {
+            ui.label(name);
+            let first = metrics.first().and_then(value);
+            for (index, metrics) in metrics.iter().enumerate() {
+                match (value(metrics), first) {
+                    (Some(value), Some(first)) if index > 0 => {
+                        let color = if value < first {
+                            egui::Color32::LIGHT_GREEN
+                        } else if value > first {
+                            egui::Color32::LIGHT_RED
+                        } else {
+                            ui.visuals().text_color()
+                        };
+                        ui.label(
+                            egui::RichText::new(format!("{value:.3} ({:+.3})", value - first))
+                                .monospace()
+                                .color(color),
+                        );
+                    }
+                    (Some(value), _) => {
+                        ui.monospace(format!("{value:.3}"));
+                    }
+                    (None, _) => {
+                        ui.monospace("--");
+                    }
+                }
+            }
+            ui.end_row();
+        }
+    });
+}
+
+pub fn disturbance(ui: &mut egui::Ui, controls: &mut SimControls) {
+    ui.checkbox(&mut controls.disturbance_enabled, "random force");
+    ui.add_enabled(
+        controls.disturbance_enabled,
+        egui::Slider::new(&mut controls.disturbance_amplitude, 0.0..=10.).text("amplitude"),
+    );
+    ui.label(format!("seed {}", controls.seed()));
+}
+
+pub fn lighting(ui: &mut egui::Ui, lighting: &mut Lighting) {
+    ui.add(egui::Slider::new(&mut lighting.azimuth, -180.0..=180.).text("azimuth"));
+    ui.add(egui::Slider::new(&mut lighting.elevation, -90.0..=90.).text("elevation"));
+    ui.horizontal(|ui| {
+        ui.color_edit_button_rgb(&mut lighting.color);
+        ui.label("color");
+    });
+    ui.add(egui::Slider::new(&mut lighting.ambient, 0.0..=1.).text("ambient"));
+    ui.add(egui::Slider::new(&mut lighting.specular, 0.0..=1.).text("specular"));
+    ui.add(
+        egui::Slider::new(&mut lighting.shininess, 1.0..=256.)
+            .logarithmic(true)
+            .text("shininess"),
+    );
+    if ui.button("defaults").clicked() {
+        *lighting = Lighting::default();
+    }
+}
+
+pub fn vectors(ui: &mut egui::Ui, vectors: &mut Vectors) {
+    ui.checkbox(&mut vectors.visible, "show (V)");
+    ui.add_enabled_ui(vectors.visible, |ui| {
+        for (kind, enabled) in VectorKind::ALL.iter().zip(&mut vectors.enabled) {
+            ui.checkbox(enabled, kind.name());
+        }
+        ui.add(
+            egui::Slider::new(&mut vectors.force_scale, 0.001..=1.)
+                .logarithmic(true)
+                .text("force scale"),
+        );
+        ui.add(
+            egui::Slider::new(&mut vectors.velocity_scale, 0.01..=5.)
+                .logarithmic(true)
+                .text("velocity scale"),
+        );
+    });
+}
+
+pub fn controller(ui: &mut egui::Ui, tunables: &mut Tunables, published: &[(String, f32)]) {
+    if tunables.entries.is_empty() && published.is_empty() {
+        ui.label("no tunables or published values, see sandbox.h");
+        return;
+    }
+    for tunable in &mut tunables.entries {
+        // values set from the command line may lie outside the controller's range
+        ui.add(
+            egui::Slider::new(&mut tunable.value, tunable.min..=tunable.max)
+                .clamping(egui::SliderClamping::Edits)
+                .text(&tunable.name),
+        );
+    }
+    egui::Grid::new("published").striped(true).show(ui, |ui| {
+        for (name, value) in published {
+            ui.label(name);
+            ui.monospace(format!("{value:>10.4}"));
+            ui.end_row();
+        }
+    });
+}
+
+fn tint_color(tint: [f32; 3]) -> egui::Color32 {
+    let [r, g, b] = tint.map(|channel| (channel * 255.) as u8);
+    egui::Color32::from_rgb(r, g, b)
+}
@@ -0,0 +1,245 @@
+use std::collections::VecDeque;
+
+use sokol::app as sap;
+use sokol::debugtext as sdtx;
+use sokol::gfx;
+use sokol::gl as sgl;
+
+use crate::camera::Inputs;
+
+// history kept per signal, the widest zoom level
+const MAX_WINDOW: f32 = 60.;
+const MIN_WINDOW: f32 = 1.;
+// size of a debugtext character cell in pixels
+const CELL: f32 = 16.;
+
+const COLORS: [[f32; 3]; 8] = [
+    [0.95, 0.35, 0.3],
+    [0.3, 0.8, 0.4],
+    [0.9, 0.9, 0.9],
+    [0.35, 0.6, 1.],
+    [1., 0.75, 0.2],
+    [0.8, 0.4, 0.9],
+    [0.3, 0.9, 0.9],
+    [0.9, 0.55, 0.7],
+];
+
+pub struct Signal {
+    pub name: String,
+    pub color: [f32; 3],
+    pub enabled: bool,
+    samples: VecDeque<[f32; 2]>,
+}
+
+pub struct StripChart {
+    pub signals: Vec<Signal>,
+    pub window: f32,
+    pub paused: bool,
+    pub visible: bool,
+    pipeline: sgl::Pipeline,
+}
+
+impl Default for StripChart {
+    fn default() -> StripChart {
+        let mut chart = StripChart {
+            signals: Vec::new(),
+            window: 10.,
+            paused: false,
+            visible: true,
+            pipeline: sgl::Pipeline::default(),
+        };
+        for name in ["position", "velocity", "setpoint", "force"] {
+            chart.signal(name);
+        }
+        chart
+    }
+}
+
+impl StripChart {
+    pub fn init(&mut self) {
+        self.pipeline = sgl::make_pipeline(&gfx::PipelineDesc {
+            colors: {
+                let mut colors = [gfx::ColorTargetState::default(); 4];
+                colors[0].blend = gfx::BlendState {
+                    enabled: true,
+                    src_factor_rgb: gfx::BlendFactor::SrcAlpha,
+                    dst_factor_rgb: gfx::BlendFactor::OneMinusSrcAlpha,
+                    ..Default::default()
+                };
+                colors
+            },
+            ..Default::default()
+        });
+    }
+
+    fn signal(&mut self, name: &str) -> &mut Signal {
+        let index = match self.signals.iter().position(|signal| signal.name == name) {
+            Some(index) => index,
+            None => {
+                self.signals.push(Signal {
+                    name: name.to_string(),
+                    color: COLORS[self.signals.len() % COLORS.len()],
+                    enabled: true,
+                    samples: VecDeque::new(),
+                });
+                self.signals.len() - 1
+            }
+        };
+        &mut self.signals[index]
+    }
+
+    pub fn push(&mut self, name: &str, time: f32, value: f32) {
+        if self.paused {
+            return;
+        }
+        let signal = self.signal(name);
+        signal.samples.push_back([time, value]);
+        while signal
+            .samples
+            .front()
+            .is_some_and(|sample| sample[0] < time - MAX_WINDOW)
+        {
+            signal.samples.pop_front();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        for signal in &mut self.signals {
+            signal.samples.clear();
+        }
+    }
+
+    pub fn handle_inputs(&mut self, inputs: &mut Inputs) {
+        if inputs.take_press(sap::Keycode::G) {
+            self.visible = !self.visible;
+        }
+        if inputs.take_press(sap::Keycode::P) {
+            self.paused = !self.paused;
+        }
+        if inputs.take_press(sap::Keycode::LeftBracket) {
+            self.window = (self.window / 2.).max(MIN_WINDOW);
+        }
+        if inputs.take_press(sap::Keycode::RightBracket) {
+            self.window = (self.window * 2.).min(MAX_WINDOW);
+        }
+        let digits = [
+            sap::Keycode::Num1,
+            sap::Keycode::Num2,
+            sap::Keycode::Num3,
+            sap::Keycode::Num4,
+            sap::Keycode::Num5,
+            sap::Keycode::Num6,
+            sap::Keycode::Num7,
+            sap::Keycode::Num8,
+            sap::Keycode::Num9,
+        ];
+        for (signal, key) in self.signals.iter_mut().zip(digits) {
+            if inputs.take_press(key) {
+                signal.enabled = !signal.enabled;
+            }
+        }
+    }
+
+    fn latest_time(&self) -> f32 {
+        self.signals
+            .iter()
+            .filter_map(|signal| signal.samples.back())
+            .map(|sample| sample[0])
+            .fold(0., f32::max)
+    }
+
+    fn value_range(&self, start: f32) -> (f32, f32) {
+        let (low, high) = self
+            .signals
+            .iter()
+            .filter(|signal| signal.enabled)
+            .flat_map(|signal| signal.samples.iter())
+            .filter(|sample| sample[0] >= start && sample[1].is_finite())
+            .fold((f32::MAX, f32::MIN), |(low, high), sample| {
+                (low.min(sample[1]), high.max(sample[1]))
+            });
+        if low > high {
+            return (-1., 1.);
+        }
+        let padding = ((high - low) * 0.1).max(1e-3);
+        (low - padding, high + padding)
+    }
+
+    // queues sokol-gl and debugtext commands, drawn by the `sgl::draw` and `sdtx::draw` of the pass
+    pub fn draw(&self, width: f32, height: f32) {
+        if !self.visible {
+            return;
+        }
+        let (x, y, w, h) = (10., height * 0.62, width - 20., height * 0.36);
+        let end = self.latest_time();
+        let start = end - self.window;
+        let (low, high) = self.value_range(start);
+        let to_screen = |sample: &[f32; 2]| {
+            (
+                x + (sample[0] - start) / self.window * w,
+                y + h - (sample[1] - low) / (high - low) * h,
+            )
+        };
+
+        sgl::defaults();
+        sgl::load_pipeline(self.pipeline);
+        sgl::matrix_mode_projection();
+        sgl::ortho(0., width, height, 0., -1., 1.);
+
+        sgl::begin_quads();
+        sgl::c4f(0., 0., 0., 0.55);
+        sgl::v2f(x, y);
+        sgl::v2f(x + w, y);
+        sgl::v2f(x + w, y + h);
+        sgl::v2f(x, y + h);
+        sgl::end();
+
+        if low < 0. && high > 0. {
+            let (_, zero) = to_screen(&[start, 0.]);
+            sgl::begin_lines();
+            sgl::c4f(1., 1., 1., 0.25);
+            sgl::v2f(x, zero);
+            sgl::v2f(x + w, zero);
+            sgl::end();
+        }
+
+        for signal in self.signals.iter().filter(|signal| signal.enabled) {
+            let [r, g, b] = signal.color;
+            sgl::begin_line_strip();
+            sgl::c4f(r, g, b, 1.);
+            let shown = signal
+                .samples
+                .iter()
+                .filter(|sample| sample[0] >= start && sample[1].is_finite());
+            for sample in shown {
+                let (sx, sy) = to_screen(sample);
+                sgl::v2f(sx, sy);
+            }
+            sgl::end();
+        }
+
+        sdtx::canvas(width / 2., height / 2.);
+        sdtx::origin(0., 0.);
+        sdtx::pos(x / CELL + 0.5, y / CELL + 0.5);
+        sdtx::color3f(0.8, 0.8, 0.8);
+        sdtx::puts(&format!("{high:.3}"));
+        sdtx::pos(x / CELL + 0.5, (y + h) / CELL - 1.5);
+        sdtx::puts(&format!("{low:.3}"));
+        sdtx::pos((x + w) / CELL - 12., (y + h) / CELL - 1.5);
+        sdtx::puts(&format!(
+            "{:>5.1}s{}",
+            self.window,
+            if self.paused { " ||" } else { "" }
+        ));
+
+        sdtx::pos((x + w) / CELL - 14., y / CELL + 0.5);
+        for (index, signal) in self.signals.iter().enumerate() {
+            let [r, g, b] = signal.color;
+            let dim = if signal.enabled { 1. } else { 0.35 };
+            sdtx::color3f(r * dim, g * dim, b * dim);
+            sdtx::puts(&format!("{} {}", index + 1, signal.name));
+            sdtx::crlf();
+            sdtx::pos_x((x + w) / CELL - 14.);
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use glam as glm;
+use image::RgbaImage;
+
+use crate::camera::Camera;
+use crate::lighting::Lighting;
+use crate::model::Model;
+use crate::{Transform, Vertex, ground, shaders, sky};
+
+// a pixel counts as changed when any channel is further than this from the golden image
+const CHANNEL_TOLERANCE: u8 = 8;
+
+// a vertex after the vertex stage, with what the fragment stage reads interpolated across the
+// triangle
+#[derive(Clone, Copy)]
+struct Varying {
+    clip: glm::Vec4,
+    world: glm::Vec3,
+    normal: glm::Vec3,
+    texcoord: glm::Vec2,
+}
+
+impl Varying {
+    fn lerp(self, other: Varying, t: f32) -> Varying {
+        Varying {
+            clip: self.clip.lerp(other.clip, t),
+            world: self.world.lerp(other.world, t),
+            normal: self.normal.lerp(other.normal, t),
+            texcoord: self.texcoord.lerp(other.texcoord, t),
+        }
+    }
+
+    fn blend(corners: &[Varying; 3], weights: glm::Vec3) -> Varying {
+        let [a, b, c] = corners;
+        Varying {
+            clip: a.clip * weights.x + b.clip * weights.y + c.clip * weights.z,
+            world: a.world * weights.x + b.world * weights.y + c.world * weights.z,
+            normal: a.normal * weights.x + b.normal * weights.y + c.normal * weights.z,
+            texcoord: a.texcoord * weights.x + b.texcoord * weights.y + c.texcoord * weights.z,
+        }
+    }
+}
+
+// the window's scene drawn on the cpu, for machines without a gpu. it follows the shaders for the
+// sky, the ground and the aircraft but leaves out shadows and the overlays
+pub struct Canvas {
+    width: u32,
+    height: u32,
+    color: Vec<glm::Vec3>,
+    // gl normalized device depth, -1 at the near plane and 1 at the far one
+    depth: Vec<f32>,
+}
+
+impl Canvas {
+    pub fn new(width: u32, height: u32) -> Canvas {
+        let pixels = width as usize * height as usize;
+        Canvas {
+            width,
+            height,
+            color: vec![glm::Vec3::ZERO; pixels],
+            depth: vec![f32::INFINITY; pixels],
+        }
+    }
+
+    // fills every pixel with the sky, as the full screen triangle of the window does
+    pub fn sky(&mut self, camera: &Camera, lighting: &Lighting) {
+        let rotation = glm::Mat4::look_to_rh(glm::Vec3::ZERO, camera.front, camera.up);
+        let inverse_view_projection = (camera.projection_matrix() * rotation).inverse();
+        let sun = -lighting.direction();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let ndc = glm::Vec2::new(
+                    (x as f32 + 0.5) / self.width as f32 * 2. - 1.,
+                    1. - (y as f32 + 0.5) / self.height as f32 * 2.,
+                );
+                let far = inverse_view_projection * glm::Vec4::new(ndc.x, ndc.y, 1., 1.);
+                let direction = (far.truncate() / far.w).normalize();
+                self.color[(y * self.width + x) as usize] = sky::color(direction, sun);
+            }
+        }
+    }
+
+    // `travel` is how far the aircraft has flown along +x, the grid slides back by as much
+    pub fn ground(&mut self, camera: &Camera, travel: f32, grid: &RgbaImage, lighting: &Lighting) {
+        let view_projection = camera.projection_matrix() * camera.view_matrix();
+        let origin = glm::Vec3::new(camera.position.x, ground::HEIGHT, camera.position.z);
+        let corners = [(-1., -1.), (1., -1.), (1., 1.), (-1., 1.)].map(|(x, z)| {
+            let world = origin + glm::Vec3::new(x, 0., z) * ground::EXTENT;
+            Varying {
+                clip: view_projection * world.extend(1.),
+                world,
+                normal: glm::Vec3::Y,
+                texcoord: glm::Vec2::ZERO,
+            }
+        });
+        let light = glm::Vec3::from(lighting.color) * (-lighting.direction().y).max(0.);
+        let fog_distance = camera.far * 0.9;
+        let shade = |fragment: &Varying| {
+            let texcoord =
+                (glm::Vec2::new(fragment.world.x + travel, fragment.world.z)) / ground::TILE_SIZE;
+            let albedo = sample(grid, texcoord);
+            let fog = ((fragment.world - camera.position).length() / fog_distance).clamp(0., 1.);
+            (albedo * (lighting.ambient + light)).lerp(glm::Vec3::from(sky::HORIZON), fog * fog)
+        };
+        self.triangle([corners[0], corners[1], corners[2]], &shade);
+        self.triangle([corners[2], corners[3], corners[0]], &shade);
+    }
+
+    // the aircraft with the blinn-phong lighting of `texture_frag`
+    pub fn model(
+        &mut self,
+        camera: &Camera,
+        model: &Model,
+        transform: &Transform,
+        lighting: &Lighting,
+    ) {
+        let view_projection = camera.projection_matrix() * camera.view_matrix();
+        let transforms = model.mesh_transforms(transform);
+        for (mesh, transform) in model.meshes.iter().zip(transforms) {
+            let material = &model.materials[mesh.material];
+            let params = lighting.fs_params(camera.position, material);
+            let normal_matrix = glm::Mat3::from_mat4(transform);
+            let vertex_stage = |vertex: &Vertex| {
+                let world = transform.transform_point3(glm::Vec3::from(vertex.position));
+                Varying {
+                    clip: view_projection * world.extend(1.),
+                    world,
+                    normal: normal_matrix * glm::Vec3::from(vertex.normal),
+                    texcoord: glm::Vec2::from(vertex.texcoord),
+                }
+            };
+            let shade = |fragment: &Varying| {
+                let texel = material
+                    .texture
+                    .as_ref()
+                    .map_or(glm::Vec3::ONE, |texture| sample(texture, fragment.texcoord));
+                shade_model(&params, texel, fragment)
+            };
+            for triangle in model.triangles(mesh) {
+                self.triangle(triangle.map(vertex_stage), &shade);
+            }
+        }
+    }
+
+    pub fn into_image(self) -> RgbaImage {
+        RgbaImage::from_fn(self.width, self.height, |x, y| {
+            let color =
+                self.color[(y * self.width + x) as usize].clamp(glm::Vec3::ZERO, glm::Vec3::ONE);
+            let [r, g, b] = (color * 255.)
+                .round()
+                .to_array()
+                .map(|channel| channel as u8);
+            image::Rgba([r, g, b, 255])
+        })
+    }
+
+    // clips against the near plane, where gl clip space has z = -w, and fills what is left
+    fn triangle(&mut self, corners: [Varying; 3], shade: &impl Fn(&Varying) -> glm::Vec3) {
+        let mut polygon = Vec::with_capacity(4);
+        for i in 0..3 {
+            let (a, b) = (corners[i], corners[(i + 1) % 3]);
+            let (a_distance, b_distance) = (a.clip.z + a.clip.w, b.clip.z + b.clip.w);
+            if a_distance >= 0. {
+                polygon.push(a);
+            }
+            if (a_distance >= 0.) != (b_distance >= 0.) {
+                polygon.push(a.lerp(b, a_distance / (a_distance - b_distance)));
+            }
+        }
+        for i in 1..polygon.len().saturating_sub(1) {
+            self.fill([polygon[0], polygon[i], polygon[i + 1]], shade);
+        }
+    }
+
+    // either winding, pixels are sampled at their centers and interpolated perspective correctly
+    fn fill(&mut self, corners: [Varying; 3], shade: &impl Fn(&Varying) -> glm::Vec3) {
+        let (width, height) = (self.width as f32, self.height as f32);
+        let inverse_w = corners.map(|corner| 1. / corner.clip.w);
+        let screen = [0, 1, 2].map(|i| {
+            let ndc = corners[i].clip.truncate() * inverse_w[i];
+            glm::Vec3::new(
+                (ndc.x * 0.5 + 0.5) * width,
+                (0.5 - ndc.y * 0.5) * height,
+                ndc.z,
+            )
+        });
+        let area = edge(screen[0], screen[1], screen[2].truncate());
+        if area == 0. {
+            return;
+        }
+        let min = screen[0].min(screen[1]).min(screen[2]);
+        let max = screen[0].max(screen[1]).max(screen[2]);
+        let (x0, x1) = (
+            min.x.floor().clamp(0., width) as u32,
+            max.x.ceil().clamp(0., width) as u32,
+        );
+        let (y0, y1) = (
+            min.y.floor().clamp(0., height) as u32,
+            max.y.ceil().clamp(0., height) as u32,
+        );
+        let depths = glm::Vec3::new(screen[0].z, screen[1].z, screen[2].z);
+        for y in y0..y1 {
+            for x in x0..x1 {
+                let pixel = glm::Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+                let weights = glm::Vec3::new(
+                    edge(screen[1], screen[2], pixel),
+                    edge(screen[2], screen[0], pixel),
+                    edge(screen[0], screen[1], pixel),
+                ) / area;
+                if weights.min_element() < 0. {
+                    continue;
+                }
+                // depth is affine in screen space, everything else in clip space
+                let depth = weights.dot(depths);
+                let index = (y * self.width + x) as usize;
+                if depth > 1. || depth >= self.depth[index] {
+                    continue;
+                }
+                let weights = weights * glm::Vec3::from(inverse_w);
+                let fragment = Varying::blend(&corners, weights / weights.element_sum());
+                self.depth[index] = depth;
+                self.color[index] = shade(&fragment);
+            }
+        }
+    }
+}
+
+// twice the signed area of the triangle `a`, `b`, `point` on screen
+fn edge(a: glm::Vec3, b: glm::Vec3, point: glm::Vec2) -> f32 {
+    (b.x - a.x) * (point.y - a.y) - (b.y - a.y) * (point.x - a.x)
+}
+
+// `texture_frag` for one fragment of the aircraft, `texel` is what its texture holds there
+fn shade_model(params: &shaders::FsParams, texel: glm::Vec3, fragment: &Varying) -> glm::Vec3 {
+    let albedo = texel * glm::Vec4::from(params.base_color).truncate();
+    let light = (-glm::Vec3::from(params.light_direction)).normalize();
+    let light_color = glm::Vec3::from(params.light_color);
+    let mut normal = fragment.normal.normalize_or_zero();
+    let view = (glm::Vec3::from(params.eye_position) - fragment.world).normalize_or_zero();
+    // the model is drawn without culling, light whichever side faces the camera
+    if normal.dot(view) < 0. {
+        normal = -normal;
+    }
+    let diffuse = normal.dot(light).max(0.);
+    let highlight = if diffuse > 0. {
+        normal
+            .dot((light + view).normalize())
+            .max(0.)
+            .powf(params.shininess)
+            * params.specular
+    } else {
+        0.
+    };
+    albedo * (params.ambient + diffuse * light_color) + highlight * light_color
+}
+
+// nearest texel, repeating outside 0..1
+fn sample(image: &RgbaImage, texcoord: glm::Vec2) -> glm::Vec3 {
+    let (width, height) = image.dimensions();
+    let x = ((texcoord.x.rem_euclid(1.) * width as f32) as u32).min(width - 1);
+    let y = ((texcoord.y.rem_euclid(1.) * height as f32) as u32).min(height - 1);
+    let [r, g, b, _] = image.get_pixel(x, y).0;
+    glm::Vec3::new(r as f32, g as f32, b as f32) / 255.
+}
+
+// fraction of the pixels that changed against `golden`, `None` when the sizes differ
+pub fn difference(image: &RgbaImage, golden: &RgbaImage) -> Option<f32> {
+    if image.dimensions() != golden.dimensions() {
+        return None;
+    }
+    let changed = image
+        .pixels()
+        .zip(golden.pixels())
+        .filter(|(a, b)| {
+            a.0.iter()
+                .zip(b.0)
+                .any(|(a, b)| a.abs_diff(b) > CHANNEL_TOLERANCE)
+        })
+        .count();
+    Some(changed as f32 / (image.width() * image.height()).max(1) as f32)
+}
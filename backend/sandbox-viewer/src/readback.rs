@@ -0,0 +1,278 @@
+use image::RgbaImage;
+use sokol::gfx;
+
+// sokol-gfx has no readback, so a single sampled render target is copied out through the api of
+// the backend it was made with: opengl on linux, d3d11 on windows and metal on macos. call it
+// after `gfx::commit`, once the frame's commands have been handed to the gpu
+pub fn read(
+    image: gfx::Image,
+    width: u32,
+    height: u32,
+    format: gfx::PixelFormat,
+) -> Option<RgbaImage> {
+    let (mut pixels, top_down) = match gfx::query_backend() {
+        #[cfg(target_os = "linux")]
+        gfx::Backend::Glcore => (gl::read(image, width, height), false),
+        #[cfg(windows)]
+        gfx::Backend::D3d11 => (d3d11::read(image, width, height)?, true),
+        #[cfg(target_os = "macos")]
+        gfx::Backend::MetalMacos => (metal::read(image, width, height), true),
+        _ => return None,
+    };
+    if format == gfx::PixelFormat::Bgra8 {
+        for pixel in pixels.chunks_exact_mut(4) {
+            pixel.swap(0, 2);
+        }
+    }
+    let mut image = RgbaImage::from_raw(width, height, pixels)?;
+    // gl puts the first row of a render target at the bottom
+    if !top_down {
+        image::imageops::flip_vertical_in_place(&mut image);
+    }
+    Some(image)
+}
+
+#[cfg(target_os = "linux")]
+mod gl {
+    use std::ffi::c_void;
+
+    use sokol::gfx;
+
+    const GL_TEXTURE_2D: u32 = 0x0DE1;
+    const GL_RGBA: u32 = 0x1908;
+    const GL_UNSIGNED_BYTE: u32 = 0x1401;
+
+    unsafe extern "C" {
+        fn glBindTexture(target: u32, texture: u32);
+        fn glGetTexImage(target: u32, level: i32, format: u32, kind: u32, pixels: *mut c_void);
+    }
+
+    pub fn read(image: gfx::Image, width: u32, height: u32) -> Vec<u8> {
+        let info = gfx::gl_query_image_info(image);
+        let mut pixels = vec![0u8; width as usize * height as usize * 4];
+        unsafe {
+            glBindTexture(GL_TEXTURE_2D, info.tex[info.active_slot as usize]);
+            glGetTexImage(
+                GL_TEXTURE_2D,
+                0,
+                GL_RGBA,
+                GL_UNSIGNED_BYTE,
+                pixels.as_mut_ptr() as *mut c_void,
+            );
+        }
+        // the texture binding changed behind sokol's back
+        gfx::reset_state_cache();
+        pixels
+    }
+}
+
+// through the vtables of the com objects sokol hands out, which is all the d3d11 api needed here
+#[cfg(windows)]
+mod d3d11 {
+    use std::ffi::c_void;
+    use std::ptr;
+
+    use sokol::gfx;
+
+    // slots in the vtables of ID3D11Device, ID3D11DeviceContext and ID3D11Texture2D
+    const RELEASE: usize = 2;
+    const CREATE_TEXTURE_2D: usize = 5;
+    const MAP: usize = 14;
+    const UNMAP: usize = 15;
+    const COPY_RESOURCE: usize = 47;
+    const GET_DESC: usize = 10;
+
+    const USAGE_STAGING: u32 = 3;
+    const CPU_ACCESS_READ: u32 = 0x20000;
+    const MAP_READ: u32 = 1;
+
+    // D3D11_TEXTURE2D_DESC
+    #[repr(C)]
+    #[derive(Default)]
+    struct TextureDesc {
+        width: u32,
+        height: u32,
+        mip_levels: u32,
+        array_size: u32,
+        format: u32,
+        sample_count: u32,
+        sample_quality: u32,
+        usage: u32,
+        bind_flags: u32,
+        cpu_access_flags: u32,
+        misc_flags: u32,
+    }
+
+    // D3D11_MAPPED_SUBRESOURCE
+    #[repr(C)]
+    struct Mapped {
+        data: *const u8,
+        row_pitch: u32,
+        depth_pitch: u32,
+    }
+
+    // the method in slot `index` of the com object `this`
+    unsafe fn method<F>(this: *const c_void, index: usize) -> F {
+        unsafe {
+            let vtable = *(this as *const *const usize);
+            std::mem::transmute_copy(&*vtable.add(index))
+        }
+    }
+
+    // copied into a staging texture the cpu can map, the immediate context runs it after the
+    // frame's own commands
+    pub fn read(image: gfx::Image, width: u32, height: u32) -> Option<Vec<u8>> {
+        let texture = gfx::d3d11_query_image_info(image).tex2d;
+        let device = gfx::d3d11_device();
+        let context = gfx::d3d11_device_context();
+        if texture.is_null() || device.is_null() || context.is_null() {
+            return None;
+        }
+        unsafe {
+            let get_desc: unsafe extern "system" fn(*const c_void, *mut TextureDesc) =
+                method(texture, GET_DESC);
+            let mut desc = TextureDesc::default();
+            get_desc(texture, &mut desc);
+            desc.usage = USAGE_STAGING;
+            desc.bind_flags = 0;
+            desc.cpu_access_flags = CPU_ACCESS_READ;
+            desc.misc_flags = 0;
+
+            let create: unsafe extern "system" fn(
+                *const c_void,
+                *const TextureDesc,
+                *const c_void,
+                *mut *const c_void,
+            ) -> i32 = method(device, CREATE_TEXTURE_2D);
+            let mut staging = ptr::null();
+            if create(device, &desc, ptr::null(), &mut staging) < 0 {
+                return None;
+            }
+            let copy: unsafe extern "system" fn(*const c_void, *const c_void, *const c_void) =
+                method(context, COPY_RESOURCE);
+            copy(context, staging, texture);
+
+            let map: unsafe extern "system" fn(
+                *const c_void,
+                *const c_void,
+                u32,
+                u32,
+                u32,
+                *mut Mapped,
+            ) -> i32 = method(context, MAP);
+            let unmap: unsafe extern "system" fn(*const c_void, *const c_void, u32) =
+                method(context, UNMAP);
+            let mut mapped = Mapped {
+                data: ptr::null(),
+                row_pitch: 0,
+                depth_pitch: 0,
+            };
+            let pixels = (map(context, staging, 0, MAP_READ, 0, &mut mapped) >= 0).then(|| {
+                let row = width as usize * 4;
+                let pixels = (0..height as usize)
+                    .flat_map(|y| {
+                        std::slice::from_raw_parts(
+                            mapped.data.add(y * mapped.row_pitch as usize),
+                            row,
+                        )
+                    })
+                    .copied()
+                    .collect();
+                unmap(context, staging, 0);
+                pixels
+            });
+            let release: unsafe extern "system" fn(*const c_void) -> u32 = method(staging, RELEASE);
+            release(staging);
+            pixels
+        }
+    }
+}
+
+// through the objective-c runtime, blitting into a buffer shared with the cpu on a queue of our
+// own. the texture is tracked by metal, so the blit waits for the frame that drew it
+#[cfg(target_os = "macos")]
+mod metal {
+    use std::ffi::{CStr, c_void};
+
+    use sokol::gfx;
+
+    type Id = *const c_void;
+
+    // MTLOrigin and MTLSize
+    #[repr(C)]
+    struct Triple(usize, usize, usize);
+
+    #[link(name = "objc")]
+    unsafe extern "C" {
+        fn sel_registerName(name: *const std::ffi::c_char) -> Id;
+        fn objc_msgSend();
+        fn objc_autoreleasePoolPush() -> *mut c_void;
+        fn objc_autoreleasePoolPop(pool: *mut c_void);
+    }
+
+    // `objc_msgSend` cast to the signature of the method it dispatches to
+    unsafe fn send<F>() -> F {
+        unsafe { std::mem::transmute_copy(&(objc_msgSend as unsafe extern "C" fn())) }
+    }
+
+    fn selector(name: &CStr) -> Id {
+        unsafe { sel_registerName(name.as_ptr()) }
+    }
+
+    pub fn read(image: gfx::Image, width: u32, height: u32) -> Vec<u8> {
+        let info = gfx::mtl_query_image_info(image);
+        let texture = info.tex[info.active_slot as usize];
+        let device = gfx::mtl_device();
+        let (width, height) = (width as usize, height as usize);
+        let length = width * height * 4;
+        unsafe {
+            let pool = objc_autoreleasePoolPush();
+            let call: unsafe extern "C" fn(Id, Id) -> Id = send();
+            let void: unsafe extern "C" fn(Id, Id) = send();
+            let queue = call(device, selector(c"newCommandQueue"));
+            let new_buffer: unsafe extern "C" fn(Id, Id, usize, usize) -> Id = send();
+            // MTLResourceStorageModeShared
+            let buffer = new_buffer(device, selector(c"newBufferWithLength:options:"), length, 0);
+            let commands = call(queue, selector(c"commandBuffer"));
+            let blit = call(commands, selector(c"blitCommandEncoder"));
+            let copy: unsafe extern "C" fn(
+                Id,
+                Id,
+                Id,
+                usize,
+                usize,
+                Triple,
+                Triple,
+                Id,
+                usize,
+                usize,
+                usize,
+            ) = send();
+            copy(
+                blit,
+                selector(
+                    c"copyFromTexture:sourceSlice:sourceLevel:sourceOrigin:sourceSize:toBuffer:\
+                      destinationOffset:destinationBytesPerRow:destinationBytesPerImage:",
+                ),
+                texture,
+                0,
+                0,
+                Triple(0, 0, 0),
+                Triple(width, height, 1),
+                buffer,
+                0,
+                width * 4,
+                length,
+            );
+            void(blit, selector(c"endEncoding"));
+            void(commands, selector(c"commit"));
+            void(commands, selector(c"waitUntilCompleted"));
+            let contents = call(buffer, selector(c"contents")) as *const u8;
+            let pixels = std::slice::from_raw_parts(contents, length).to_vec();
+            void(buffer, selector(c"release"));
+            void(queue, selector(c"release"));
+            objc_autoreleasePoolPop(pool);
+            pixels
+        }
+    }
+}
@@ -0,0 +1,289 @@
+// / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / /
+// simple shader
+@vs simple_vertex
+in vec3 position;
+in vec3 v_color;
+
+out vec3 f_color;
+
+void main() {
+    gl_Position = vec4(position, 1.);
+    f_color = v_color;
+}
+@end
+
+@fs simple_frag
+in vec3 f_color;
+
+out vec4 color;
+
+void main() {
+    color = vec4(f_color, 1.);
+}
+@end
+
+@program simple simple_vertex simple_frag
+
+// / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / /
+// texture shader
+@vs texture_vertex
+in vec3 position;
+in vec3 v_normal;
+in vec2 v_texcoord;
+
+layout (binding = 0) uniform vs_params {
+    mat4 model;
+    mat4 view;
+    mat4 projection;
+};
+
+out vec3 f_position;
+out vec3 f_normal;
+out vec2 f_texcoord;
+
+void main() {
+    vec4 world = model * vec4(position, 1.);
+    gl_Position = projection * view * world;
+    f_position = world.xyz;
+    f_normal = mat3(model) * v_normal;
+    f_texcoord = v_texcoord;
+}
+@end
+
+@fs texture_frag
+in vec3 f_position;
+in vec3 f_normal;
+in vec2 f_texcoord;
+
+layout (binding = 0) uniform texture2D tex;
+layout (binding = 1) uniform sampler samp;
+
+// blinn-phong with one directional light
+layout (binding = 1) uniform fs_params {
+    vec3 light_direction;
+    float ambient;
+    vec3 eye_position;
+    float shininess;
+    vec3 light_color;
+    float specular;
+    vec4 base_color;
+};
+
+out vec4 color;
+
+void main() {
+    vec4 albedo = texture(sampler2D(tex, samp), f_texcoord) * base_color;
+    vec3 normal = normalize(f_normal);
+    vec3 view = normalize(eye_position - f_position);
+    // the model is drawn without culling, light whichever side faces the camera
+    if (dot(normal, view) < 0.) {
+        normal = -normal;
+    }
+    vec3 light = normalize(-light_direction);
+    float diffuse = max(dot(normal, light), 0.);
+    float highlight = 0.;
+    if (diffuse > 0.) {
+        highlight = pow(max(dot(normal, normalize(light + view)), 0.), shininess) * specular;
+    }
+    color = vec4(albedo.rgb * (ambient + diffuse * light_color) + highlight * light_color, albedo.a);
+}
+@end
+
+@program texture texture_vertex texture_frag
+
+// / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / /
+// shadow map depth pass
+@vs shadow_vertex
+in vec3 position;
+
+layout (binding = 0) uniform shadow_vs_params {
+    mat4 light_model_view_projection;
+};
+
+void main() {
+    gl_Position = light_model_view_projection * vec4(position, 1.);
+}
+@end
+
+@fs shadow_frag
+void main() {
+}
+@end
+
+@program shadow shadow_vertex shadow_frag
+
+// / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / /
+// ground grid receiving the shadow, fading into the horizon
+@vs ground_vertex
+in vec3 position;
+
+layout (binding = 0) uniform ground_vs_params {
+    mat4 view_projection;
+    mat4 light_view_projection;
+    vec3 origin;
+    float tile_size;
+    vec2 scroll;
+};
+
+out vec3 f_position;
+out vec4 f_light_position;
+out vec2 f_texcoord;
+
+void main() {
+    vec4 world = vec4(position + origin, 1.);
+    gl_Position = view_projection * world;
+    f_position = world.xyz;
+    f_light_position = light_view_projection * world;
+    f_texcoord = (world.xz + scroll) / tile_size;
+}
+@end
+
+@fs ground_frag
+in vec3 f_position;
+in vec4 f_light_position;
+in vec2 f_texcoord;
+
+layout (binding = 0) uniform texture2D shadow_map;
+layout (binding = 0) uniform sampler shadow_sampler;
+layout (binding = 1) uniform texture2D grid;
+layout (binding = 1) uniform sampler grid_sampler;
+
+layout (binding = 1) uniform ground_fs_params {
+    vec3 eye_position;
+    float ambient;
+    vec3 light_direction;
+    float bias;
+    vec3 light_color;
+    float fog_distance;
+    vec3 fog_color;
+};
+
+out vec4 color;
+
+void main() {
+    vec3 light_ndc = f_light_position.xyz / f_light_position.w;
+    // gl keeps depth in -1..1 and textures bottom up, the light projection is picked to match
+#if SOKOL_GLSL
+    vec3 coord = vec3(light_ndc.xy * 0.5 + 0.5, light_ndc.z * 0.5 + 0.5);
+#else
+    vec3 coord = vec3(light_ndc.x * 0.5 + 0.5, 0.5 - light_ndc.y * 0.5, light_ndc.z);
+#endif
+    float lit = texture(sampler2DShadow(shadow_map, shadow_sampler), vec3(coord.xy, coord.z - bias));
+    float diffuse = max(-light_direction.y, 0.) * lit;
+    vec3 albedo = texture(sampler2D(grid, grid_sampler), f_texcoord).rgb;
+    float fog = clamp(length(f_position - eye_position) / fog_distance, 0., 1.);
+    color = vec4(mix(albedo * (ambient + diffuse * light_color), fog_color, fog * fog), 1.);
+}
+@end
+
+@program ground ground_vertex ground_frag
+
+// / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / /
+// sky gradient with a sun, drawn as a full screen triangle behind everything
+@vs sky_vertex
+in vec2 position;
+
+layout (binding = 0) uniform sky_vs_params {
+    mat4 inverse_view_projection;
+};
+
+out vec3 f_direction;
+
+void main() {
+    gl_Position = vec4(position, 0., 1.);
+    vec4 far = inverse_view_projection * vec4(position, 1., 1.);
+    f_direction = far.xyz / far.w;
+}
+@end
+
+@fs sky_frag
+in vec3 f_direction;
+
+layout (binding = 1) uniform sky_fs_params {
+    vec3 zenith_color;
+    float sun_sharpness;
+    vec3 horizon_color;
+    float sun_intensity;
+    vec3 sun_direction;
+};
+
+out vec4 color;
+
+void main() {
+    vec3 direction = normalize(f_direction);
+    vec3 sky = mix(horizon_color, zenith_color, sqrt(max(direction.y, 0.)));
+    float sun = pow(max(dot(direction, sun_direction), 0.), sun_sharpness) * sun_intensity;
+    color = vec4(sky + vec3(sun), 1.);
+}
+@end
+
+@program sky sky_vertex sky_frag
+
+// / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / /
+// colored world-space lines, for the trajectory trail and the reference path
+@vs line_vertex
+in vec3 position;
+in vec4 v_color;
+
+layout (binding = 0) uniform line_vs_params {
+    mat4 view_projection;
+};
+
+out vec4 f_color;
+
+void main() {
+    gl_Position = view_projection * vec4(position, 1.);
+    f_color = v_color;
+}
+@end
+
+@fs line_frag
+in vec4 f_color;
+
+out vec4 color;
+
+void main() {
+    color = f_color;
+}
+@end
+
+@program line line_vertex line_frag
+
+// / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / / /
+// translucent flat-shaded copy of the model where the setpoint would put it
+@vs ghost_vertex
+in vec3 position;
+in vec3 v_normal;
+
+layout (binding = 0) uniform ghost_vs_params {
+    mat4 model;
+    mat4 view_projection;
+};
+
+out vec3 f_normal;
+
+void main() {
+    gl_Position = view_projection * model * vec4(position, 1.);
+    f_normal = mat3(model) * v_normal;
+}
+@end
+
+@fs ghost_frag
+in vec3 f_normal;
+
+layout (binding = 1) uniform ghost_fs_params {
+    vec4 ghost_color;
+    vec3 light_direction;
+    float ambient;
+};
+
+out vec4 color;
+
+void main() {
+    // both sides of the unculled mesh catch the light
+    float diffuse = abs(dot(normalize(f_normal), light_direction));
+    color = vec4(ghost_color.rgb * (ambient + (1. - ambient) * diffuse), ghost_color.a);
+}
+@end
+
+@program ghost ghost_vertex ghost_frag
@@ -0,0 +1,3753 @@
+#![allow(dead_code)]
+use sokol::gfx as sg;
+/*
+    #version:1# (machine generated, don't edit!)
+
+    Generated by sokol-shdc (https://github.com/floooh/sokol-tools)
+
+    Cmdline:
+        sokol-shdc -i ./sandbox-viewer/src/shaders.glsl -o ./sandbox-viewer/src/shaders.rs --slang hlsl5:wgsl:glsl430 -f sokol_rust
+
+    Overview:
+    =========
+    Shader program: 'texture':
+        Get shader desc: texture_shader_desc(sg::query_backend());
+        Vertex Shader: texture_vertex
+        Fragment Shader: texture_frag
+        Attributes:
+            ATTR_TEXTURE_POSITION => 0
+            ATTR_TEXTURE_V_NORMAL => 1
+            ATTR_TEXTURE_V_TEXCOORD => 2
+    Shader program: 'shadow':
+        Get shader desc: shadow_shader_desc(sg::query_backend());
+        Vertex Shader: shadow_vertex
+        Fragment Shader: shadow_frag
+        Attributes:
+            ATTR_SHADOW_POSITION => 0
+    Shader program: 'ground':
+        Get shader desc: ground_shader_desc(sg::query_backend());
+        Vertex Shader: ground_vertex
+        Fragment Shader: ground_frag
+        Attributes:
+            ATTR_GROUND_POSITION => 0
+    Shader program: 'sky':
+        Get shader desc: sky_shader_desc(sg::query_backend());
+        Vertex Shader: sky_vertex
+        Fragment Shader: sky_frag
+        Attributes:
+            ATTR_SKY_POSITION => 0
+    Shader program: 'line':
+        Get shader desc: line_shader_desc(sg::query_backend());
+        Vertex Shader: line_vertex
+        Fragment Shader: line_frag
+        Attributes:
+            ATTR_LINE_POSITION => 0
+            ATTR_LINE_V_COLOR => 1
+    Shader program: 'ghost':
+        Get shader desc: ghost_shader_desc(sg::query_backend());
+        Vertex Shader: ghost_vertex
+        Fragment Shader: ghost_frag
+        Attributes:
+            ATTR_GHOST_POSITION => 0
+            ATTR_GHOST_V_NORMAL => 1
+    Bindings:
+        Uniform block 'vs_params':
+            Rust struct: VsParams
+            Bind slot: UB_VS_PARAMS => 0
+        Uniform block 'fs_params':
+            Rust struct: FsParams
+            Bind slot: UB_FS_PARAMS => 1
+        Uniform block 'shadow_vs_params':
+            Rust struct: ShadowVsParams
+            Bind slot: UB_SHADOW_VS_PARAMS => 0
+        Uniform block 'ground_vs_params':
+            Rust struct: GroundVsParams
+            Bind slot: UB_GROUND_VS_PARAMS => 0
+        Uniform block 'ground_fs_params':
+            Rust struct: GroundFsParams
+            Bind slot: UB_GROUND_FS_PARAMS => 1
+        Uniform block 'sky_vs_params':
+            Rust struct: SkyVsParams
+            Bind slot: UB_SKY_VS_PARAMS => 0
+        Uniform block 'sky_fs_params':
+            Rust struct: SkyFsParams
+            Bind slot: UB_SKY_FS_PARAMS => 1
+        Uniform block 'line_vs_params':
+            Rust struct: LineVsParams
+            Bind slot: UB_LINE_VS_PARAMS => 0
+        Uniform block 'ghost_vs_params':
+            Rust struct: GhostVsParams
+            Bind slot: UB_GHOST_VS_PARAMS => 0
+        Uniform block 'ghost_fs_params':
+            Rust struct: GhostFsParams
+            Bind slot: UB_GHOST_FS_PARAMS => 1
+        Image 'tex':
+            Image type: sg::ImageType::Dim2
+            Sample type: sg::ImageSampleType::Float
+            Multisampled: false
+            Bind slot: IMG_TEX => 0
+        Image 'shadow_map':
+            Image type: sg::ImageType::Dim2
+            Sample type: sg::ImageSampleType::Depth
+            Multisampled: false
+            Bind slot: IMG_SHADOW_MAP => 0
+        Image 'grid':
+            Image type: sg::ImageType::Dim2
+            Sample type: sg::ImageSampleType::Float
+            Multisampled: false
+            Bind slot: IMG_GRID => 1
+        Sampler 'samp':
+            Type: sg::SamplerType::Filtering
+            Bind slot: SMP_SAMP => 1
+        Sampler 'shadow_sampler':
+            Type: sg::SamplerType::Comparison
+            Bind slot: SMP_SHADOW_SAMPLER => 0
+        Sampler 'grid_sampler':
+            Type: sg::SamplerType::Filtering
+            Bind slot: SMP_GRID_SAMPLER => 1
+*/
+pub const ATTR_TEXTURE_POSITION: usize = 0;
+pub const ATTR_TEXTURE_V_NORMAL: usize = 1;
+pub const ATTR_TEXTURE_V_TEXCOORD: usize = 2;
+pub const ATTR_SHADOW_POSITION: usize = 0;
+pub const ATTR_GROUND_POSITION: usize = 0;
+pub const ATTR_SKY_POSITION: usize = 0;
+pub const ATTR_LINE_POSITION: usize = 0;
+pub const ATTR_LINE_V_COLOR: usize = 1;
+pub const ATTR_GHOST_POSITION: usize = 0;
+pub const ATTR_GHOST_V_NORMAL: usize = 1;
+pub const UB_VS_PARAMS: usize = 0;
+pub const UB_FS_PARAMS: usize = 1;
+pub const UB_SHADOW_VS_PARAMS: usize = 0;
+pub const UB_GROUND_VS_PARAMS: usize = 0;
+pub const UB_GROUND_FS_PARAMS: usize = 1;
+pub const UB_SKY_VS_PARAMS: usize = 0;
+pub const UB_SKY_FS_PARAMS: usize = 1;
+pub const UB_LINE_VS_PARAMS: usize = 0;
+pub const UB_GHOST_VS_PARAMS: usize = 0;
+pub const UB_GHOST_FS_PARAMS: usize = 1;
+pub const IMG_TEX: usize = 0;
+pub const IMG_SHADOW_MAP: usize = 0;
+pub const IMG_GRID: usize = 1;
+pub const SMP_SAMP: usize = 1;
+pub const SMP_SHADOW_SAMPLER: usize = 0;
+pub const SMP_GRID_SAMPLER: usize = 1;
+#[repr(C, align(16))]
+pub struct VsParams {
+    pub model: [f32; 16],
+    pub view: [f32; 16],
+    pub projection: [f32; 16],
+}
+#[repr(C, align(16))]
+pub struct FsParams {
+    pub light_direction: [f32; 3],
+    pub ambient: f32,
+    pub eye_position: [f32; 3],
+    pub shininess: f32,
+    pub light_color: [f32; 3],
+    pub specular: f32,
+    pub base_color: [f32; 4],
+}
+#[repr(C, align(16))]
+pub struct ShadowVsParams {
+    pub light_model_view_projection: [f32; 16],
+}
+#[repr(C, align(16))]
+pub struct GroundVsParams {
+    pub view_projection: [f32; 16],
+    pub light_view_projection: [f32; 16],
+    pub origin: [f32; 3],
+    pub tile_size: f32,
+    pub scroll: [f32; 2],
+    pub _pad_152: [u8; 8],
+}
+#[repr(C, align(16))]
+pub struct GroundFsParams {
+    pub eye_position: [f32; 3],
+    pub ambient: f32,
+    pub light_direction: [f32; 3],
+    pub bias: f32,
+    pub light_color: [f32; 3],
+    pub fog_distance: f32,
+    pub fog_color: [f32; 3],
+    pub _pad_60: [u8; 4],
+}
+#[repr(C, align(16))]
+pub struct SkyVsParams {
+    pub inverse_view_projection: [f32; 16],
+}
+#[repr(C, align(16))]
+pub struct SkyFsParams {
+    pub zenith_color: [f32; 3],
+    pub sun_sharpness: f32,
+    pub horizon_color: [f32; 3],
+    pub sun_intensity: f32,
+    pub sun_direction: [f32; 3],
+    pub _pad_44: [u8; 4],
+}
+#[repr(C, align(16))]
+pub struct LineVsParams {
+    pub view_projection: [f32; 16],
+}
+#[repr(C, align(16))]
+pub struct GhostVsParams {
+    pub model: [f32; 16],
+    pub view_projection: [f32; 16],
+}
+#[repr(C, align(16))]
+pub struct GhostFsParams {
+    pub ghost_color: [f32; 4],
+    pub light_direction: [f32; 3],
+    pub ambient: f32,
+}
+/*
+    #version 430
+
+    uniform vec4 vs_params[12];
+    layout(location = 0) in vec3 position;
+    layout(location = 0) out vec3 f_position;
+    layout(location = 1) out vec3 f_normal;
+    layout(location = 1) in vec3 v_normal;
+    layout(location = 2) out vec2 f_texcoord;
+    layout(location = 2) in vec2 v_texcoord;
+
+    void main()
+    {
+        mat4 _21 = mat4(vs_params[0], vs_params[1], vs_params[2], vs_params[3]);
+        vec4 _31 = _21 * vec4(position, 1.0);
+        gl_Position = (mat4(vs_params[8], vs_params[9], vs_params[10], vs_params[11]) * mat4(vs_params[4], vs_params[5], vs_params[6], vs_params[7])) * _31;
+        f_position = _31.xyz;
+        f_normal = mat3(_21[0].xyz, _21[1].xyz, _21[2].xyz) * v_normal;
+        f_texcoord = v_texcoord;
+    }
+
+*/
+pub const TEXTURE_VERTEX_SOURCE_GLSL430: [u8; 699] = [
+    0x23,0x76,0x65,0x72,0x73,0x69,0x6f,0x6e,0x20,0x34,0x33,0x30,0x0a,0x0a,0x75,0x6e,
+    0x69,0x66,0x6f,0x72,0x6d,0x20,0x76,0x65,0x63,0x34,0x20,0x76,0x73,0x5f,0x70,0x61,
+    0x72,0x61,0x6d,0x73,0x5b,0x31,0x32,0x5d,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,
+    0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x30,0x29,0x20,0x69,
+    0x6e,0x20,0x76,0x65,0x63,0x33,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,
+    0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,
+    0x20,0x3d,0x20,0x30,0x29,0x20,0x6f,0x75,0x74,0x20,0x76,0x65,0x63,0x33,0x20,0x66,
+    0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,
+    0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x31,0x29,0x20,
+    0x6f,0x75,0x74,0x20,0x76,0x65,0x63,0x33,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,
+    0x6c,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,
+    0x6f,0x6e,0x20,0x3d,0x20,0x31,0x29,0x20,0x69,0x6e,0x20,0x76,0x65,0x63,0x33,0x20,
+    0x76,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,
+    0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x32,0x29,0x20,0x6f,
+    0x75,0x74,0x20,0x76,0x65,0x63,0x32,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,
+    0x72,0x64,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,
+    0x69,0x6f,0x6e,0x20,0x3d,0x20,0x32,0x29,0x20,0x69,0x6e,0x20,0x76,0x65,0x63,0x32,
+    0x20,0x76,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x3b,0x0a,0x0a,0x76,0x6f,
+    0x69,0x64,0x20,0x6d,0x61,0x69,0x6e,0x28,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,
+    0x6d,0x61,0x74,0x34,0x20,0x5f,0x32,0x31,0x20,0x3d,0x20,0x6d,0x61,0x74,0x34,0x28,
+    0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x30,0x5d,0x2c,0x20,0x76,0x73,
+    0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x31,0x5d,0x2c,0x20,0x76,0x73,0x5f,0x70,
+    0x61,0x72,0x61,0x6d,0x73,0x5b,0x32,0x5d,0x2c,0x20,0x76,0x73,0x5f,0x70,0x61,0x72,
+    0x61,0x6d,0x73,0x5b,0x33,0x5d,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x76,0x65,0x63,
+    0x34,0x20,0x5f,0x33,0x31,0x20,0x3d,0x20,0x5f,0x32,0x31,0x20,0x2a,0x20,0x76,0x65,
+    0x63,0x34,0x28,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x2c,0x20,0x31,0x2e,0x30,
+    0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,
+    0x6f,0x6e,0x20,0x3d,0x20,0x28,0x6d,0x61,0x74,0x34,0x28,0x76,0x73,0x5f,0x70,0x61,
+    0x72,0x61,0x6d,0x73,0x5b,0x38,0x5d,0x2c,0x20,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,
+    0x6d,0x73,0x5b,0x39,0x5d,0x2c,0x20,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,
+    0x5b,0x31,0x30,0x5d,0x2c,0x20,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,
+    0x31,0x31,0x5d,0x29,0x20,0x2a,0x20,0x6d,0x61,0x74,0x34,0x28,0x76,0x73,0x5f,0x70,
+    0x61,0x72,0x61,0x6d,0x73,0x5b,0x34,0x5d,0x2c,0x20,0x76,0x73,0x5f,0x70,0x61,0x72,
+    0x61,0x6d,0x73,0x5b,0x35,0x5d,0x2c,0x20,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,
+    0x73,0x5b,0x36,0x5d,0x2c,0x20,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,
+    0x37,0x5d,0x29,0x29,0x20,0x2a,0x20,0x5f,0x33,0x31,0x3b,0x0a,0x20,0x20,0x20,0x20,
+    0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x5f,0x33,0x31,
+    0x2e,0x78,0x79,0x7a,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,
+    0x61,0x6c,0x20,0x3d,0x20,0x6d,0x61,0x74,0x33,0x28,0x5f,0x32,0x31,0x5b,0x30,0x5d,
+    0x2e,0x78,0x79,0x7a,0x2c,0x20,0x5f,0x32,0x31,0x5b,0x31,0x5d,0x2e,0x78,0x79,0x7a,
+    0x2c,0x20,0x5f,0x32,0x31,0x5b,0x32,0x5d,0x2e,0x78,0x79,0x7a,0x29,0x20,0x2a,0x20,
+    0x76,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x5f,
+    0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x20,0x3d,0x20,0x76,0x5f,0x74,0x65,0x78,
+    0x63,0x6f,0x6f,0x72,0x64,0x3b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+/*
+    #version 430
+
+    uniform vec4 fs_params[4];
+    layout(binding = 16) uniform sampler2D tex_samp;
+
+    layout(location = 2) in vec2 f_texcoord;
+    layout(location = 1) in vec3 f_normal;
+    layout(location = 0) in vec3 f_position;
+    layout(location = 0) out vec4 color;
+
+    void main()
+    {
+        vec4 _22 = texture(tex_samp, f_texcoord) * fs_params[3];
+        vec3 normal = normalize(f_normal);
+        vec3 _36 = normalize(fs_params[1].xyz - f_position);
+        if (dot(normal, _36) < 0.0)
+        {
+            normal = -normal;
+        }
+        vec3 _52 = normalize(-fs_params[0].xyz);
+        float _57 = max(dot(normal, _52), 0.0);
+        float _71;
+        if (_57 > 0.0)
+        {
+            _71 = pow(max(dot(normal, normalize(_52 + _36)), 0.0), fs_params[1].w) * fs_params[2].w;
+        }
+        else
+        {
+            _71 = 0.0;
+        }
+        color = vec4((_22.xyz * (vec3(fs_params[0].w) + (fs_params[2].xyz * _57))) + (fs_params[2].xyz * _71), _22.w);
+    }
+
+*/
+pub const TEXTURE_FRAG_SOURCE_GLSL430: [u8; 882] = [
+    0x23,0x76,0x65,0x72,0x73,0x69,0x6f,0x6e,0x20,0x34,0x33,0x30,0x0a,0x0a,0x75,0x6e,
+    0x69,0x66,0x6f,0x72,0x6d,0x20,0x76,0x65,0x63,0x34,0x20,0x66,0x73,0x5f,0x70,0x61,
+    0x72,0x61,0x6d,0x73,0x5b,0x34,0x5d,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,
+    0x62,0x69,0x6e,0x64,0x69,0x6e,0x67,0x20,0x3d,0x20,0x31,0x36,0x29,0x20,0x75,0x6e,
+    0x69,0x66,0x6f,0x72,0x6d,0x20,0x73,0x61,0x6d,0x70,0x6c,0x65,0x72,0x32,0x44,0x20,
+    0x74,0x65,0x78,0x5f,0x73,0x61,0x6d,0x70,0x3b,0x0a,0x0a,0x6c,0x61,0x79,0x6f,0x75,
+    0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x32,0x29,0x20,
+    0x69,0x6e,0x20,0x76,0x65,0x63,0x32,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,
+    0x72,0x64,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,
+    0x69,0x6f,0x6e,0x20,0x3d,0x20,0x31,0x29,0x20,0x69,0x6e,0x20,0x76,0x65,0x63,0x33,
+    0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,
+    0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x30,0x29,0x20,
+    0x69,0x6e,0x20,0x76,0x65,0x63,0x33,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,
+    0x6f,0x6e,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,
+    0x69,0x6f,0x6e,0x20,0x3d,0x20,0x30,0x29,0x20,0x6f,0x75,0x74,0x20,0x76,0x65,0x63,
+    0x34,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,0x0a,0x76,0x6f,0x69,0x64,0x20,0x6d,
+    0x61,0x69,0x6e,0x28,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x76,0x65,0x63,0x34,
+    0x20,0x5f,0x32,0x32,0x20,0x3d,0x20,0x74,0x65,0x78,0x74,0x75,0x72,0x65,0x28,0x74,
+    0x65,0x78,0x5f,0x73,0x61,0x6d,0x70,0x2c,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,
+    0x6f,0x72,0x64,0x29,0x20,0x2a,0x20,0x66,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,
+    0x5b,0x33,0x5d,0x3b,0x0a,0x20,0x20,0x20,0x20,0x76,0x65,0x63,0x33,0x20,0x6e,0x6f,
+    0x72,0x6d,0x61,0x6c,0x20,0x3d,0x20,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x69,0x7a,0x65,
+    0x28,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,
+    0x76,0x65,0x63,0x33,0x20,0x5f,0x33,0x36,0x20,0x3d,0x20,0x6e,0x6f,0x72,0x6d,0x61,
+    0x6c,0x69,0x7a,0x65,0x28,0x66,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x31,
+    0x5d,0x2e,0x78,0x79,0x7a,0x20,0x2d,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,
+    0x6f,0x6e,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x69,0x66,0x20,0x28,0x64,0x6f,0x74,
+    0x28,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x2c,0x20,0x5f,0x33,0x36,0x29,0x20,0x3c,0x20,
+    0x30,0x2e,0x30,0x29,0x0a,0x20,0x20,0x20,0x20,0x7b,0x0a,0x20,0x20,0x20,0x20,0x20,
+    0x20,0x20,0x20,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3d,0x20,0x2d,0x6e,0x6f,0x72,
+    0x6d,0x61,0x6c,0x3b,0x0a,0x20,0x20,0x20,0x20,0x7d,0x0a,0x20,0x20,0x20,0x20,0x76,
+    0x65,0x63,0x33,0x20,0x5f,0x35,0x32,0x20,0x3d,0x20,0x6e,0x6f,0x72,0x6d,0x61,0x6c,
+    0x69,0x7a,0x65,0x28,0x2d,0x66,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x30,
+    0x5d,0x2e,0x78,0x79,0x7a,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,
+    0x74,0x20,0x5f,0x35,0x37,0x20,0x3d,0x20,0x6d,0x61,0x78,0x28,0x64,0x6f,0x74,0x28,
+    0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x2c,0x20,0x5f,0x35,0x32,0x29,0x2c,0x20,0x30,0x2e,
+    0x30,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x20,0x5f,0x37,
+    0x31,0x3b,0x0a,0x20,0x20,0x20,0x20,0x69,0x66,0x20,0x28,0x5f,0x35,0x37,0x20,0x3e,
+    0x20,0x30,0x2e,0x30,0x29,0x0a,0x20,0x20,0x20,0x20,0x7b,0x0a,0x20,0x20,0x20,0x20,
+    0x20,0x20,0x20,0x20,0x5f,0x37,0x31,0x20,0x3d,0x20,0x70,0x6f,0x77,0x28,0x6d,0x61,
+    0x78,0x28,0x64,0x6f,0x74,0x28,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x2c,0x20,0x6e,0x6f,
+    0x72,0x6d,0x61,0x6c,0x69,0x7a,0x65,0x28,0x5f,0x35,0x32,0x20,0x2b,0x20,0x5f,0x33,
+    0x36,0x29,0x29,0x2c,0x20,0x30,0x2e,0x30,0x29,0x2c,0x20,0x66,0x73,0x5f,0x70,0x61,
+    0x72,0x61,0x6d,0x73,0x5b,0x31,0x5d,0x2e,0x77,0x29,0x20,0x2a,0x20,0x66,0x73,0x5f,
+    0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x32,0x5d,0x2e,0x77,0x3b,0x0a,0x20,0x20,0x20,
+    0x20,0x7d,0x0a,0x20,0x20,0x20,0x20,0x65,0x6c,0x73,0x65,0x0a,0x20,0x20,0x20,0x20,
+    0x7b,0x0a,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x5f,0x37,0x31,0x20,0x3d,0x20,
+    0x30,0x2e,0x30,0x3b,0x0a,0x20,0x20,0x20,0x20,0x7d,0x0a,0x20,0x20,0x20,0x20,0x63,
+    0x6f,0x6c,0x6f,0x72,0x20,0x3d,0x20,0x76,0x65,0x63,0x34,0x28,0x28,0x5f,0x32,0x32,
+    0x2e,0x78,0x79,0x7a,0x20,0x2a,0x20,0x28,0x76,0x65,0x63,0x33,0x28,0x66,0x73,0x5f,
+    0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x30,0x5d,0x2e,0x77,0x29,0x20,0x2b,0x20,0x28,
+    0x66,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x32,0x5d,0x2e,0x78,0x79,0x7a,
+    0x20,0x2a,0x20,0x5f,0x35,0x37,0x29,0x29,0x29,0x20,0x2b,0x20,0x28,0x66,0x73,0x5f,
+    0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x32,0x5d,0x2e,0x78,0x79,0x7a,0x20,0x2a,0x20,
+    0x5f,0x37,0x31,0x29,0x2c,0x20,0x5f,0x32,0x32,0x2e,0x77,0x29,0x3b,0x0a,0x7d,0x0a,
+    0x0a,0x00,
+];
+/*
+    cbuffer vs_params : register(b0)
+    {
+        row_major float4x4 _19_model : packoffset(c0);
+        row_major float4x4 _19_view : packoffset(c4);
+        row_major float4x4 _19_projection : packoffset(c8);
+    };
+
+
+    static float4 gl_Position;
+    static float3 position;
+    static float3 f_position;
+    static float3 f_normal;
+    static float3 v_normal;
+    static float2 f_texcoord;
+    static float2 v_texcoord;
+
+    struct SPIRV_Cross_Input
+    {
+        float3 position : TEXCOORD0;
+        float3 v_normal : TEXCOORD1;
+        float2 v_texcoord : TEXCOORD2;
+    };
+
+    struct SPIRV_Cross_Output
+    {
+        float3 f_position : TEXCOORD0;
+        float3 f_normal : TEXCOORD1;
+        float2 f_texcoord : TEXCOORD2;
+        float4 gl_Position : SV_Position;
+    };
+
+    void vert_main()
+    {
+        float4 _31 = mul(float4(position, 1.0f), _19_model);
+        gl_Position = mul(_31, mul(_19_view, _19_projection));
+        f_position = _31.xyz;
+        f_normal = mul(v_normal, float3x3(_19_model[0].xyz, _19_model[1].xyz, _19_model[2].xyz));
+        f_texcoord = v_texcoord;
+    }
+
+    SPIRV_Cross_Output main(SPIRV_Cross_Input stage_input)
+    {
+        position = stage_input.position;
+        v_normal = stage_input.v_normal;
+        v_texcoord = stage_input.v_texcoord;
+        vert_main();
+        SPIRV_Cross_Output stage_output;
+        stage_output.gl_Position = gl_Position;
+        stage_output.f_position = f_position;
+        stage_output.f_normal = f_normal;
+        stage_output.f_texcoord = f_texcoord;
+        return stage_output;
+    }
+*/
+pub const TEXTURE_VERTEX_SOURCE_HLSL5: [u8; 1387] = [
+    0x63,0x62,0x75,0x66,0x66,0x65,0x72,0x20,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,
+    0x73,0x20,0x3a,0x20,0x72,0x65,0x67,0x69,0x73,0x74,0x65,0x72,0x28,0x62,0x30,0x29,
+    0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x72,0x6f,0x77,0x5f,0x6d,0x61,0x6a,0x6f,0x72,
+    0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x78,0x34,0x20,0x5f,0x31,0x39,0x5f,0x6d,0x6f,
+    0x64,0x65,0x6c,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,
+    0x28,0x63,0x30,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x72,0x6f,0x77,0x5f,0x6d,0x61,
+    0x6a,0x6f,0x72,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x78,0x34,0x20,0x5f,0x31,0x39,
+    0x5f,0x76,0x69,0x65,0x77,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,
+    0x65,0x74,0x28,0x63,0x34,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x72,0x6f,0x77,0x5f,
+    0x6d,0x61,0x6a,0x6f,0x72,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x78,0x34,0x20,0x5f,
+    0x31,0x39,0x5f,0x70,0x72,0x6f,0x6a,0x65,0x63,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,
+    0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,0x38,0x29,0x3b,0x0a,
+    0x7d,0x3b,0x0a,0x0a,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,
+    0x74,0x34,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,
+    0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x70,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,
+    0x6c,0x6f,0x61,0x74,0x33,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,
+    0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,
+    0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,
+    0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x76,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,
+    0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,0x32,0x20,
+    0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x3b,0x0a,0x73,0x74,0x61,0x74,
+    0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,0x32,0x20,0x76,0x5f,0x74,0x65,0x78,0x63,
+    0x6f,0x6f,0x72,0x64,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,0x53,0x50,
+    0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x49,0x6e,0x70,0x75,0x74,0x0a,
+    0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x70,0x6f,0x73,
+    0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x54,0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,
+    0x30,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x76,0x5f,
+    0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3a,0x20,0x54,0x45,0x58,0x43,0x4f,0x4f,0x52,
+    0x44,0x31,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x32,0x20,0x76,
+    0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x20,0x3a,0x20,0x54,0x45,0x58,0x43,
+    0x4f,0x4f,0x52,0x44,0x32,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,
+    0x74,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x4f,0x75,
+    0x74,0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,
+    0x33,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x54,
+    0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,0x30,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,
+    0x6f,0x61,0x74,0x33,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3a,0x20,
+    0x54,0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,0x31,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,
+    0x6c,0x6f,0x61,0x74,0x32,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,
+    0x20,0x3a,0x20,0x54,0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,0x32,0x3b,0x0a,0x20,0x20,
+    0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x53,0x56,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,
+    0x6f,0x6e,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,0x76,0x6f,0x69,0x64,0x20,0x76,0x65,0x72,
+    0x74,0x5f,0x6d,0x61,0x69,0x6e,0x28,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,
+    0x6c,0x6f,0x61,0x74,0x34,0x20,0x5f,0x33,0x31,0x20,0x3d,0x20,0x6d,0x75,0x6c,0x28,
+    0x66,0x6c,0x6f,0x61,0x74,0x34,0x28,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x2c,
+    0x20,0x31,0x2e,0x30,0x66,0x29,0x2c,0x20,0x5f,0x31,0x39,0x5f,0x6d,0x6f,0x64,0x65,
+    0x6c,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,
+    0x69,0x6f,0x6e,0x20,0x3d,0x20,0x6d,0x75,0x6c,0x28,0x5f,0x33,0x31,0x2c,0x20,0x6d,
+    0x75,0x6c,0x28,0x5f,0x31,0x39,0x5f,0x76,0x69,0x65,0x77,0x2c,0x20,0x5f,0x31,0x39,
+    0x5f,0x70,0x72,0x6f,0x6a,0x65,0x63,0x74,0x69,0x6f,0x6e,0x29,0x29,0x3b,0x0a,0x20,
+    0x20,0x20,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,
+    0x5f,0x33,0x31,0x2e,0x78,0x79,0x7a,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x5f,0x6e,
+    0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3d,0x20,0x6d,0x75,0x6c,0x28,0x76,0x5f,0x6e,0x6f,
+    0x72,0x6d,0x61,0x6c,0x2c,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x78,0x33,0x28,0x5f,
+    0x31,0x39,0x5f,0x6d,0x6f,0x64,0x65,0x6c,0x5b,0x30,0x5d,0x2e,0x78,0x79,0x7a,0x2c,
+    0x20,0x5f,0x31,0x39,0x5f,0x6d,0x6f,0x64,0x65,0x6c,0x5b,0x31,0x5d,0x2e,0x78,0x79,
+    0x7a,0x2c,0x20,0x5f,0x31,0x39,0x5f,0x6d,0x6f,0x64,0x65,0x6c,0x5b,0x32,0x5d,0x2e,
+    0x78,0x79,0x7a,0x29,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x5f,0x74,0x65,0x78,
+    0x63,0x6f,0x6f,0x72,0x64,0x20,0x3d,0x20,0x76,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,
+    0x72,0x64,0x3b,0x0a,0x7d,0x0a,0x0a,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,
+    0x73,0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x20,0x6d,0x61,0x69,0x6e,0x28,0x53,
+    0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x49,0x6e,0x70,0x75,0x74,
+    0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x29,0x0a,0x7b,0x0a,
+    0x20,0x20,0x20,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x73,
+    0x74,0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x2e,0x70,0x6f,0x73,0x69,0x74,
+    0x69,0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x20,0x20,0x76,0x5f,0x6e,0x6f,0x72,0x6d,0x61,
+    0x6c,0x20,0x3d,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x2e,
+    0x76,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x3b,0x0a,0x20,0x20,0x20,0x20,0x76,0x5f,
+    0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x20,0x3d,0x20,0x73,0x74,0x61,0x67,0x65,
+    0x5f,0x69,0x6e,0x70,0x75,0x74,0x2e,0x76,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,
+    0x64,0x3b,0x0a,0x20,0x20,0x20,0x20,0x76,0x65,0x72,0x74,0x5f,0x6d,0x61,0x69,0x6e,
+    0x28,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,
+    0x6f,0x73,0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x20,0x73,0x74,0x61,0x67,0x65,
+    0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x3b,0x0a,0x20,0x20,0x20,0x20,0x73,0x74,0x61,
+    0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x2e,0x67,0x6c,0x5f,0x50,0x6f,0x73,
+    0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,
+    0x69,0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x20,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x6f,
+    0x75,0x74,0x70,0x75,0x74,0x2e,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,
+    0x20,0x3d,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x20,
+    0x20,0x20,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x2e,
+    0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3d,0x20,0x66,0x5f,0x6e,0x6f,0x72,
+    0x6d,0x61,0x6c,0x3b,0x0a,0x20,0x20,0x20,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x6f,
+    0x75,0x74,0x70,0x75,0x74,0x2e,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,
+    0x20,0x3d,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x3b,0x0a,0x20,
+    0x20,0x20,0x20,0x72,0x65,0x74,0x75,0x72,0x6e,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,
+    0x6f,0x75,0x74,0x70,0x75,0x74,0x3b,0x0a,0x7d,0x0a,0x00,
+];
+/*
+    cbuffer fs_params : register(b1)
+    {
+        float3 _41_light_direction : packoffset(c0);
+        float _41_ambient : packoffset(c0.w);
+        float3 _41_eye_position : packoffset(c1);
+        float _41_shininess : packoffset(c1.w);
+        float3 _41_light_color : packoffset(c2);
+        float _41_specular : packoffset(c2.w);
+        float4 _41_base_color : packoffset(c3);
+    };
+
+    Texture2D<float4> tex : register(t0);
+    SamplerState samp : register(s0);
+
+    static float2 f_texcoord;
+    static float3 f_normal;
+    static float3 f_position;
+    static float4 color;
+
+    struct SPIRV_Cross_Input
+    {
+        float3 f_position : TEXCOORD0;
+        float3 f_normal : TEXCOORD1;
+        float2 f_texcoord : TEXCOORD2;
+    };
+
+    struct SPIRV_Cross_Output
+    {
+        float4 color : SV_Target0;
+    };
+
+    void frag_main()
+    {
+        float4 _22 = tex.Sample(samp, f_texcoord) * _41_base_color;
+        float3 normal = normalize(f_normal);
+        float3 _36 = normalize(_41_eye_position - f_position);
+        if (dot(normal, _36) < 0.0f)
+        {
+            normal = -normal;
+        }
+        float3 _52 = normalize(-_41_light_direction);
+        float _57 = max(dot(normal, _52), 0.0f);
+        float _71;
+        if (_57 > 0.0f)
+        {
+            _71 = pow(max(dot(normal, normalize(_52 + _36)), 0.0f), _41_shininess) * _41_specular;
+        }
+        else
+        {
+            _71 = 0.0f;
+        }
+        color = float4((_22.xyz * (_41_ambient.xxx + (_41_light_color * _57))) + (_41_light_color * _71), _22.w);
+    }
+
+    SPIRV_Cross_Output main(SPIRV_Cross_Input stage_input)
+    {
+        f_texcoord = stage_input.f_texcoord;
+        f_normal = stage_input.f_normal;
+        f_position = stage_input.f_position;
+        frag_main();
+        SPIRV_Cross_Output stage_output;
+        stage_output.color = color;
+        return stage_output;
+    }
+*/
+pub const TEXTURE_FRAG_SOURCE_HLSL5: [u8; 1655] = [
+    0x63,0x62,0x75,0x66,0x66,0x65,0x72,0x20,0x66,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,
+    0x73,0x20,0x3a,0x20,0x72,0x65,0x67,0x69,0x73,0x74,0x65,0x72,0x28,0x62,0x31,0x29,
+    0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x5f,0x34,
+    0x31,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,
+    0x6e,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,
+    0x30,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x20,0x5f,0x34,
+    0x31,0x5f,0x61,0x6d,0x62,0x69,0x65,0x6e,0x74,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,
+    0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,0x30,0x2e,0x77,0x29,0x3b,0x0a,0x20,0x20,
+    0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x5f,0x34,0x31,0x5f,0x65,0x79,0x65,
+    0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,
+    0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,0x31,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,
+    0x66,0x6c,0x6f,0x61,0x74,0x20,0x5f,0x34,0x31,0x5f,0x73,0x68,0x69,0x6e,0x69,0x6e,
+    0x65,0x73,0x73,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,
+    0x28,0x63,0x31,0x2e,0x77,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,
+    0x74,0x33,0x20,0x5f,0x34,0x31,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x63,0x6f,0x6c,
+    0x6f,0x72,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,
+    0x63,0x32,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x20,0x5f,
+    0x34,0x31,0x5f,0x73,0x70,0x65,0x63,0x75,0x6c,0x61,0x72,0x20,0x3a,0x20,0x70,0x61,
+    0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,0x32,0x2e,0x77,0x29,0x3b,0x0a,
+    0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x5f,0x34,0x31,0x5f,0x62,
+    0x61,0x73,0x65,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,
+    0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,0x33,0x29,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,
+    0x54,0x65,0x78,0x74,0x75,0x72,0x65,0x32,0x44,0x3c,0x66,0x6c,0x6f,0x61,0x74,0x34,
+    0x3e,0x20,0x74,0x65,0x78,0x20,0x3a,0x20,0x72,0x65,0x67,0x69,0x73,0x74,0x65,0x72,
+    0x28,0x74,0x30,0x29,0x3b,0x0a,0x53,0x61,0x6d,0x70,0x6c,0x65,0x72,0x53,0x74,0x61,
+    0x74,0x65,0x20,0x73,0x61,0x6d,0x70,0x20,0x3a,0x20,0x72,0x65,0x67,0x69,0x73,0x74,
+    0x65,0x72,0x28,0x73,0x30,0x29,0x3b,0x0a,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,
+    0x66,0x6c,0x6f,0x61,0x74,0x32,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,
+    0x64,0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,
+    0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,
+    0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,
+    0x69,0x6f,0x6e,0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,
+    0x74,0x34,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,
+    0x74,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x49,0x6e,
+    0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,
+    0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x54,0x45,
+    0x58,0x43,0x4f,0x4f,0x52,0x44,0x30,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,
+    0x61,0x74,0x33,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3a,0x20,0x54,
+    0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,0x31,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,
+    0x6f,0x61,0x74,0x32,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x20,
+    0x3a,0x20,0x54,0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,0x32,0x3b,0x0a,0x7d,0x3b,0x0a,
+    0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,
+    0x6f,0x73,0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,0x20,0x20,
+    0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3a,0x20,
+    0x53,0x56,0x5f,0x54,0x61,0x72,0x67,0x65,0x74,0x30,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,
+    0x76,0x6f,0x69,0x64,0x20,0x66,0x72,0x61,0x67,0x5f,0x6d,0x61,0x69,0x6e,0x28,0x29,
+    0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x5f,0x32,
+    0x32,0x20,0x3d,0x20,0x74,0x65,0x78,0x2e,0x53,0x61,0x6d,0x70,0x6c,0x65,0x28,0x73,
+    0x61,0x6d,0x70,0x2c,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x29,
+    0x20,0x2a,0x20,0x5f,0x34,0x31,0x5f,0x62,0x61,0x73,0x65,0x5f,0x63,0x6f,0x6c,0x6f,
+    0x72,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x6e,0x6f,
+    0x72,0x6d,0x61,0x6c,0x20,0x3d,0x20,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x69,0x7a,0x65,
+    0x28,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,
+    0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x5f,0x33,0x36,0x20,0x3d,0x20,0x6e,0x6f,0x72,
+    0x6d,0x61,0x6c,0x69,0x7a,0x65,0x28,0x5f,0x34,0x31,0x5f,0x65,0x79,0x65,0x5f,0x70,
+    0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x2d,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x69,0x66,0x20,0x28,0x64,
+    0x6f,0x74,0x28,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x2c,0x20,0x5f,0x33,0x36,0x29,0x20,
+    0x3c,0x20,0x30,0x2e,0x30,0x66,0x29,0x0a,0x20,0x20,0x20,0x20,0x7b,0x0a,0x20,0x20,
+    0x20,0x20,0x20,0x20,0x20,0x20,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3d,0x20,0x2d,
+    0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x3b,0x0a,0x20,0x20,0x20,0x20,0x7d,0x0a,0x20,0x20,
+    0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x5f,0x35,0x32,0x20,0x3d,0x20,0x6e,
+    0x6f,0x72,0x6d,0x61,0x6c,0x69,0x7a,0x65,0x28,0x2d,0x5f,0x34,0x31,0x5f,0x6c,0x69,
+    0x67,0x68,0x74,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,0x29,0x3b,0x0a,
+    0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x20,0x5f,0x35,0x37,0x20,0x3d,0x20,
+    0x6d,0x61,0x78,0x28,0x64,0x6f,0x74,0x28,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x2c,0x20,
+    0x5f,0x35,0x32,0x29,0x2c,0x20,0x30,0x2e,0x30,0x66,0x29,0x3b,0x0a,0x20,0x20,0x20,
+    0x20,0x66,0x6c,0x6f,0x61,0x74,0x20,0x5f,0x37,0x31,0x3b,0x0a,0x20,0x20,0x20,0x20,
+    0x69,0x66,0x20,0x28,0x5f,0x35,0x37,0x20,0x3e,0x20,0x30,0x2e,0x30,0x66,0x29,0x0a,
+    0x20,0x20,0x20,0x20,0x7b,0x0a,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x20,0x5f,0x37,
+    0x31,0x20,0x3d,0x20,0x70,0x6f,0x77,0x28,0x6d,0x61,0x78,0x28,0x64,0x6f,0x74,0x28,
+    0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x2c,0x20,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x69,0x7a,
+    0x65,0x28,0x5f,0x35,0x32,0x20,0x2b,0x20,0x5f,0x33,0x36,0x29,0x29,0x2c,0x20,0x30,
+    0x2e,0x30,0x66,0x29,0x2c,0x20,0x5f,0x34,0x31,0x5f,0x73,0x68,0x69,0x6e,0x69,0x6e,
+    0x65,0x73,0x73,0x29,0x20,0x2a,0x20,0x5f,0x34,0x31,0x5f,0x73,0x70,0x65,0x63,0x75,
+    0x6c,0x61,0x72,0x3b,0x0a,0x20,0x20,0x20,0x20,0x7d,0x0a,0x20,0x20,0x20,0x20,0x65,
+    0x6c,0x73,0x65,0x0a,0x20,0x20,0x20,0x20,0x7b,0x0a,0x20,0x20,0x20,0x20,0x20,0x20,
+    0x20,0x20,0x5f,0x37,0x31,0x20,0x3d,0x20,0x30,0x2e,0x30,0x66,0x3b,0x0a,0x20,0x20,
+    0x20,0x20,0x7d,0x0a,0x20,0x20,0x20,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3d,0x20,
+    0x66,0x6c,0x6f,0x61,0x74,0x34,0x28,0x28,0x5f,0x32,0x32,0x2e,0x78,0x79,0x7a,0x20,
+    0x2a,0x20,0x28,0x5f,0x34,0x31,0x5f,0x61,0x6d,0x62,0x69,0x65,0x6e,0x74,0x2e,0x78,
+    0x78,0x78,0x20,0x2b,0x20,0x28,0x5f,0x34,0x31,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,
+    0x63,0x6f,0x6c,0x6f,0x72,0x20,0x2a,0x20,0x5f,0x35,0x37,0x29,0x29,0x29,0x20,0x2b,
+    0x20,0x28,0x5f,0x34,0x31,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x63,0x6f,0x6c,0x6f,
+    0x72,0x20,0x2a,0x20,0x5f,0x37,0x31,0x29,0x2c,0x20,0x5f,0x32,0x32,0x2e,0x77,0x29,
+    0x3b,0x0a,0x7d,0x0a,0x0a,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,
+    0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x20,0x6d,0x61,0x69,0x6e,0x28,0x53,0x50,0x49,
+    0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x49,0x6e,0x70,0x75,0x74,0x20,0x73,
+    0x74,0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x29,0x0a,0x7b,0x0a,0x20,0x20,
+    0x20,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x20,0x3d,0x20,0x73,
+    0x74,0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x2e,0x66,0x5f,0x74,0x65,0x78,
+    0x63,0x6f,0x6f,0x72,0x64,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x5f,0x6e,0x6f,0x72,
+    0x6d,0x61,0x6c,0x20,0x3d,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,
+    0x74,0x2e,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x3b,0x0a,0x20,0x20,0x20,0x20,
+    0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x73,0x74,0x61,
+    0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x2e,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,
+    0x69,0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x72,0x61,0x67,0x5f,0x6d,0x61,
+    0x69,0x6e,0x28,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,
+    0x43,0x72,0x6f,0x73,0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x20,0x73,0x74,0x61,
+    0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x3b,0x0a,0x20,0x20,0x20,0x20,0x73,
+    0x74,0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x2e,0x63,0x6f,0x6c,0x6f,
+    0x72,0x20,0x3d,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,0x20,0x20,0x20,0x20,0x72,
+    0x65,0x74,0x75,0x72,0x6e,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,
+    0x75,0x74,0x3b,0x0a,0x7d,0x0a,0x00,
+];
+/*
+    diagnostic(off, derivative_uniformity);
+
+    struct vs_params {
+      /_ @offset(0) _/
+      model : mat4x4f,
+      /_ @offset(64) _/
+      view : mat4x4f,
+      /_ @offset(128) _/
+      projection : mat4x4f,
+    }
+
+    @group(0) @binding(0) var<uniform> x_19 : vs_params;
+
+    var<private> position_1 : vec3f;
+
+    var<private> f_position : vec3f;
+
+    var<private> f_normal : vec3f;
+
+    var<private> v_normal : vec3f;
+
+    var<private> f_texcoord : vec2f;
+
+    var<private> v_texcoord : vec2f;
+
+    var<private> gl_Position : vec4f;
+
+    fn main_1() {
+      let x_21 : mat4x4f = x_19.model;
+      let x_26 : vec3f = position_1;
+      let x_31 : vec4f = (x_21 * vec4f(x_26.x, x_26.y, x_26.z, 1.0f));
+      let x_34 : mat4x4f = x_19.projection;
+      let x_37 : mat4x4f = x_19.view;
+      gl_Position = ((x_34 * x_37) * x_31);
+      f_position = vec3f(x_31.x, x_31.y, x_31.z);
+      let x_50 : vec3f = v_normal;
+      f_normal = (mat3x3f(x_21[0u].xyz, x_21[1u].xyz, x_21[2u].xyz) * x_50);
+      let x_58 : vec2f = v_texcoord;
+      f_texcoord = x_58;
+      return;
+    }
+
+    struct main_out {
+      @builtin(position)
+      gl_Position : vec4f,
+      @location(0)
+      f_position_1 : vec3f,
+      @location(1)
+      f_normal_1 : vec3f,
+      @location(2)
+      f_texcoord_1 : vec2f,
+    }
+
+    @vertex
+    fn main(@location(0) position_1_param : vec3f, @location(1) v_normal_param : vec3f, @location(2) v_texcoord_param : vec2f) -> main_out {
+      position_1 = position_1_param;
+      v_normal = v_normal_param;
+      v_texcoord = v_texcoord_param;
+      main_1();
+      return main_out(gl_Position, f_position, f_normal, f_texcoord);
+    }
+
+*/
+pub const TEXTURE_VERTEX_SOURCE_WGSL: [u8; 1455] = [
+    0x64,0x69,0x61,0x67,0x6e,0x6f,0x73,0x74,0x69,0x63,0x28,0x6f,0x66,0x66,0x2c,0x20,
+    0x64,0x65,0x72,0x69,0x76,0x61,0x74,0x69,0x76,0x65,0x5f,0x75,0x6e,0x69,0x66,0x6f,
+    0x72,0x6d,0x69,0x74,0x79,0x29,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,
+    0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x20,0x7b,0x0a,0x20,0x20,0x2f,0x2a,
+    0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x30,0x29,0x20,0x2a,0x2f,0x0a,0x20,
+    0x20,0x6d,0x6f,0x64,0x65,0x6c,0x20,0x3a,0x20,0x6d,0x61,0x74,0x34,0x78,0x34,0x66,
+    0x2c,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x36,
+    0x34,0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,0x76,0x69,0x65,0x77,0x20,0x3a,0x20,0x6d,
+    0x61,0x74,0x34,0x78,0x34,0x66,0x2c,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,
+    0x66,0x73,0x65,0x74,0x28,0x31,0x32,0x38,0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,0x70,
+    0x72,0x6f,0x6a,0x65,0x63,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x6d,0x61,0x74,0x34,
+    0x78,0x34,0x66,0x2c,0x0a,0x7d,0x0a,0x0a,0x40,0x67,0x72,0x6f,0x75,0x70,0x28,0x30,
+    0x29,0x20,0x40,0x62,0x69,0x6e,0x64,0x69,0x6e,0x67,0x28,0x30,0x29,0x20,0x76,0x61,
+    0x72,0x3c,0x75,0x6e,0x69,0x66,0x6f,0x72,0x6d,0x3e,0x20,0x78,0x5f,0x31,0x39,0x20,
+    0x3a,0x20,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x3b,0x0a,0x0a,0x76,0x61,
+    0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x70,0x6f,0x73,0x69,0x74,
+    0x69,0x6f,0x6e,0x5f,0x31,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x3b,0x0a,0x0a,
+    0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x66,0x5f,0x70,
+    0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x3b,
+    0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x66,
+    0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x3b,
+    0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x76,
+    0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x3b,
+    0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x66,
+    0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x20,0x3a,0x20,0x76,0x65,0x63,0x32,
+    0x66,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,
+    0x20,0x76,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x20,0x3a,0x20,0x76,0x65,
+    0x63,0x32,0x66,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,
+    0x65,0x3e,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,
+    0x20,0x76,0x65,0x63,0x34,0x66,0x3b,0x0a,0x0a,0x66,0x6e,0x20,0x6d,0x61,0x69,0x6e,
+    0x5f,0x31,0x28,0x29,0x20,0x7b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x32,
+    0x31,0x20,0x3a,0x20,0x6d,0x61,0x74,0x34,0x78,0x34,0x66,0x20,0x3d,0x20,0x78,0x5f,
+    0x31,0x39,0x2e,0x6d,0x6f,0x64,0x65,0x6c,0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,
+    0x78,0x5f,0x32,0x36,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x20,0x3d,0x20,0x70,
+    0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,
+    0x20,0x78,0x5f,0x33,0x31,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x20,0x3d,0x20,
+    0x28,0x78,0x5f,0x32,0x31,0x20,0x2a,0x20,0x76,0x65,0x63,0x34,0x66,0x28,0x78,0x5f,
+    0x32,0x36,0x2e,0x78,0x2c,0x20,0x78,0x5f,0x32,0x36,0x2e,0x79,0x2c,0x20,0x78,0x5f,
+    0x32,0x36,0x2e,0x7a,0x2c,0x20,0x31,0x2e,0x30,0x66,0x29,0x29,0x3b,0x0a,0x20,0x20,
+    0x6c,0x65,0x74,0x20,0x78,0x5f,0x33,0x34,0x20,0x3a,0x20,0x6d,0x61,0x74,0x34,0x78,
+    0x34,0x66,0x20,0x3d,0x20,0x78,0x5f,0x31,0x39,0x2e,0x70,0x72,0x6f,0x6a,0x65,0x63,
+    0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x33,0x37,
+    0x20,0x3a,0x20,0x6d,0x61,0x74,0x34,0x78,0x34,0x66,0x20,0x3d,0x20,0x78,0x5f,0x31,
+    0x39,0x2e,0x76,0x69,0x65,0x77,0x3b,0x0a,0x20,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,
+    0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x28,0x28,0x78,0x5f,0x33,0x34,0x20,0x2a,
+    0x20,0x78,0x5f,0x33,0x37,0x29,0x20,0x2a,0x20,0x78,0x5f,0x33,0x31,0x29,0x3b,0x0a,
+    0x20,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x76,
+    0x65,0x63,0x33,0x66,0x28,0x78,0x5f,0x33,0x31,0x2e,0x78,0x2c,0x20,0x78,0x5f,0x33,
+    0x31,0x2e,0x79,0x2c,0x20,0x78,0x5f,0x33,0x31,0x2e,0x7a,0x29,0x3b,0x0a,0x20,0x20,
+    0x6c,0x65,0x74,0x20,0x78,0x5f,0x35,0x30,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,
+    0x20,0x3d,0x20,0x76,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x3b,0x0a,0x20,0x20,0x66,
+    0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3d,0x20,0x28,0x6d,0x61,0x74,0x33,0x78,
+    0x33,0x66,0x28,0x78,0x5f,0x32,0x31,0x5b,0x30,0x75,0x5d,0x2e,0x78,0x79,0x7a,0x2c,
+    0x20,0x78,0x5f,0x32,0x31,0x5b,0x31,0x75,0x5d,0x2e,0x78,0x79,0x7a,0x2c,0x20,0x78,
+    0x5f,0x32,0x31,0x5b,0x32,0x75,0x5d,0x2e,0x78,0x79,0x7a,0x29,0x20,0x2a,0x20,0x78,
+    0x5f,0x35,0x30,0x29,0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x35,0x38,
+    0x20,0x3a,0x20,0x76,0x65,0x63,0x32,0x66,0x20,0x3d,0x20,0x76,0x5f,0x74,0x65,0x78,
+    0x63,0x6f,0x6f,0x72,0x64,0x3b,0x0a,0x20,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,
+    0x6f,0x72,0x64,0x20,0x3d,0x20,0x78,0x5f,0x35,0x38,0x3b,0x0a,0x20,0x20,0x72,0x65,
+    0x74,0x75,0x72,0x6e,0x3b,0x0a,0x7d,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,
+    0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,0x74,0x20,0x7b,0x0a,0x20,0x20,0x40,0x62,0x75,
+    0x69,0x6c,0x74,0x69,0x6e,0x28,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x29,0x0a,
+    0x20,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,
+    0x76,0x65,0x63,0x34,0x66,0x2c,0x0a,0x20,0x20,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,
+    0x6f,0x6e,0x28,0x30,0x29,0x0a,0x20,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,
+    0x6f,0x6e,0x5f,0x31,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x2c,0x0a,0x20,0x20,
+    0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x31,0x29,0x0a,0x20,0x20,0x66,
+    0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x5f,0x31,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,
+    0x66,0x2c,0x0a,0x20,0x20,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x32,
+    0x29,0x0a,0x20,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x5f,0x31,
+    0x20,0x3a,0x20,0x76,0x65,0x63,0x32,0x66,0x2c,0x0a,0x7d,0x0a,0x0a,0x40,0x76,0x65,
+    0x72,0x74,0x65,0x78,0x0a,0x66,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x28,0x40,0x6c,0x6f,
+    0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x30,0x29,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,
+    0x6f,0x6e,0x5f,0x31,0x5f,0x70,0x61,0x72,0x61,0x6d,0x20,0x3a,0x20,0x76,0x65,0x63,
+    0x33,0x66,0x2c,0x20,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x31,0x29,
+    0x20,0x76,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x5f,0x70,0x61,0x72,0x61,0x6d,0x20,
+    0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x2c,0x20,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,
+    0x6f,0x6e,0x28,0x32,0x29,0x20,0x76,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,
+    0x5f,0x70,0x61,0x72,0x61,0x6d,0x20,0x3a,0x20,0x76,0x65,0x63,0x32,0x66,0x29,0x20,
+    0x2d,0x3e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,0x74,0x20,0x7b,0x0a,0x20,0x20,
+    0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x20,0x3d,0x20,0x70,0x6f,0x73,
+    0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x5f,0x70,0x61,0x72,0x61,0x6d,0x3b,0x0a,0x20,
+    0x20,0x76,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3d,0x20,0x76,0x5f,0x6e,0x6f,
+    0x72,0x6d,0x61,0x6c,0x5f,0x70,0x61,0x72,0x61,0x6d,0x3b,0x0a,0x20,0x20,0x76,0x5f,
+    0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x20,0x3d,0x20,0x76,0x5f,0x74,0x65,0x78,
+    0x63,0x6f,0x6f,0x72,0x64,0x5f,0x70,0x61,0x72,0x61,0x6d,0x3b,0x0a,0x20,0x20,0x6d,
+    0x61,0x69,0x6e,0x5f,0x31,0x28,0x29,0x3b,0x0a,0x20,0x20,0x72,0x65,0x74,0x75,0x72,
+    0x6e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,0x74,0x28,0x67,0x6c,0x5f,0x50,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x2c,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,
+    0x6f,0x6e,0x2c,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x2c,0x20,0x66,0x5f,
+    0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x29,0x3b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+/*
+    diagnostic(off, derivative_uniformity);
+
+    struct fs_params {
+      /_ @offset(0) _/
+      light_direction : vec3f,
+      /_ @offset(12) _/
+      ambient : f32,
+      /_ @offset(16) _/
+      eye_position : vec3f,
+      /_ @offset(28) _/
+      shininess : f32,
+      /_ @offset(32) _/
+      light_color : vec3f,
+      /_ @offset(44) _/
+      specular : f32,
+      /_ @offset(48) _/
+      base_color : vec4f,
+    }
+
+    @group(1) @binding(64) var tex : texture_2d<f32>;
+
+    @group(1) @binding(80) var samp : sampler;
+
+    @group(0) @binding(1) var<uniform> x_41 : fs_params;
+
+    var<private> f_texcoord : vec2f;
+
+    var<private> f_normal : vec3f;
+
+    var<private> f_position : vec3f;
+
+    var<private> color : vec4f;
+
+    fn main_1() {
+      var normal : vec3f;
+      var x_71 : f32;
+      let x_22 : vec4f = (textureSample(tex, samp, f_texcoord) * x_41.base_color);
+      normal = normalize(f_normal);
+      let x_36 : vec3f = normalize((x_41.eye_position - f_position));
+      if ((dot(normal, x_36) < 0.0f)) {
+        normal = -(normal);
+      }
+      let x_52 : vec3f = normalize(-(x_41.light_direction));
+      let x_57 : f32 = max(dot(normal, x_52), 0.0f);
+      if ((x_57 > 0.0f)) {
+        x_71 = (pow(max(dot(normal, normalize((x_52 + x_36))), 0.0f), x_41.shininess) * x_41.specular);
+      } else {
+        x_71 = 0.0f;
+      }
+      color = vec4f(((x_22.xyz * (vec3f(x_41.ambient) + (x_41.light_color * x_57))) + (x_41.light_color * x_71)), x_22.w);
+      return;
+    }
+
+    struct main_out {
+      @location(0)
+      color_1 : vec4f,
+    }
+
+    @fragment
+    fn main(@location(0) f_position_param : vec3f, @location(1) f_normal_param : vec3f, @location(2) f_texcoord_param : vec2f) -> main_out {
+      f_position = f_position_param;
+      f_normal = f_normal_param;
+      f_texcoord = f_texcoord_param;
+      main_1();
+      return main_out(color);
+    }
+
+*/
+pub const TEXTURE_FRAG_SOURCE_WGSL: [u8; 1657] = [
+    0x64,0x69,0x61,0x67,0x6e,0x6f,0x73,0x74,0x69,0x63,0x28,0x6f,0x66,0x66,0x2c,0x20,
+    0x64,0x65,0x72,0x69,0x76,0x61,0x74,0x69,0x76,0x65,0x5f,0x75,0x6e,0x69,0x66,0x6f,
+    0x72,0x6d,0x69,0x74,0x79,0x29,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,
+    0x66,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x20,0x7b,0x0a,0x20,0x20,0x2f,0x2a,
+    0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x30,0x29,0x20,0x2a,0x2f,0x0a,0x20,
+    0x20,0x6c,0x69,0x67,0x68,0x74,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,
+    0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x2c,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,
+    0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x31,0x32,0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,
+    0x61,0x6d,0x62,0x69,0x65,0x6e,0x74,0x20,0x3a,0x20,0x66,0x33,0x32,0x2c,0x0a,0x20,
+    0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x31,0x36,0x29,0x20,
+    0x2a,0x2f,0x0a,0x20,0x20,0x65,0x79,0x65,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,
+    0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x2c,0x0a,0x20,0x20,0x2f,0x2a,0x20,
+    0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x32,0x38,0x29,0x20,0x2a,0x2f,0x0a,0x20,
+    0x20,0x73,0x68,0x69,0x6e,0x69,0x6e,0x65,0x73,0x73,0x20,0x3a,0x20,0x66,0x33,0x32,
+    0x2c,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x33,
+    0x32,0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,0x6c,0x69,0x67,0x68,0x74,0x5f,0x63,0x6f,
+    0x6c,0x6f,0x72,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x2c,0x0a,0x20,0x20,0x2f,
+    0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x34,0x34,0x29,0x20,0x2a,0x2f,
+    0x0a,0x20,0x20,0x73,0x70,0x65,0x63,0x75,0x6c,0x61,0x72,0x20,0x3a,0x20,0x66,0x33,
+    0x32,0x2c,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,
+    0x34,0x38,0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,0x62,0x61,0x73,0x65,0x5f,0x63,0x6f,
+    0x6c,0x6f,0x72,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x2c,0x0a,0x7d,0x0a,0x0a,
+    0x40,0x67,0x72,0x6f,0x75,0x70,0x28,0x31,0x29,0x20,0x40,0x62,0x69,0x6e,0x64,0x69,
+    0x6e,0x67,0x28,0x36,0x34,0x29,0x20,0x76,0x61,0x72,0x20,0x74,0x65,0x78,0x20,0x3a,
+    0x20,0x74,0x65,0x78,0x74,0x75,0x72,0x65,0x5f,0x32,0x64,0x3c,0x66,0x33,0x32,0x3e,
+    0x3b,0x0a,0x0a,0x40,0x67,0x72,0x6f,0x75,0x70,0x28,0x31,0x29,0x20,0x40,0x62,0x69,
+    0x6e,0x64,0x69,0x6e,0x67,0x28,0x38,0x30,0x29,0x20,0x76,0x61,0x72,0x20,0x73,0x61,
+    0x6d,0x70,0x20,0x3a,0x20,0x73,0x61,0x6d,0x70,0x6c,0x65,0x72,0x3b,0x0a,0x0a,0x40,
+    0x67,0x72,0x6f,0x75,0x70,0x28,0x30,0x29,0x20,0x40,0x62,0x69,0x6e,0x64,0x69,0x6e,
+    0x67,0x28,0x31,0x29,0x20,0x76,0x61,0x72,0x3c,0x75,0x6e,0x69,0x66,0x6f,0x72,0x6d,
+    0x3e,0x20,0x78,0x5f,0x34,0x31,0x20,0x3a,0x20,0x66,0x73,0x5f,0x70,0x61,0x72,0x61,
+    0x6d,0x73,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,
+    0x3e,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x20,0x3a,0x20,0x76,
+    0x65,0x63,0x32,0x66,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,
+    0x74,0x65,0x3e,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3a,0x20,0x76,
+    0x65,0x63,0x33,0x66,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,
+    0x74,0x65,0x3e,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,
+    0x20,0x76,0x65,0x63,0x33,0x66,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,
+    0x76,0x61,0x74,0x65,0x3e,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3a,0x20,0x76,0x65,
+    0x63,0x34,0x66,0x3b,0x0a,0x0a,0x66,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x31,0x28,
+    0x29,0x20,0x7b,0x0a,0x20,0x20,0x76,0x61,0x72,0x20,0x6e,0x6f,0x72,0x6d,0x61,0x6c,
+    0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x3b,0x0a,0x20,0x20,0x76,0x61,0x72,0x20,
+    0x78,0x5f,0x37,0x31,0x20,0x3a,0x20,0x66,0x33,0x32,0x3b,0x0a,0x20,0x20,0x6c,0x65,
+    0x74,0x20,0x78,0x5f,0x32,0x32,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x20,0x3d,
+    0x20,0x28,0x74,0x65,0x78,0x74,0x75,0x72,0x65,0x53,0x61,0x6d,0x70,0x6c,0x65,0x28,
+    0x74,0x65,0x78,0x2c,0x20,0x73,0x61,0x6d,0x70,0x2c,0x20,0x66,0x5f,0x74,0x65,0x78,
+    0x63,0x6f,0x6f,0x72,0x64,0x29,0x20,0x2a,0x20,0x78,0x5f,0x34,0x31,0x2e,0x62,0x61,
+    0x73,0x65,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x29,0x3b,0x0a,0x20,0x20,0x6e,0x6f,0x72,
+    0x6d,0x61,0x6c,0x20,0x3d,0x20,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x69,0x7a,0x65,0x28,
+    0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x29,0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,
+    0x20,0x78,0x5f,0x33,0x36,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x20,0x3d,0x20,
+    0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x69,0x7a,0x65,0x28,0x28,0x78,0x5f,0x34,0x31,0x2e,
+    0x65,0x79,0x65,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x2d,0x20,0x66,
+    0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x29,0x29,0x3b,0x0a,0x20,0x20,0x69,
+    0x66,0x20,0x28,0x28,0x64,0x6f,0x74,0x28,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x2c,0x20,
+    0x78,0x5f,0x33,0x36,0x29,0x20,0x3c,0x20,0x30,0x2e,0x30,0x66,0x29,0x29,0x20,0x7b,
+    0x0a,0x20,0x20,0x20,0x20,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3d,0x20,0x2d,0x28,
+    0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x29,0x3b,0x0a,0x20,0x20,0x7d,0x0a,0x20,0x20,0x6c,
+    0x65,0x74,0x20,0x78,0x5f,0x35,0x32,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x20,
+    0x3d,0x20,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x69,0x7a,0x65,0x28,0x2d,0x28,0x78,0x5f,
+    0x34,0x31,0x2e,0x6c,0x69,0x67,0x68,0x74,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,
+    0x6f,0x6e,0x29,0x29,0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x35,0x37,
+    0x20,0x3a,0x20,0x66,0x33,0x32,0x20,0x3d,0x20,0x6d,0x61,0x78,0x28,0x64,0x6f,0x74,
+    0x28,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x2c,0x20,0x78,0x5f,0x35,0x32,0x29,0x2c,0x20,
+    0x30,0x2e,0x30,0x66,0x29,0x3b,0x0a,0x20,0x20,0x69,0x66,0x20,0x28,0x28,0x78,0x5f,
+    0x35,0x37,0x20,0x3e,0x20,0x30,0x2e,0x30,0x66,0x29,0x29,0x20,0x7b,0x0a,0x20,0x20,
+    0x20,0x20,0x78,0x5f,0x37,0x31,0x20,0x3d,0x20,0x28,0x70,0x6f,0x77,0x28,0x6d,0x61,
+    0x78,0x28,0x64,0x6f,0x74,0x28,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x2c,0x20,0x6e,0x6f,
+    0x72,0x6d,0x61,0x6c,0x69,0x7a,0x65,0x28,0x28,0x78,0x5f,0x35,0x32,0x20,0x2b,0x20,
+    0x78,0x5f,0x33,0x36,0x29,0x29,0x29,0x2c,0x20,0x30,0x2e,0x30,0x66,0x29,0x2c,0x20,
+    0x78,0x5f,0x34,0x31,0x2e,0x73,0x68,0x69,0x6e,0x69,0x6e,0x65,0x73,0x73,0x29,0x20,
+    0x2a,0x20,0x78,0x5f,0x34,0x31,0x2e,0x73,0x70,0x65,0x63,0x75,0x6c,0x61,0x72,0x29,
+    0x3b,0x0a,0x20,0x20,0x7d,0x20,0x65,0x6c,0x73,0x65,0x20,0x7b,0x0a,0x20,0x20,0x20,
+    0x20,0x78,0x5f,0x37,0x31,0x20,0x3d,0x20,0x30,0x2e,0x30,0x66,0x3b,0x0a,0x20,0x20,
+    0x7d,0x0a,0x20,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3d,0x20,0x76,0x65,0x63,0x34,
+    0x66,0x28,0x28,0x28,0x78,0x5f,0x32,0x32,0x2e,0x78,0x79,0x7a,0x20,0x2a,0x20,0x28,
+    0x76,0x65,0x63,0x33,0x66,0x28,0x78,0x5f,0x34,0x31,0x2e,0x61,0x6d,0x62,0x69,0x65,
+    0x6e,0x74,0x29,0x20,0x2b,0x20,0x28,0x78,0x5f,0x34,0x31,0x2e,0x6c,0x69,0x67,0x68,
+    0x74,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x2a,0x20,0x78,0x5f,0x35,0x37,0x29,0x29,
+    0x29,0x20,0x2b,0x20,0x28,0x78,0x5f,0x34,0x31,0x2e,0x6c,0x69,0x67,0x68,0x74,0x5f,
+    0x63,0x6f,0x6c,0x6f,0x72,0x20,0x2a,0x20,0x78,0x5f,0x37,0x31,0x29,0x29,0x2c,0x20,
+    0x78,0x5f,0x32,0x32,0x2e,0x77,0x29,0x3b,0x0a,0x20,0x20,0x72,0x65,0x74,0x75,0x72,
+    0x6e,0x3b,0x0a,0x7d,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,0x6d,0x61,0x69,
+    0x6e,0x5f,0x6f,0x75,0x74,0x20,0x7b,0x0a,0x20,0x20,0x40,0x6c,0x6f,0x63,0x61,0x74,
+    0x69,0x6f,0x6e,0x28,0x30,0x29,0x0a,0x20,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x5f,0x31,
+    0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x2c,0x0a,0x7d,0x0a,0x0a,0x40,0x66,0x72,
+    0x61,0x67,0x6d,0x65,0x6e,0x74,0x0a,0x66,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x28,0x40,
+    0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x30,0x29,0x20,0x66,0x5f,0x70,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x70,0x61,0x72,0x61,0x6d,0x20,0x3a,0x20,0x76,
+    0x65,0x63,0x33,0x66,0x2c,0x20,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,
+    0x31,0x29,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x5f,0x70,0x61,0x72,0x61,
+    0x6d,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x2c,0x20,0x40,0x6c,0x6f,0x63,0x61,
+    0x74,0x69,0x6f,0x6e,0x28,0x32,0x29,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,
+    0x72,0x64,0x5f,0x70,0x61,0x72,0x61,0x6d,0x20,0x3a,0x20,0x76,0x65,0x63,0x32,0x66,
+    0x29,0x20,0x2d,0x3e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,0x74,0x20,0x7b,0x0a,
+    0x20,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x66,
+    0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x70,0x61,0x72,0x61,0x6d,0x3b,
+    0x0a,0x20,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3d,0x20,0x66,0x5f,
+    0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x5f,0x70,0x61,0x72,0x61,0x6d,0x3b,0x0a,0x20,0x20,
+    0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x20,0x3d,0x20,0x66,0x5f,0x74,
+    0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x5f,0x70,0x61,0x72,0x61,0x6d,0x3b,0x0a,0x20,
+    0x20,0x6d,0x61,0x69,0x6e,0x5f,0x31,0x28,0x29,0x3b,0x0a,0x20,0x20,0x72,0x65,0x74,
+    0x75,0x72,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,0x74,0x28,0x63,0x6f,0x6c,
+    0x6f,0x72,0x29,0x3b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+/*
+    #version 430
+
+    uniform vec4 shadow_vs_params[4];
+    layout(location = 0) in vec3 position;
+
+    void main()
+    {
+        gl_Position = mat4(shadow_vs_params[0], shadow_vs_params[1], shadow_vs_params[2], shadow_vs_params[3]) * vec4(position, 1.0);
+    }
+
+*/
+pub const SHADOW_VERTEX_SOURCE_GLSL430: [u8; 236] = [
+    0x23,0x76,0x65,0x72,0x73,0x69,0x6f,0x6e,0x20,0x34,0x33,0x30,0x0a,0x0a,0x75,0x6e,
+    0x69,0x66,0x6f,0x72,0x6d,0x20,0x76,0x65,0x63,0x34,0x20,0x73,0x68,0x61,0x64,0x6f,
+    0x77,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x34,0x5d,0x3b,0x0a,
+    0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x20,
+    0x3d,0x20,0x30,0x29,0x20,0x69,0x6e,0x20,0x76,0x65,0x63,0x33,0x20,0x70,0x6f,0x73,
+    0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x0a,0x76,0x6f,0x69,0x64,0x20,0x6d,0x61,0x69,
+    0x6e,0x28,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,
+    0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x6d,0x61,0x74,0x34,0x28,0x73,0x68,0x61,
+    0x64,0x6f,0x77,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x30,0x5d,
+    0x2c,0x20,0x73,0x68,0x61,0x64,0x6f,0x77,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,
+    0x6d,0x73,0x5b,0x31,0x5d,0x2c,0x20,0x73,0x68,0x61,0x64,0x6f,0x77,0x5f,0x76,0x73,
+    0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x32,0x5d,0x2c,0x20,0x73,0x68,0x61,0x64,
+    0x6f,0x77,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x33,0x5d,0x29,
+    0x20,0x2a,0x20,0x76,0x65,0x63,0x34,0x28,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,
+    0x2c,0x20,0x31,0x2e,0x30,0x29,0x3b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+/*
+    #version 430
+
+    void main()
+    {
+    }
+
+*/
+pub const SHADOW_FRAG_SOURCE_GLSL430: [u8; 32] = [
+    0x23,0x76,0x65,0x72,0x73,0x69,0x6f,0x6e,0x20,0x34,0x33,0x30,0x0a,0x0a,0x76,0x6f,
+    0x69,0x64,0x20,0x6d,0x61,0x69,0x6e,0x28,0x29,0x0a,0x7b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+/*
+    cbuffer shadow_vs_params : register(b0)
+    {
+        row_major float4x4 _19_light_model_view_projection : packoffset(c0);
+    };
+
+
+    static float4 gl_Position;
+    static float3 position;
+
+    struct SPIRV_Cross_Input
+    {
+        float3 position : TEXCOORD0;
+    };
+
+    struct SPIRV_Cross_Output
+    {
+        float4 gl_Position : SV_Position;
+    };
+
+    void vert_main()
+    {
+        gl_Position = mul(float4(position, 1.0f), _19_light_model_view_projection);
+    }
+
+    SPIRV_Cross_Output main(SPIRV_Cross_Input stage_input)
+    {
+        position = stage_input.position;
+        vert_main();
+        SPIRV_Cross_Output stage_output;
+        stage_output.gl_Position = gl_Position;
+        return stage_output;
+    }
+*/
+pub const SHADOW_VERTEX_SOURCE_HLSL5: [u8; 628] = [
+    0x63,0x62,0x75,0x66,0x66,0x65,0x72,0x20,0x73,0x68,0x61,0x64,0x6f,0x77,0x5f,0x76,
+    0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x20,0x3a,0x20,0x72,0x65,0x67,0x69,0x73,
+    0x74,0x65,0x72,0x28,0x62,0x30,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x72,0x6f,
+    0x77,0x5f,0x6d,0x61,0x6a,0x6f,0x72,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x78,0x34,
+    0x20,0x5f,0x31,0x39,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x6d,0x6f,0x64,0x65,0x6c,
+    0x5f,0x76,0x69,0x65,0x77,0x5f,0x70,0x72,0x6f,0x6a,0x65,0x63,0x74,0x69,0x6f,0x6e,
+    0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,0x30,
+    0x29,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,
+    0x6c,0x6f,0x61,0x74,0x34,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,
+    0x6e,0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,
+    0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,
+    0x63,0x74,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x49,
+    0x6e,0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,
+    0x33,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x54,0x45,0x58,
+    0x43,0x4f,0x4f,0x52,0x44,0x30,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,
+    0x63,0x74,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x4f,
+    0x75,0x74,0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,
+    0x74,0x34,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,
+    0x20,0x53,0x56,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x7d,0x3b,
+    0x0a,0x0a,0x76,0x6f,0x69,0x64,0x20,0x76,0x65,0x72,0x74,0x5f,0x6d,0x61,0x69,0x6e,
+    0x28,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x6d,0x75,0x6c,0x28,0x66,0x6c,0x6f,0x61,0x74,
+    0x34,0x28,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x2c,0x20,0x31,0x2e,0x30,0x66,
+    0x29,0x2c,0x20,0x5f,0x31,0x39,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x6d,0x6f,0x64,
+    0x65,0x6c,0x5f,0x76,0x69,0x65,0x77,0x5f,0x70,0x72,0x6f,0x6a,0x65,0x63,0x74,0x69,
+    0x6f,0x6e,0x29,0x3b,0x0a,0x7d,0x0a,0x0a,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,
+    0x6f,0x73,0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x20,0x6d,0x61,0x69,0x6e,0x28,
+    0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x49,0x6e,0x70,0x75,
+    0x74,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x29,0x0a,0x7b,
+    0x0a,0x20,0x20,0x20,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,
+    0x73,0x74,0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x2e,0x70,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x20,0x20,0x76,0x65,0x72,0x74,0x5f,0x6d,
+    0x61,0x69,0x6e,0x28,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x53,0x50,0x49,0x52,0x56,
+    0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x20,0x73,0x74,
+    0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x3b,0x0a,0x20,0x20,0x20,0x20,
+    0x73,0x74,0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x2e,0x67,0x6c,0x5f,
+    0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x67,0x6c,0x5f,0x50,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x20,0x20,0x72,0x65,0x74,0x75,
+    0x72,0x6e,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x3b,
+    0x0a,0x7d,0x0a,0x00,
+];
+/*
+    void frag_main()
+    {
+    }
+
+    void main()
+    {
+        frag_main();
+    }
+*/
+pub const SHADOW_FRAG_SOURCE_HLSL5: [u8; 56] = [
+    0x76,0x6f,0x69,0x64,0x20,0x66,0x72,0x61,0x67,0x5f,0x6d,0x61,0x69,0x6e,0x28,0x29,
+    0x0a,0x7b,0x0a,0x7d,0x0a,0x0a,0x76,0x6f,0x69,0x64,0x20,0x6d,0x61,0x69,0x6e,0x28,
+    0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x72,0x61,0x67,0x5f,0x6d,0x61,0x69,
+    0x6e,0x28,0x29,0x3b,0x0a,0x7d,0x0a,0x00,
+];
+/*
+    diagnostic(off, derivative_uniformity);
+
+    struct shadow_vs_params {
+      /_ @offset(0) _/
+      light_model_view_projection : mat4x4f,
+    }
+
+    @group(0) @binding(0) var<uniform> x_19 : shadow_vs_params;
+
+    var<private> position_1 : vec3f;
+
+    var<private> gl_Position : vec4f;
+
+    fn main_1() {
+      let x_22 : mat4x4f = x_19.light_model_view_projection;
+      let x_26 : vec3f = position_1;
+      gl_Position = (x_22 * vec4f(x_26.x, x_26.y, x_26.z, 1.0f));
+      return;
+    }
+
+    struct main_out {
+      @builtin(position)
+      gl_Position : vec4f,
+    }
+
+    @vertex
+    fn main(@location(0) position_1_param : vec3f) -> main_out {
+      position_1 = position_1_param;
+      main_1();
+      return main_out(gl_Position);
+    }
+
+*/
+pub const SHADOW_VERTEX_SOURCE_WGSL: [u8; 654] = [
+    0x64,0x69,0x61,0x67,0x6e,0x6f,0x73,0x74,0x69,0x63,0x28,0x6f,0x66,0x66,0x2c,0x20,
+    0x64,0x65,0x72,0x69,0x76,0x61,0x74,0x69,0x76,0x65,0x5f,0x75,0x6e,0x69,0x66,0x6f,
+    0x72,0x6d,0x69,0x74,0x79,0x29,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,
+    0x73,0x68,0x61,0x64,0x6f,0x77,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,
+    0x20,0x7b,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,
+    0x30,0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,0x6c,0x69,0x67,0x68,0x74,0x5f,0x6d,0x6f,
+    0x64,0x65,0x6c,0x5f,0x76,0x69,0x65,0x77,0x5f,0x70,0x72,0x6f,0x6a,0x65,0x63,0x74,
+    0x69,0x6f,0x6e,0x20,0x3a,0x20,0x6d,0x61,0x74,0x34,0x78,0x34,0x66,0x2c,0x0a,0x7d,
+    0x0a,0x0a,0x40,0x67,0x72,0x6f,0x75,0x70,0x28,0x30,0x29,0x20,0x40,0x62,0x69,0x6e,
+    0x64,0x69,0x6e,0x67,0x28,0x30,0x29,0x20,0x76,0x61,0x72,0x3c,0x75,0x6e,0x69,0x66,
+    0x6f,0x72,0x6d,0x3e,0x20,0x78,0x5f,0x31,0x39,0x20,0x3a,0x20,0x73,0x68,0x61,0x64,
+    0x6f,0x77,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x3b,0x0a,0x0a,0x76,
+    0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x70,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x5f,0x31,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x3b,0x0a,
+    0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x67,0x6c,
+    0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,
+    0x66,0x3b,0x0a,0x0a,0x66,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x31,0x28,0x29,0x20,
+    0x7b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x32,0x32,0x20,0x3a,0x20,0x6d,
+    0x61,0x74,0x34,0x78,0x34,0x66,0x20,0x3d,0x20,0x78,0x5f,0x31,0x39,0x2e,0x6c,0x69,
+    0x67,0x68,0x74,0x5f,0x6d,0x6f,0x64,0x65,0x6c,0x5f,0x76,0x69,0x65,0x77,0x5f,0x70,
+    0x72,0x6f,0x6a,0x65,0x63,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,
+    0x20,0x78,0x5f,0x32,0x36,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x20,0x3d,0x20,
+    0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x3b,0x0a,0x20,0x20,0x67,0x6c,
+    0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x28,0x78,0x5f,0x32,
+    0x32,0x20,0x2a,0x20,0x76,0x65,0x63,0x34,0x66,0x28,0x78,0x5f,0x32,0x36,0x2e,0x78,
+    0x2c,0x20,0x78,0x5f,0x32,0x36,0x2e,0x79,0x2c,0x20,0x78,0x5f,0x32,0x36,0x2e,0x7a,
+    0x2c,0x20,0x31,0x2e,0x30,0x66,0x29,0x29,0x3b,0x0a,0x20,0x20,0x72,0x65,0x74,0x75,
+    0x72,0x6e,0x3b,0x0a,0x7d,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,0x6d,0x61,
+    0x69,0x6e,0x5f,0x6f,0x75,0x74,0x20,0x7b,0x0a,0x20,0x20,0x40,0x62,0x75,0x69,0x6c,
+    0x74,0x69,0x6e,0x28,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x29,0x0a,0x20,0x20,
+    0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x76,0x65,
+    0x63,0x34,0x66,0x2c,0x0a,0x7d,0x0a,0x0a,0x40,0x76,0x65,0x72,0x74,0x65,0x78,0x0a,
+    0x66,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x28,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,
+    0x6e,0x28,0x30,0x29,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x5f,
+    0x70,0x61,0x72,0x61,0x6d,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x29,0x20,0x2d,
+    0x3e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,0x74,0x20,0x7b,0x0a,0x20,0x20,0x70,
+    0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x20,0x3d,0x20,0x70,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x5f,0x31,0x5f,0x70,0x61,0x72,0x61,0x6d,0x3b,0x0a,0x20,0x20,
+    0x6d,0x61,0x69,0x6e,0x5f,0x31,0x28,0x29,0x3b,0x0a,0x20,0x20,0x72,0x65,0x74,0x75,
+    0x72,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,0x74,0x28,0x67,0x6c,0x5f,0x50,
+    0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x29,0x3b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+/*
+    diagnostic(off, derivative_uniformity);
+
+    fn main_1() {
+      return;
+    }
+
+    @fragment
+    fn main() {
+      main_1();
+    }
+
+*/
+pub const SHADOW_FRAG_SOURCE_WGSL: [u8; 106] = [
+    0x64,0x69,0x61,0x67,0x6e,0x6f,0x73,0x74,0x69,0x63,0x28,0x6f,0x66,0x66,0x2c,0x20,
+    0x64,0x65,0x72,0x69,0x76,0x61,0x74,0x69,0x76,0x65,0x5f,0x75,0x6e,0x69,0x66,0x6f,
+    0x72,0x6d,0x69,0x74,0x79,0x29,0x3b,0x0a,0x0a,0x66,0x6e,0x20,0x6d,0x61,0x69,0x6e,
+    0x5f,0x31,0x28,0x29,0x20,0x7b,0x0a,0x20,0x20,0x72,0x65,0x74,0x75,0x72,0x6e,0x3b,
+    0x0a,0x7d,0x0a,0x0a,0x40,0x66,0x72,0x61,0x67,0x6d,0x65,0x6e,0x74,0x0a,0x66,0x6e,
+    0x20,0x6d,0x61,0x69,0x6e,0x28,0x29,0x20,0x7b,0x0a,0x20,0x20,0x6d,0x61,0x69,0x6e,
+    0x5f,0x31,0x28,0x29,0x3b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+/*
+    #version 430
+
+    uniform vec4 ground_vs_params[10];
+    layout(location = 0) in vec3 position;
+    layout(location = 0) out vec3 f_position;
+    layout(location = 1) out vec4 f_light_position;
+    layout(location = 2) out vec2 f_texcoord;
+
+    void main()
+    {
+        vec4 _27 = vec4(position + ground_vs_params[8].xyz, 1.0);
+        gl_Position = mat4(ground_vs_params[0], ground_vs_params[1], ground_vs_params[2], ground_vs_params[3]) * _27;
+        f_position = _27.xyz;
+        f_light_position = mat4(ground_vs_params[4], ground_vs_params[5], ground_vs_params[6], ground_vs_params[7]) * _27;
+        f_texcoord = (_27.xz + ground_vs_params[9].xy) / vec2(ground_vs_params[8].w);
+    }
+
+*/
+pub const GROUND_VERTEX_SOURCE_GLSL430: [u8; 642] = [
+    0x23,0x76,0x65,0x72,0x73,0x69,0x6f,0x6e,0x20,0x34,0x33,0x30,0x0a,0x0a,0x75,0x6e,
+    0x69,0x66,0x6f,0x72,0x6d,0x20,0x76,0x65,0x63,0x34,0x20,0x67,0x72,0x6f,0x75,0x6e,
+    0x64,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x31,0x30,0x5d,0x3b,
+    0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,
+    0x20,0x3d,0x20,0x30,0x29,0x20,0x69,0x6e,0x20,0x76,0x65,0x63,0x33,0x20,0x70,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,
+    0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x30,0x29,0x20,0x6f,0x75,0x74,
+    0x20,0x76,0x65,0x63,0x33,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,
+    0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,
+    0x6e,0x20,0x3d,0x20,0x31,0x29,0x20,0x6f,0x75,0x74,0x20,0x76,0x65,0x63,0x34,0x20,
+    0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,
+    0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,
+    0x6e,0x20,0x3d,0x20,0x32,0x29,0x20,0x6f,0x75,0x74,0x20,0x76,0x65,0x63,0x32,0x20,
+    0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x3b,0x0a,0x0a,0x76,0x6f,0x69,
+    0x64,0x20,0x6d,0x61,0x69,0x6e,0x28,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x76,
+    0x65,0x63,0x34,0x20,0x5f,0x32,0x37,0x20,0x3d,0x20,0x76,0x65,0x63,0x34,0x28,0x70,
+    0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x2b,0x20,0x67,0x72,0x6f,0x75,0x6e,0x64,
+    0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x38,0x5d,0x2e,0x78,0x79,
+    0x7a,0x2c,0x20,0x31,0x2e,0x30,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x67,0x6c,0x5f,
+    0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x6d,0x61,0x74,0x34,0x28,
+    0x67,0x72,0x6f,0x75,0x6e,0x64,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,
+    0x5b,0x30,0x5d,0x2c,0x20,0x67,0x72,0x6f,0x75,0x6e,0x64,0x5f,0x76,0x73,0x5f,0x70,
+    0x61,0x72,0x61,0x6d,0x73,0x5b,0x31,0x5d,0x2c,0x20,0x67,0x72,0x6f,0x75,0x6e,0x64,
+    0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x32,0x5d,0x2c,0x20,0x67,
+    0x72,0x6f,0x75,0x6e,0x64,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,
+    0x33,0x5d,0x29,0x20,0x2a,0x20,0x5f,0x32,0x37,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,
+    0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x5f,0x32,0x37,0x2e,
+    0x78,0x79,0x7a,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,
+    0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x6d,0x61,0x74,0x34,
+    0x28,0x67,0x72,0x6f,0x75,0x6e,0x64,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,
+    0x73,0x5b,0x34,0x5d,0x2c,0x20,0x67,0x72,0x6f,0x75,0x6e,0x64,0x5f,0x76,0x73,0x5f,
+    0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x35,0x5d,0x2c,0x20,0x67,0x72,0x6f,0x75,0x6e,
+    0x64,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x36,0x5d,0x2c,0x20,
+    0x67,0x72,0x6f,0x75,0x6e,0x64,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,
+    0x5b,0x37,0x5d,0x29,0x20,0x2a,0x20,0x5f,0x32,0x37,0x3b,0x0a,0x20,0x20,0x20,0x20,
+    0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x20,0x3d,0x20,0x28,0x5f,0x32,
+    0x37,0x2e,0x78,0x7a,0x20,0x2b,0x20,0x67,0x72,0x6f,0x75,0x6e,0x64,0x5f,0x76,0x73,
+    0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x39,0x5d,0x2e,0x78,0x79,0x29,0x20,0x2f,
+    0x20,0x76,0x65,0x63,0x32,0x28,0x67,0x72,0x6f,0x75,0x6e,0x64,0x5f,0x76,0x73,0x5f,
+    0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x38,0x5d,0x2e,0x77,0x29,0x3b,0x0a,0x7d,0x0a,
+    0x0a,0x00,
+];
+/*
+    #version 430
+
+    uniform vec4 ground_fs_params[4];
+    layout(binding = 16) uniform sampler2DShadow shadow_map_shadow_sampler;
+    layout(binding = 17) uniform sampler2D grid_grid_sampler;
+
+    layout(location = 1) in vec4 f_light_position;
+    layout(location = 2) in vec2 f_texcoord;
+    layout(location = 0) in vec3 f_position;
+    layout(location = 0) out vec4 color;
+
+    void main()
+    {
+        vec3 _22 = f_light_position.xyz / vec3(f_light_position.w);
+        vec3 _35 = vec3((_22.xy * 0.5) + vec2(0.5), (_22.z * 0.5) + 0.5);
+        float _50 = texture(shadow_map_shadow_sampler, vec3(_35.xy, _35.z - ground_fs_params[1].w));
+        float _86 = clamp(length(f_position - ground_fs_params[0].xyz) / ground_fs_params[2].w, 0.0, 1.0);
+        color = vec4(mix(texture(grid_grid_sampler, f_texcoord).xyz * (vec3(ground_fs_params[0].w) + (ground_fs_params[2].xyz * (max(-ground_fs_params[1].y, 0.0) * _50))), ground_fs_params[3].xyz, vec3(_86 * _86)), 1.0);
+    }
+
+*/
+pub const GROUND_FRAG_SOURCE_GLSL430: [u8; 915] = [
+    0x23,0x76,0x65,0x72,0x73,0x69,0x6f,0x6e,0x20,0x34,0x33,0x30,0x0a,0x0a,0x75,0x6e,
+    0x69,0x66,0x6f,0x72,0x6d,0x20,0x76,0x65,0x63,0x34,0x20,0x67,0x72,0x6f,0x75,0x6e,
+    0x64,0x5f,0x66,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x34,0x5d,0x3b,0x0a,
+    0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x62,0x69,0x6e,0x64,0x69,0x6e,0x67,0x20,0x3d,
+    0x20,0x31,0x36,0x29,0x20,0x75,0x6e,0x69,0x66,0x6f,0x72,0x6d,0x20,0x73,0x61,0x6d,
+    0x70,0x6c,0x65,0x72,0x32,0x44,0x53,0x68,0x61,0x64,0x6f,0x77,0x20,0x73,0x68,0x61,
+    0x64,0x6f,0x77,0x5f,0x6d,0x61,0x70,0x5f,0x73,0x68,0x61,0x64,0x6f,0x77,0x5f,0x73,
+    0x61,0x6d,0x70,0x6c,0x65,0x72,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x62,
+    0x69,0x6e,0x64,0x69,0x6e,0x67,0x20,0x3d,0x20,0x31,0x37,0x29,0x20,0x75,0x6e,0x69,
+    0x66,0x6f,0x72,0x6d,0x20,0x73,0x61,0x6d,0x70,0x6c,0x65,0x72,0x32,0x44,0x20,0x67,
+    0x72,0x69,0x64,0x5f,0x67,0x72,0x69,0x64,0x5f,0x73,0x61,0x6d,0x70,0x6c,0x65,0x72,
+    0x3b,0x0a,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,
+    0x6f,0x6e,0x20,0x3d,0x20,0x31,0x29,0x20,0x69,0x6e,0x20,0x76,0x65,0x63,0x34,0x20,
+    0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,
+    0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,
+    0x6e,0x20,0x3d,0x20,0x32,0x29,0x20,0x69,0x6e,0x20,0x76,0x65,0x63,0x32,0x20,0x66,
+    0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,
+    0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x30,0x29,0x20,
+    0x69,0x6e,0x20,0x76,0x65,0x63,0x33,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,
+    0x6f,0x6e,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,
+    0x69,0x6f,0x6e,0x20,0x3d,0x20,0x30,0x29,0x20,0x6f,0x75,0x74,0x20,0x76,0x65,0x63,
+    0x34,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,0x0a,0x76,0x6f,0x69,0x64,0x20,0x6d,
+    0x61,0x69,0x6e,0x28,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x76,0x65,0x63,0x33,
+    0x20,0x5f,0x32,0x32,0x20,0x3d,0x20,0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x70,
+    0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x2e,0x78,0x79,0x7a,0x20,0x2f,0x20,0x76,0x65,
+    0x63,0x33,0x28,0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,0x69,0x74,
+    0x69,0x6f,0x6e,0x2e,0x77,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x76,0x65,0x63,0x33,
+    0x20,0x5f,0x33,0x35,0x20,0x3d,0x20,0x76,0x65,0x63,0x33,0x28,0x28,0x5f,0x32,0x32,
+    0x2e,0x78,0x79,0x20,0x2a,0x20,0x30,0x2e,0x35,0x29,0x20,0x2b,0x20,0x76,0x65,0x63,
+    0x32,0x28,0x30,0x2e,0x35,0x29,0x2c,0x20,0x28,0x5f,0x32,0x32,0x2e,0x7a,0x20,0x2a,
+    0x20,0x30,0x2e,0x35,0x29,0x20,0x2b,0x20,0x30,0x2e,0x35,0x29,0x3b,0x0a,0x20,0x20,
+    0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x20,0x5f,0x35,0x30,0x20,0x3d,0x20,0x74,0x65,
+    0x78,0x74,0x75,0x72,0x65,0x28,0x73,0x68,0x61,0x64,0x6f,0x77,0x5f,0x6d,0x61,0x70,
+    0x5f,0x73,0x68,0x61,0x64,0x6f,0x77,0x5f,0x73,0x61,0x6d,0x70,0x6c,0x65,0x72,0x2c,
+    0x20,0x76,0x65,0x63,0x33,0x28,0x5f,0x33,0x35,0x2e,0x78,0x79,0x2c,0x20,0x5f,0x33,
+    0x35,0x2e,0x7a,0x20,0x2d,0x20,0x67,0x72,0x6f,0x75,0x6e,0x64,0x5f,0x66,0x73,0x5f,
+    0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x31,0x5d,0x2e,0x77,0x29,0x29,0x3b,0x0a,0x20,
+    0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x20,0x5f,0x38,0x36,0x20,0x3d,0x20,0x63,
+    0x6c,0x61,0x6d,0x70,0x28,0x6c,0x65,0x6e,0x67,0x74,0x68,0x28,0x66,0x5f,0x70,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x2d,0x20,0x67,0x72,0x6f,0x75,0x6e,0x64,0x5f,
+    0x66,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x30,0x5d,0x2e,0x78,0x79,0x7a,
+    0x29,0x20,0x2f,0x20,0x67,0x72,0x6f,0x75,0x6e,0x64,0x5f,0x66,0x73,0x5f,0x70,0x61,
+    0x72,0x61,0x6d,0x73,0x5b,0x32,0x5d,0x2e,0x77,0x2c,0x20,0x30,0x2e,0x30,0x2c,0x20,
+    0x31,0x2e,0x30,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x20,
+    0x3d,0x20,0x76,0x65,0x63,0x34,0x28,0x6d,0x69,0x78,0x28,0x74,0x65,0x78,0x74,0x75,
+    0x72,0x65,0x28,0x67,0x72,0x69,0x64,0x5f,0x67,0x72,0x69,0x64,0x5f,0x73,0x61,0x6d,
+    0x70,0x6c,0x65,0x72,0x2c,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,
+    0x29,0x2e,0x78,0x79,0x7a,0x20,0x2a,0x20,0x28,0x76,0x65,0x63,0x33,0x28,0x67,0x72,
+    0x6f,0x75,0x6e,0x64,0x5f,0x66,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x30,
+    0x5d,0x2e,0x77,0x29,0x20,0x2b,0x20,0x28,0x67,0x72,0x6f,0x75,0x6e,0x64,0x5f,0x66,
+    0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x32,0x5d,0x2e,0x78,0x79,0x7a,0x20,
+    0x2a,0x20,0x28,0x6d,0x61,0x78,0x28,0x2d,0x67,0x72,0x6f,0x75,0x6e,0x64,0x5f,0x66,
+    0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x31,0x5d,0x2e,0x79,0x2c,0x20,0x30,
+    0x2e,0x30,0x29,0x20,0x2a,0x20,0x5f,0x35,0x30,0x29,0x29,0x29,0x2c,0x20,0x67,0x72,
+    0x6f,0x75,0x6e,0x64,0x5f,0x66,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x33,
+    0x5d,0x2e,0x78,0x79,0x7a,0x2c,0x20,0x76,0x65,0x63,0x33,0x28,0x5f,0x38,0x36,0x20,
+    0x2a,0x20,0x5f,0x38,0x36,0x29,0x29,0x2c,0x20,0x31,0x2e,0x30,0x29,0x3b,0x0a,0x7d,
+    0x0a,0x0a,0x00,
+];
+/*
+    cbuffer ground_vs_params : register(b0)
+    {
+        row_major float4x4 _19_view_projection : packoffset(c0);
+        row_major float4x4 _19_light_view_projection : packoffset(c4);
+        float3 _19_origin : packoffset(c8);
+        float _19_tile_size : packoffset(c8.w);
+        float2 _19_scroll : packoffset(c9);
+    };
+
+
+    static float4 gl_Position;
+    static float3 position;
+    static float3 f_position;
+    static float4 f_light_position;
+    static float2 f_texcoord;
+
+    struct SPIRV_Cross_Input
+    {
+        float3 position : TEXCOORD0;
+    };
+
+    struct SPIRV_Cross_Output
+    {
+        float3 f_position : TEXCOORD0;
+        float4 f_light_position : TEXCOORD1;
+        float2 f_texcoord : TEXCOORD2;
+        float4 gl_Position : SV_Position;
+    };
+
+    void vert_main()
+    {
+        float4 _27 = float4(position + _19_origin, 1.0f);
+        gl_Position = mul(_27, _19_view_projection);
+        f_position = _27.xyz;
+        f_light_position = mul(_27, _19_light_view_projection);
+        f_texcoord = (_27.xz + _19_scroll) / _19_tile_size.xx;
+    }
+
+    SPIRV_Cross_Output main(SPIRV_Cross_Input stage_input)
+    {
+        position = stage_input.position;
+        vert_main();
+        SPIRV_Cross_Output stage_output;
+        stage_output.gl_Position = gl_Position;
+        stage_output.f_position = f_position;
+        stage_output.f_light_position = f_light_position;
+        stage_output.f_texcoord = f_texcoord;
+        return stage_output;
+    }
+*/
+pub const GROUND_VERTEX_SOURCE_HLSL5: [u8; 1308] = [
+    0x63,0x62,0x75,0x66,0x66,0x65,0x72,0x20,0x67,0x72,0x6f,0x75,0x6e,0x64,0x5f,0x76,
+    0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x20,0x3a,0x20,0x72,0x65,0x67,0x69,0x73,
+    0x74,0x65,0x72,0x28,0x62,0x30,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x72,0x6f,
+    0x77,0x5f,0x6d,0x61,0x6a,0x6f,0x72,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x78,0x34,
+    0x20,0x5f,0x31,0x39,0x5f,0x76,0x69,0x65,0x77,0x5f,0x70,0x72,0x6f,0x6a,0x65,0x63,
+    0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,
+    0x74,0x28,0x63,0x30,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x72,0x6f,0x77,0x5f,0x6d,
+    0x61,0x6a,0x6f,0x72,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x78,0x34,0x20,0x5f,0x31,
+    0x39,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x76,0x69,0x65,0x77,0x5f,0x70,0x72,0x6f,
+    0x6a,0x65,0x63,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,
+    0x66,0x73,0x65,0x74,0x28,0x63,0x34,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,
+    0x6f,0x61,0x74,0x33,0x20,0x5f,0x31,0x39,0x5f,0x6f,0x72,0x69,0x67,0x69,0x6e,0x20,
+    0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,0x38,0x29,
+    0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x20,0x5f,0x31,0x39,0x5f,
+    0x74,0x69,0x6c,0x65,0x5f,0x73,0x69,0x7a,0x65,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,
+    0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,0x38,0x2e,0x77,0x29,0x3b,0x0a,0x20,0x20,
+    0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x32,0x20,0x5f,0x31,0x39,0x5f,0x73,0x63,0x72,
+    0x6f,0x6c,0x6c,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,
+    0x28,0x63,0x39,0x29,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,0x0a,0x73,0x74,0x61,0x74,0x69,
+    0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,
+    0x61,0x74,0x33,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x73,0x74,
+    0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x66,0x5f,0x70,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,
+    0x6c,0x6f,0x61,0x74,0x34,0x20,0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x70,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,
+    0x6c,0x6f,0x61,0x74,0x32,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,
+    0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,
+    0x43,0x72,0x6f,0x73,0x73,0x5f,0x49,0x6e,0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,0x20,
+    0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,
+    0x6e,0x20,0x3a,0x20,0x54,0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,0x30,0x3b,0x0a,0x7d,
+    0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,
+    0x43,0x72,0x6f,0x73,0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,
+    0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x54,0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,0x30,
+    0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x66,0x5f,0x6c,
+    0x69,0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,
+    0x54,0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,0x31,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,
+    0x6c,0x6f,0x61,0x74,0x32,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,
+    0x20,0x3a,0x20,0x54,0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,0x32,0x3b,0x0a,0x20,0x20,
+    0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x53,0x56,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,
+    0x6f,0x6e,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,0x76,0x6f,0x69,0x64,0x20,0x76,0x65,0x72,
+    0x74,0x5f,0x6d,0x61,0x69,0x6e,0x28,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,
+    0x6c,0x6f,0x61,0x74,0x34,0x20,0x5f,0x32,0x37,0x20,0x3d,0x20,0x66,0x6c,0x6f,0x61,
+    0x74,0x34,0x28,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x2b,0x20,0x5f,0x31,
+    0x39,0x5f,0x6f,0x72,0x69,0x67,0x69,0x6e,0x2c,0x20,0x31,0x2e,0x30,0x66,0x29,0x3b,
+    0x0a,0x20,0x20,0x20,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,
+    0x20,0x3d,0x20,0x6d,0x75,0x6c,0x28,0x5f,0x32,0x37,0x2c,0x20,0x5f,0x31,0x39,0x5f,
+    0x76,0x69,0x65,0x77,0x5f,0x70,0x72,0x6f,0x6a,0x65,0x63,0x74,0x69,0x6f,0x6e,0x29,
+    0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,
+    0x20,0x3d,0x20,0x5f,0x32,0x37,0x2e,0x78,0x79,0x7a,0x3b,0x0a,0x20,0x20,0x20,0x20,
+    0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,
+    0x20,0x3d,0x20,0x6d,0x75,0x6c,0x28,0x5f,0x32,0x37,0x2c,0x20,0x5f,0x31,0x39,0x5f,
+    0x6c,0x69,0x67,0x68,0x74,0x5f,0x76,0x69,0x65,0x77,0x5f,0x70,0x72,0x6f,0x6a,0x65,
+    0x63,0x74,0x69,0x6f,0x6e,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x5f,0x74,0x65,
+    0x78,0x63,0x6f,0x6f,0x72,0x64,0x20,0x3d,0x20,0x28,0x5f,0x32,0x37,0x2e,0x78,0x7a,
+    0x20,0x2b,0x20,0x5f,0x31,0x39,0x5f,0x73,0x63,0x72,0x6f,0x6c,0x6c,0x29,0x20,0x2f,
+    0x20,0x5f,0x31,0x39,0x5f,0x74,0x69,0x6c,0x65,0x5f,0x73,0x69,0x7a,0x65,0x2e,0x78,
+    0x78,0x3b,0x0a,0x7d,0x0a,0x0a,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,
+    0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x20,0x6d,0x61,0x69,0x6e,0x28,0x53,0x50,
+    0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x49,0x6e,0x70,0x75,0x74,0x20,
+    0x73,0x74,0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x29,0x0a,0x7b,0x0a,0x20,
+    0x20,0x20,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x73,0x74,
+    0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x2e,0x70,0x6f,0x73,0x69,0x74,0x69,
+    0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x20,0x20,0x76,0x65,0x72,0x74,0x5f,0x6d,0x61,0x69,
+    0x6e,0x28,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,
+    0x72,0x6f,0x73,0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x20,0x73,0x74,0x61,0x67,
+    0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x3b,0x0a,0x20,0x20,0x20,0x20,0x73,0x74,
+    0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x2e,0x67,0x6c,0x5f,0x50,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x20,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,
+    0x6f,0x75,0x74,0x70,0x75,0x74,0x2e,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,
+    0x6e,0x20,0x3d,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,
+    0x20,0x20,0x20,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,
+    0x2e,0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,
+    0x6e,0x20,0x3d,0x20,0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x20,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,
+    0x6f,0x75,0x74,0x70,0x75,0x74,0x2e,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,
+    0x64,0x20,0x3d,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x3b,0x0a,
+    0x20,0x20,0x20,0x20,0x72,0x65,0x74,0x75,0x72,0x6e,0x20,0x73,0x74,0x61,0x67,0x65,
+    0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x3b,0x0a,0x7d,0x0a,0x00,
+];
+/*
+    cbuffer ground_fs_params : register(b1)
+    {
+        float3 _41_eye_position : packoffset(c0);
+        float _41_ambient : packoffset(c0.w);
+        float3 _41_light_direction : packoffset(c1);
+        float _41_bias : packoffset(c1.w);
+        float3 _41_light_color : packoffset(c2);
+        float _41_fog_distance : packoffset(c2.w);
+        float3 _41_fog_color : packoffset(c3);
+    };
+
+    Texture2D<float4> shadow_map : register(t0);
+    SamplerComparisonState shadow_sampler : register(s0);
+    Texture2D<float4> grid : register(t1);
+    SamplerState grid_sampler : register(s1);
+
+    static float4 f_light_position;
+    static float2 f_texcoord;
+    static float3 f_position;
+    static float4 color;
+
+    struct SPIRV_Cross_Input
+    {
+        float3 f_position : TEXCOORD0;
+        float4 f_light_position : TEXCOORD1;
+        float2 f_texcoord : TEXCOORD2;
+    };
+
+    struct SPIRV_Cross_Output
+    {
+        float4 color : SV_Target0;
+    };
+
+    void frag_main()
+    {
+        float3 _22 = f_light_position.xyz / f_light_position.w.xxx;
+        float3 _35 = float3((_22.x * 0.5f) + 0.5f, 0.5f - (_22.y * 0.5f), _22.z);
+        float _50 = shadow_map.SampleCmp(shadow_sampler, _35.xy, _35.z - _41_bias);
+        float _86 = clamp(length(f_position - _41_eye_position) / _41_fog_distance, 0.0f, 1.0f);
+        color = float4(lerp(grid.Sample(grid_sampler, f_texcoord).xyz * (_41_ambient.xxx + (_41_light_color * (max(-_41_light_direction.y, 0.0f) * _50))), _41_fog_color, (_86 * _86).xxx), 1.0f);
+    }
+
+    SPIRV_Cross_Output main(SPIRV_Cross_Input stage_input)
+    {
+        f_light_position = stage_input.f_light_position;
+        f_texcoord = stage_input.f_texcoord;
+        f_position = stage_input.f_position;
+        frag_main();
+        SPIRV_Cross_Output stage_output;
+        stage_output.color = color;
+        return stage_output;
+    }
+*/
+pub const GROUND_FRAG_SOURCE_HLSL5: [u8; 1683] = [
+    0x63,0x62,0x75,0x66,0x66,0x65,0x72,0x20,0x67,0x72,0x6f,0x75,0x6e,0x64,0x5f,0x66,
+    0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x20,0x3a,0x20,0x72,0x65,0x67,0x69,0x73,
+    0x74,0x65,0x72,0x28,0x62,0x31,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,
+    0x6f,0x61,0x74,0x33,0x20,0x5f,0x34,0x31,0x5f,0x65,0x79,0x65,0x5f,0x70,0x6f,0x73,
+    0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,
+    0x65,0x74,0x28,0x63,0x30,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,
+    0x74,0x20,0x5f,0x34,0x31,0x5f,0x61,0x6d,0x62,0x69,0x65,0x6e,0x74,0x20,0x3a,0x20,
+    0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,0x30,0x2e,0x77,0x29,
+    0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x5f,0x34,0x31,
+    0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,
+    0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,0x31,
+    0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x20,0x5f,0x34,0x31,
+    0x5f,0x62,0x69,0x61,0x73,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,
+    0x65,0x74,0x28,0x63,0x31,0x2e,0x77,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,
+    0x6f,0x61,0x74,0x33,0x20,0x5f,0x34,0x31,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x63,
+    0x6f,0x6c,0x6f,0x72,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,
+    0x74,0x28,0x63,0x32,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,
+    0x20,0x5f,0x34,0x31,0x5f,0x66,0x6f,0x67,0x5f,0x64,0x69,0x73,0x74,0x61,0x6e,0x63,
+    0x65,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,
+    0x32,0x2e,0x77,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,
+    0x20,0x5f,0x34,0x31,0x5f,0x66,0x6f,0x67,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3a,
+    0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,0x33,0x29,0x3b,
+    0x0a,0x7d,0x3b,0x0a,0x0a,0x54,0x65,0x78,0x74,0x75,0x72,0x65,0x32,0x44,0x3c,0x66,
+    0x6c,0x6f,0x61,0x74,0x34,0x3e,0x20,0x73,0x68,0x61,0x64,0x6f,0x77,0x5f,0x6d,0x61,
+    0x70,0x20,0x3a,0x20,0x72,0x65,0x67,0x69,0x73,0x74,0x65,0x72,0x28,0x74,0x30,0x29,
+    0x3b,0x0a,0x53,0x61,0x6d,0x70,0x6c,0x65,0x72,0x43,0x6f,0x6d,0x70,0x61,0x72,0x69,
+    0x73,0x6f,0x6e,0x53,0x74,0x61,0x74,0x65,0x20,0x73,0x68,0x61,0x64,0x6f,0x77,0x5f,
+    0x73,0x61,0x6d,0x70,0x6c,0x65,0x72,0x20,0x3a,0x20,0x72,0x65,0x67,0x69,0x73,0x74,
+    0x65,0x72,0x28,0x73,0x30,0x29,0x3b,0x0a,0x54,0x65,0x78,0x74,0x75,0x72,0x65,0x32,
+    0x44,0x3c,0x66,0x6c,0x6f,0x61,0x74,0x34,0x3e,0x20,0x67,0x72,0x69,0x64,0x20,0x3a,
+    0x20,0x72,0x65,0x67,0x69,0x73,0x74,0x65,0x72,0x28,0x74,0x31,0x29,0x3b,0x0a,0x53,
+    0x61,0x6d,0x70,0x6c,0x65,0x72,0x53,0x74,0x61,0x74,0x65,0x20,0x67,0x72,0x69,0x64,
+    0x5f,0x73,0x61,0x6d,0x70,0x6c,0x65,0x72,0x20,0x3a,0x20,0x72,0x65,0x67,0x69,0x73,
+    0x74,0x65,0x72,0x28,0x73,0x31,0x29,0x3b,0x0a,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,
+    0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,
+    0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,
+    0x20,0x66,0x6c,0x6f,0x61,0x74,0x32,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,
+    0x72,0x64,0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,
+    0x33,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x73,0x74,
+    0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x63,0x6f,0x6c,0x6f,
+    0x72,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,0x53,0x50,0x49,0x52,0x56,
+    0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x49,0x6e,0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,
+    0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x54,0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,0x30,
+    0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x66,0x5f,0x6c,
+    0x69,0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,
+    0x54,0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,0x31,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,
+    0x6c,0x6f,0x61,0x74,0x32,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,
+    0x20,0x3a,0x20,0x54,0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,0x32,0x3b,0x0a,0x7d,0x3b,
+    0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,
+    0x72,0x6f,0x73,0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,0x20,
+    0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3a,
+    0x20,0x53,0x56,0x5f,0x54,0x61,0x72,0x67,0x65,0x74,0x30,0x3b,0x0a,0x7d,0x3b,0x0a,
+    0x0a,0x76,0x6f,0x69,0x64,0x20,0x66,0x72,0x61,0x67,0x5f,0x6d,0x61,0x69,0x6e,0x28,
+    0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x5f,
+    0x32,0x32,0x20,0x3d,0x20,0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,
+    0x69,0x74,0x69,0x6f,0x6e,0x2e,0x78,0x79,0x7a,0x20,0x2f,0x20,0x66,0x5f,0x6c,0x69,
+    0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x2e,0x77,0x2e,0x78,
+    0x78,0x78,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x5f,
+    0x33,0x35,0x20,0x3d,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x28,0x28,0x5f,0x32,0x32,
+    0x2e,0x78,0x20,0x2a,0x20,0x30,0x2e,0x35,0x66,0x29,0x20,0x2b,0x20,0x30,0x2e,0x35,
+    0x66,0x2c,0x20,0x30,0x2e,0x35,0x66,0x20,0x2d,0x20,0x28,0x5f,0x32,0x32,0x2e,0x79,
+    0x20,0x2a,0x20,0x30,0x2e,0x35,0x66,0x29,0x2c,0x20,0x5f,0x32,0x32,0x2e,0x7a,0x29,
+    0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x20,0x5f,0x35,0x30,0x20,
+    0x3d,0x20,0x73,0x68,0x61,0x64,0x6f,0x77,0x5f,0x6d,0x61,0x70,0x2e,0x53,0x61,0x6d,
+    0x70,0x6c,0x65,0x43,0x6d,0x70,0x28,0x73,0x68,0x61,0x64,0x6f,0x77,0x5f,0x73,0x61,
+    0x6d,0x70,0x6c,0x65,0x72,0x2c,0x20,0x5f,0x33,0x35,0x2e,0x78,0x79,0x2c,0x20,0x5f,
+    0x33,0x35,0x2e,0x7a,0x20,0x2d,0x20,0x5f,0x34,0x31,0x5f,0x62,0x69,0x61,0x73,0x29,
+    0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x20,0x5f,0x38,0x36,0x20,
+    0x3d,0x20,0x63,0x6c,0x61,0x6d,0x70,0x28,0x6c,0x65,0x6e,0x67,0x74,0x68,0x28,0x66,
+    0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x2d,0x20,0x5f,0x34,0x31,0x5f,
+    0x65,0x79,0x65,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x29,0x20,0x2f,0x20,
+    0x5f,0x34,0x31,0x5f,0x66,0x6f,0x67,0x5f,0x64,0x69,0x73,0x74,0x61,0x6e,0x63,0x65,
+    0x2c,0x20,0x30,0x2e,0x30,0x66,0x2c,0x20,0x31,0x2e,0x30,0x66,0x29,0x3b,0x0a,0x20,
+    0x20,0x20,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3d,0x20,0x66,0x6c,0x6f,0x61,0x74,
+    0x34,0x28,0x6c,0x65,0x72,0x70,0x28,0x67,0x72,0x69,0x64,0x2e,0x53,0x61,0x6d,0x70,
+    0x6c,0x65,0x28,0x67,0x72,0x69,0x64,0x5f,0x73,0x61,0x6d,0x70,0x6c,0x65,0x72,0x2c,
+    0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x29,0x2e,0x78,0x79,0x7a,
+    0x20,0x2a,0x20,0x28,0x5f,0x34,0x31,0x5f,0x61,0x6d,0x62,0x69,0x65,0x6e,0x74,0x2e,
+    0x78,0x78,0x78,0x20,0x2b,0x20,0x28,0x5f,0x34,0x31,0x5f,0x6c,0x69,0x67,0x68,0x74,
+    0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x2a,0x20,0x28,0x6d,0x61,0x78,0x28,0x2d,0x5f,
+    0x34,0x31,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,
+    0x6f,0x6e,0x2e,0x79,0x2c,0x20,0x30,0x2e,0x30,0x66,0x29,0x20,0x2a,0x20,0x5f,0x35,
+    0x30,0x29,0x29,0x29,0x2c,0x20,0x5f,0x34,0x31,0x5f,0x66,0x6f,0x67,0x5f,0x63,0x6f,
+    0x6c,0x6f,0x72,0x2c,0x20,0x28,0x5f,0x38,0x36,0x20,0x2a,0x20,0x5f,0x38,0x36,0x29,
+    0x2e,0x78,0x78,0x78,0x29,0x2c,0x20,0x31,0x2e,0x30,0x66,0x29,0x3b,0x0a,0x7d,0x0a,
+    0x0a,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x4f,0x75,0x74,
+    0x70,0x75,0x74,0x20,0x6d,0x61,0x69,0x6e,0x28,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,
+    0x72,0x6f,0x73,0x73,0x5f,0x49,0x6e,0x70,0x75,0x74,0x20,0x73,0x74,0x61,0x67,0x65,
+    0x5f,0x69,0x6e,0x70,0x75,0x74,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x5f,
+    0x6c,0x69,0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,
+    0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x2e,0x66,0x5f,0x6c,
+    0x69,0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x20,
+    0x20,0x20,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x20,0x3d,0x20,
+    0x73,0x74,0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x2e,0x66,0x5f,0x74,0x65,
+    0x78,0x63,0x6f,0x6f,0x72,0x64,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x5f,0x70,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x69,
+    0x6e,0x70,0x75,0x74,0x2e,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,
+    0x0a,0x20,0x20,0x20,0x20,0x66,0x72,0x61,0x67,0x5f,0x6d,0x61,0x69,0x6e,0x28,0x29,
+    0x3b,0x0a,0x20,0x20,0x20,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,
+    0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x6f,
+    0x75,0x74,0x70,0x75,0x74,0x3b,0x0a,0x20,0x20,0x20,0x20,0x73,0x74,0x61,0x67,0x65,
+    0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x2e,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3d,0x20,
+    0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,0x20,0x20,0x20,0x20,0x72,0x65,0x74,0x75,0x72,
+    0x6e,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x3b,0x0a,
+    0x7d,0x0a,0x00,
+];
+/*
+    diagnostic(off, derivative_uniformity);
+
+    struct ground_vs_params {
+      /_ @offset(0) _/
+      view_projection : mat4x4f,
+      /_ @offset(64) _/
+      light_view_projection : mat4x4f,
+      /_ @offset(128) _/
+      origin : vec3f,
+      /_ @offset(140) _/
+      tile_size : f32,
+      /_ @offset(144) _/
+      scroll : vec2f,
+    }
+
+    @group(0) @binding(0) var<uniform> x_19 : ground_vs_params;
+
+    var<private> position_1 : vec3f;
+
+    var<private> f_position : vec3f;
+
+    var<private> f_light_position : vec4f;
+
+    var<private> f_texcoord : vec2f;
+
+    var<private> gl_Position : vec4f;
+
+    fn main_1() {
+      let x_20 : vec3f = position_1;
+      let x_24 : vec3f = x_19.origin;
+      let x_25 : vec3f = (x_20 + x_24);
+      let x_27 : vec4f = vec4f(x_25.x, x_25.y, x_25.z, 1.0f);
+      let x_30 : mat4x4f = x_19.view_projection;
+      gl_Position = (x_30 * x_27);
+      f_position = x_27.xyz;
+      let x_38 : mat4x4f = x_19.light_view_projection;
+      f_light_position = (x_38 * x_27);
+      let x_46 : vec2f = x_19.scroll;
+      let x_49 : f32 = x_19.tile_size;
+      f_texcoord = ((x_27.xz + x_46) / vec2f(x_49));
+      return;
+    }
+
+    struct main_out {
+      @builtin(position)
+      gl_Position : vec4f,
+      @location(0)
+      f_position_1 : vec3f,
+      @location(1)
+      f_light_position_1 : vec4f,
+      @location(2)
+      f_texcoord_1 : vec2f,
+    }
+
+    @vertex
+    fn main(@location(0) position_1_param : vec3f) -> main_out {
+      position_1 = position_1_param;
+      main_1();
+      return main_out(gl_Position, f_position, f_light_position, f_texcoord);
+    }
+
+*/
+pub const GROUND_VERTEX_SOURCE_WGSL: [u8; 1403] = [
+    0x64,0x69,0x61,0x67,0x6e,0x6f,0x73,0x74,0x69,0x63,0x28,0x6f,0x66,0x66,0x2c,0x20,
+    0x64,0x65,0x72,0x69,0x76,0x61,0x74,0x69,0x76,0x65,0x5f,0x75,0x6e,0x69,0x66,0x6f,
+    0x72,0x6d,0x69,0x74,0x79,0x29,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,
+    0x67,0x72,0x6f,0x75,0x6e,0x64,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,
+    0x20,0x7b,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,
+    0x30,0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,0x76,0x69,0x65,0x77,0x5f,0x70,0x72,0x6f,
+    0x6a,0x65,0x63,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x6d,0x61,0x74,0x34,0x78,0x34,
+    0x66,0x2c,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,
+    0x36,0x34,0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,0x6c,0x69,0x67,0x68,0x74,0x5f,0x76,
+    0x69,0x65,0x77,0x5f,0x70,0x72,0x6f,0x6a,0x65,0x63,0x74,0x69,0x6f,0x6e,0x20,0x3a,
+    0x20,0x6d,0x61,0x74,0x34,0x78,0x34,0x66,0x2c,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,
+    0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x31,0x32,0x38,0x29,0x20,0x2a,0x2f,0x0a,0x20,
+    0x20,0x6f,0x72,0x69,0x67,0x69,0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x2c,
+    0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x31,0x34,
+    0x30,0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,0x74,0x69,0x6c,0x65,0x5f,0x73,0x69,0x7a,
+    0x65,0x20,0x3a,0x20,0x66,0x33,0x32,0x2c,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,
+    0x66,0x66,0x73,0x65,0x74,0x28,0x31,0x34,0x34,0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,
+    0x73,0x63,0x72,0x6f,0x6c,0x6c,0x20,0x3a,0x20,0x76,0x65,0x63,0x32,0x66,0x2c,0x0a,
+    0x7d,0x0a,0x0a,0x40,0x67,0x72,0x6f,0x75,0x70,0x28,0x30,0x29,0x20,0x40,0x62,0x69,
+    0x6e,0x64,0x69,0x6e,0x67,0x28,0x30,0x29,0x20,0x76,0x61,0x72,0x3c,0x75,0x6e,0x69,
+    0x66,0x6f,0x72,0x6d,0x3e,0x20,0x78,0x5f,0x31,0x39,0x20,0x3a,0x20,0x67,0x72,0x6f,
+    0x75,0x6e,0x64,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x3b,0x0a,0x0a,
+    0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x70,0x6f,0x73,
+    0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x3b,
+    0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x66,
+    0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,
+    0x66,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,
+    0x20,0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,
+    0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,
+    0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,
+    0x6f,0x72,0x64,0x20,0x3a,0x20,0x76,0x65,0x63,0x32,0x66,0x3b,0x0a,0x0a,0x76,0x61,
+    0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x67,0x6c,0x5f,0x50,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x3b,0x0a,
+    0x0a,0x66,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x31,0x28,0x29,0x20,0x7b,0x0a,0x20,
+    0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x32,0x30,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,
+    0x66,0x20,0x3d,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x3b,0x0a,
+    0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x32,0x34,0x20,0x3a,0x20,0x76,0x65,0x63,
+    0x33,0x66,0x20,0x3d,0x20,0x78,0x5f,0x31,0x39,0x2e,0x6f,0x72,0x69,0x67,0x69,0x6e,
+    0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x32,0x35,0x20,0x3a,0x20,0x76,
+    0x65,0x63,0x33,0x66,0x20,0x3d,0x20,0x28,0x78,0x5f,0x32,0x30,0x20,0x2b,0x20,0x78,
+    0x5f,0x32,0x34,0x29,0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x32,0x37,
+    0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x20,0x3d,0x20,0x76,0x65,0x63,0x34,0x66,
+    0x28,0x78,0x5f,0x32,0x35,0x2e,0x78,0x2c,0x20,0x78,0x5f,0x32,0x35,0x2e,0x79,0x2c,
+    0x20,0x78,0x5f,0x32,0x35,0x2e,0x7a,0x2c,0x20,0x31,0x2e,0x30,0x66,0x29,0x3b,0x0a,
+    0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x33,0x30,0x20,0x3a,0x20,0x6d,0x61,0x74,
+    0x34,0x78,0x34,0x66,0x20,0x3d,0x20,0x78,0x5f,0x31,0x39,0x2e,0x76,0x69,0x65,0x77,
+    0x5f,0x70,0x72,0x6f,0x6a,0x65,0x63,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x67,
+    0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x28,0x78,0x5f,
+    0x33,0x30,0x20,0x2a,0x20,0x78,0x5f,0x32,0x37,0x29,0x3b,0x0a,0x20,0x20,0x66,0x5f,
+    0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x78,0x5f,0x32,0x37,0x2e,
+    0x78,0x79,0x7a,0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x33,0x38,0x20,
+    0x3a,0x20,0x6d,0x61,0x74,0x34,0x78,0x34,0x66,0x20,0x3d,0x20,0x78,0x5f,0x31,0x39,
+    0x2e,0x6c,0x69,0x67,0x68,0x74,0x5f,0x76,0x69,0x65,0x77,0x5f,0x70,0x72,0x6f,0x6a,
+    0x65,0x63,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x66,0x5f,0x6c,0x69,0x67,0x68,
+    0x74,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x28,0x78,0x5f,
+    0x33,0x38,0x20,0x2a,0x20,0x78,0x5f,0x32,0x37,0x29,0x3b,0x0a,0x20,0x20,0x6c,0x65,
+    0x74,0x20,0x78,0x5f,0x34,0x36,0x20,0x3a,0x20,0x76,0x65,0x63,0x32,0x66,0x20,0x3d,
+    0x20,0x78,0x5f,0x31,0x39,0x2e,0x73,0x63,0x72,0x6f,0x6c,0x6c,0x3b,0x0a,0x20,0x20,
+    0x6c,0x65,0x74,0x20,0x78,0x5f,0x34,0x39,0x20,0x3a,0x20,0x66,0x33,0x32,0x20,0x3d,
+    0x20,0x78,0x5f,0x31,0x39,0x2e,0x74,0x69,0x6c,0x65,0x5f,0x73,0x69,0x7a,0x65,0x3b,
+    0x0a,0x20,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x20,0x3d,0x20,
+    0x28,0x28,0x78,0x5f,0x32,0x37,0x2e,0x78,0x7a,0x20,0x2b,0x20,0x78,0x5f,0x34,0x36,
+    0x29,0x20,0x2f,0x20,0x76,0x65,0x63,0x32,0x66,0x28,0x78,0x5f,0x34,0x39,0x29,0x29,
+    0x3b,0x0a,0x20,0x20,0x72,0x65,0x74,0x75,0x72,0x6e,0x3b,0x0a,0x7d,0x0a,0x0a,0x73,
+    0x74,0x72,0x75,0x63,0x74,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,0x74,0x20,0x7b,
+    0x0a,0x20,0x20,0x40,0x62,0x75,0x69,0x6c,0x74,0x69,0x6e,0x28,0x70,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x29,0x0a,0x20,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,
+    0x69,0x6f,0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x2c,0x0a,0x20,0x20,0x40,
+    0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x30,0x29,0x0a,0x20,0x20,0x66,0x5f,
+    0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x20,0x3a,0x20,0x76,0x65,0x63,
+    0x33,0x66,0x2c,0x0a,0x20,0x20,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,
+    0x31,0x29,0x0a,0x20,0x20,0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,
+    0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x2c,
+    0x0a,0x20,0x20,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x32,0x29,0x0a,
+    0x20,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x5f,0x31,0x20,0x3a,
+    0x20,0x76,0x65,0x63,0x32,0x66,0x2c,0x0a,0x7d,0x0a,0x0a,0x40,0x76,0x65,0x72,0x74,
+    0x65,0x78,0x0a,0x66,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x28,0x40,0x6c,0x6f,0x63,0x61,
+    0x74,0x69,0x6f,0x6e,0x28,0x30,0x29,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,
+    0x5f,0x31,0x5f,0x70,0x61,0x72,0x61,0x6d,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,
+    0x29,0x20,0x2d,0x3e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,0x74,0x20,0x7b,0x0a,
+    0x20,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x20,0x3d,0x20,0x70,
+    0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x5f,0x70,0x61,0x72,0x61,0x6d,0x3b,
+    0x0a,0x20,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x31,0x28,0x29,0x3b,0x0a,0x20,0x20,0x72,
+    0x65,0x74,0x75,0x72,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,0x74,0x28,0x67,
+    0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x2c,0x20,0x66,0x5f,0x70,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x2c,0x20,0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,
+    0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x2c,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,
+    0x6f,0x6f,0x72,0x64,0x29,0x3b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+/*
+    diagnostic(off, derivative_uniformity);
+
+    struct ground_fs_params {
+      /_ @offset(0) _/
+      eye_position : vec3f,
+      /_ @offset(12) _/
+      ambient : f32,
+      /_ @offset(16) _/
+      light_direction : vec3f,
+      /_ @offset(28) _/
+      bias : f32,
+      /_ @offset(32) _/
+      light_color : vec3f,
+      /_ @offset(44) _/
+      fog_distance : f32,
+      /_ @offset(48) _/
+      fog_color : vec3f,
+    }
+
+    @group(1) @binding(64) var shadow_map : texture_depth_2d;
+
+    @group(1) @binding(80) var shadow_sampler : sampler_comparison;
+
+    @group(1) @binding(65) var grid : texture_2d<f32>;
+
+    @group(1) @binding(81) var grid_sampler : sampler;
+
+    @group(0) @binding(1) var<uniform> x_41 : ground_fs_params;
+
+    var<private> f_light_position : vec4f;
+
+    var<private> f_texcoord : vec2f;
+
+    var<private> f_position : vec3f;
+
+    var<private> color : vec4f;
+
+    fn main_1() {
+      let x_22 : vec3f = (f_light_position.xyz / vec3f(f_light_position.w));
+      let x_35 : vec3f = vec3f(((x_22.x * 0.5f) + 0.5f), (0.5f - (x_22.y * 0.5f)), x_22.z);
+      let x_50 : f32 = textureSampleCompare(shadow_map, shadow_sampler, x_35.xy, (x_35.z - x_41.bias));
+      let x_65 : vec4f = textureSample(grid, grid_sampler, f_texcoord);
+      let x_86 : f32 = clamp((length((f_position - x_41.eye_position)) / x_41.fog_distance), 0.0f, 1.0f);
+      color = vec4f(mix((x_65.xyz * (vec3f(x_41.ambient) + (x_41.light_color * (max(-(x_41.light_direction.y), 0.0f) * x_50)))), x_41.fog_color, vec3f((x_86 * x_86))), 1.0f);
+      return;
+    }
+
+    struct main_out {
+      @location(0)
+      color_1 : vec4f,
+    }
+
+    @fragment
+    fn main(@location(0) f_position_param : vec3f, @location(1) f_light_position_param : vec4f, @location(2) f_texcoord_param : vec2f) -> main_out {
+      f_position = f_position_param;
+      f_light_position = f_light_position_param;
+      f_texcoord = f_texcoord_param;
+      main_1();
+      return main_out(color);
+    }
+
+*/
+pub const GROUND_FRAG_SOURCE_WGSL: [u8; 1775] = [
+    0x64,0x69,0x61,0x67,0x6e,0x6f,0x73,0x74,0x69,0x63,0x28,0x6f,0x66,0x66,0x2c,0x20,
+    0x64,0x65,0x72,0x69,0x76,0x61,0x74,0x69,0x76,0x65,0x5f,0x75,0x6e,0x69,0x66,0x6f,
+    0x72,0x6d,0x69,0x74,0x79,0x29,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,
+    0x67,0x72,0x6f,0x75,0x6e,0x64,0x5f,0x66,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,
+    0x20,0x7b,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,
+    0x30,0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,0x65,0x79,0x65,0x5f,0x70,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x2c,0x0a,0x20,0x20,
+    0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x31,0x32,0x29,0x20,0x2a,
+    0x2f,0x0a,0x20,0x20,0x61,0x6d,0x62,0x69,0x65,0x6e,0x74,0x20,0x3a,0x20,0x66,0x33,
+    0x32,0x2c,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,
+    0x31,0x36,0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,0x6c,0x69,0x67,0x68,0x74,0x5f,0x64,
+    0x69,0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,
+    0x2c,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x32,
+    0x38,0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,0x62,0x69,0x61,0x73,0x20,0x3a,0x20,0x66,
+    0x33,0x32,0x2c,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,
+    0x28,0x33,0x32,0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,0x6c,0x69,0x67,0x68,0x74,0x5f,
+    0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x2c,0x0a,0x20,
+    0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x34,0x34,0x29,0x20,
+    0x2a,0x2f,0x0a,0x20,0x20,0x66,0x6f,0x67,0x5f,0x64,0x69,0x73,0x74,0x61,0x6e,0x63,
+    0x65,0x20,0x3a,0x20,0x66,0x33,0x32,0x2c,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,
+    0x66,0x66,0x73,0x65,0x74,0x28,0x34,0x38,0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,0x66,
+    0x6f,0x67,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,
+    0x2c,0x0a,0x7d,0x0a,0x0a,0x40,0x67,0x72,0x6f,0x75,0x70,0x28,0x31,0x29,0x20,0x40,
+    0x62,0x69,0x6e,0x64,0x69,0x6e,0x67,0x28,0x36,0x34,0x29,0x20,0x76,0x61,0x72,0x20,
+    0x73,0x68,0x61,0x64,0x6f,0x77,0x5f,0x6d,0x61,0x70,0x20,0x3a,0x20,0x74,0x65,0x78,
+    0x74,0x75,0x72,0x65,0x5f,0x64,0x65,0x70,0x74,0x68,0x5f,0x32,0x64,0x3b,0x0a,0x0a,
+    0x40,0x67,0x72,0x6f,0x75,0x70,0x28,0x31,0x29,0x20,0x40,0x62,0x69,0x6e,0x64,0x69,
+    0x6e,0x67,0x28,0x38,0x30,0x29,0x20,0x76,0x61,0x72,0x20,0x73,0x68,0x61,0x64,0x6f,
+    0x77,0x5f,0x73,0x61,0x6d,0x70,0x6c,0x65,0x72,0x20,0x3a,0x20,0x73,0x61,0x6d,0x70,
+    0x6c,0x65,0x72,0x5f,0x63,0x6f,0x6d,0x70,0x61,0x72,0x69,0x73,0x6f,0x6e,0x3b,0x0a,
+    0x0a,0x40,0x67,0x72,0x6f,0x75,0x70,0x28,0x31,0x29,0x20,0x40,0x62,0x69,0x6e,0x64,
+    0x69,0x6e,0x67,0x28,0x36,0x35,0x29,0x20,0x76,0x61,0x72,0x20,0x67,0x72,0x69,0x64,
+    0x20,0x3a,0x20,0x74,0x65,0x78,0x74,0x75,0x72,0x65,0x5f,0x32,0x64,0x3c,0x66,0x33,
+    0x32,0x3e,0x3b,0x0a,0x0a,0x40,0x67,0x72,0x6f,0x75,0x70,0x28,0x31,0x29,0x20,0x40,
+    0x62,0x69,0x6e,0x64,0x69,0x6e,0x67,0x28,0x38,0x31,0x29,0x20,0x76,0x61,0x72,0x20,
+    0x67,0x72,0x69,0x64,0x5f,0x73,0x61,0x6d,0x70,0x6c,0x65,0x72,0x20,0x3a,0x20,0x73,
+    0x61,0x6d,0x70,0x6c,0x65,0x72,0x3b,0x0a,0x0a,0x40,0x67,0x72,0x6f,0x75,0x70,0x28,
+    0x30,0x29,0x20,0x40,0x62,0x69,0x6e,0x64,0x69,0x6e,0x67,0x28,0x31,0x29,0x20,0x76,
+    0x61,0x72,0x3c,0x75,0x6e,0x69,0x66,0x6f,0x72,0x6d,0x3e,0x20,0x78,0x5f,0x34,0x31,
+    0x20,0x3a,0x20,0x67,0x72,0x6f,0x75,0x6e,0x64,0x5f,0x66,0x73,0x5f,0x70,0x61,0x72,
+    0x61,0x6d,0x73,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,
+    0x65,0x3e,0x20,0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,0x69,0x74,
+    0x69,0x6f,0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x3b,0x0a,0x0a,0x76,0x61,
+    0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x66,0x5f,0x74,0x65,0x78,
+    0x63,0x6f,0x6f,0x72,0x64,0x20,0x3a,0x20,0x76,0x65,0x63,0x32,0x66,0x3b,0x0a,0x0a,
+    0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x66,0x5f,0x70,
+    0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x3b,
+    0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x63,
+    0x6f,0x6c,0x6f,0x72,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x3b,0x0a,0x0a,0x66,
+    0x6e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x31,0x28,0x29,0x20,0x7b,0x0a,0x20,0x20,0x6c,
+    0x65,0x74,0x20,0x78,0x5f,0x32,0x32,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x20,
+    0x3d,0x20,0x28,0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,0x69,0x74,
+    0x69,0x6f,0x6e,0x2e,0x78,0x79,0x7a,0x20,0x2f,0x20,0x76,0x65,0x63,0x33,0x66,0x28,
+    0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,
+    0x2e,0x77,0x29,0x29,0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x33,0x35,
+    0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x20,0x3d,0x20,0x76,0x65,0x63,0x33,0x66,
+    0x28,0x28,0x28,0x78,0x5f,0x32,0x32,0x2e,0x78,0x20,0x2a,0x20,0x30,0x2e,0x35,0x66,
+    0x29,0x20,0x2b,0x20,0x30,0x2e,0x35,0x66,0x29,0x2c,0x20,0x28,0x30,0x2e,0x35,0x66,
+    0x20,0x2d,0x20,0x28,0x78,0x5f,0x32,0x32,0x2e,0x79,0x20,0x2a,0x20,0x30,0x2e,0x35,
+    0x66,0x29,0x29,0x2c,0x20,0x78,0x5f,0x32,0x32,0x2e,0x7a,0x29,0x3b,0x0a,0x20,0x20,
+    0x6c,0x65,0x74,0x20,0x78,0x5f,0x35,0x30,0x20,0x3a,0x20,0x66,0x33,0x32,0x20,0x3d,
+    0x20,0x74,0x65,0x78,0x74,0x75,0x72,0x65,0x53,0x61,0x6d,0x70,0x6c,0x65,0x43,0x6f,
+    0x6d,0x70,0x61,0x72,0x65,0x28,0x73,0x68,0x61,0x64,0x6f,0x77,0x5f,0x6d,0x61,0x70,
+    0x2c,0x20,0x73,0x68,0x61,0x64,0x6f,0x77,0x5f,0x73,0x61,0x6d,0x70,0x6c,0x65,0x72,
+    0x2c,0x20,0x78,0x5f,0x33,0x35,0x2e,0x78,0x79,0x2c,0x20,0x28,0x78,0x5f,0x33,0x35,
+    0x2e,0x7a,0x20,0x2d,0x20,0x78,0x5f,0x34,0x31,0x2e,0x62,0x69,0x61,0x73,0x29,0x29,
+    0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x36,0x35,0x20,0x3a,0x20,0x76,
+    0x65,0x63,0x34,0x66,0x20,0x3d,0x20,0x74,0x65,0x78,0x74,0x75,0x72,0x65,0x53,0x61,
+    0x6d,0x70,0x6c,0x65,0x28,0x67,0x72,0x69,0x64,0x2c,0x20,0x67,0x72,0x69,0x64,0x5f,
+    0x73,0x61,0x6d,0x70,0x6c,0x65,0x72,0x2c,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,
+    0x6f,0x72,0x64,0x29,0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x38,0x36,
+    0x20,0x3a,0x20,0x66,0x33,0x32,0x20,0x3d,0x20,0x63,0x6c,0x61,0x6d,0x70,0x28,0x28,
+    0x6c,0x65,0x6e,0x67,0x74,0x68,0x28,0x28,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,
+    0x6f,0x6e,0x20,0x2d,0x20,0x78,0x5f,0x34,0x31,0x2e,0x65,0x79,0x65,0x5f,0x70,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x29,0x29,0x20,0x2f,0x20,0x78,0x5f,0x34,0x31,0x2e,
+    0x66,0x6f,0x67,0x5f,0x64,0x69,0x73,0x74,0x61,0x6e,0x63,0x65,0x29,0x2c,0x20,0x30,
+    0x2e,0x30,0x66,0x2c,0x20,0x31,0x2e,0x30,0x66,0x29,0x3b,0x0a,0x20,0x20,0x63,0x6f,
+    0x6c,0x6f,0x72,0x20,0x3d,0x20,0x76,0x65,0x63,0x34,0x66,0x28,0x6d,0x69,0x78,0x28,
+    0x28,0x78,0x5f,0x36,0x35,0x2e,0x78,0x79,0x7a,0x20,0x2a,0x20,0x28,0x76,0x65,0x63,
+    0x33,0x66,0x28,0x78,0x5f,0x34,0x31,0x2e,0x61,0x6d,0x62,0x69,0x65,0x6e,0x74,0x29,
+    0x20,0x2b,0x20,0x28,0x78,0x5f,0x34,0x31,0x2e,0x6c,0x69,0x67,0x68,0x74,0x5f,0x63,
+    0x6f,0x6c,0x6f,0x72,0x20,0x2a,0x20,0x28,0x6d,0x61,0x78,0x28,0x2d,0x28,0x78,0x5f,
+    0x34,0x31,0x2e,0x6c,0x69,0x67,0x68,0x74,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,
+    0x6f,0x6e,0x2e,0x79,0x29,0x2c,0x20,0x30,0x2e,0x30,0x66,0x29,0x20,0x2a,0x20,0x78,
+    0x5f,0x35,0x30,0x29,0x29,0x29,0x29,0x2c,0x20,0x78,0x5f,0x34,0x31,0x2e,0x66,0x6f,
+    0x67,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x2c,0x20,0x76,0x65,0x63,0x33,0x66,0x28,0x28,
+    0x78,0x5f,0x38,0x36,0x20,0x2a,0x20,0x78,0x5f,0x38,0x36,0x29,0x29,0x29,0x2c,0x20,
+    0x31,0x2e,0x30,0x66,0x29,0x3b,0x0a,0x20,0x20,0x72,0x65,0x74,0x75,0x72,0x6e,0x3b,
+    0x0a,0x7d,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,0x6d,0x61,0x69,0x6e,0x5f,
+    0x6f,0x75,0x74,0x20,0x7b,0x0a,0x20,0x20,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,
+    0x6e,0x28,0x30,0x29,0x0a,0x20,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x5f,0x31,0x20,0x3a,
+    0x20,0x76,0x65,0x63,0x34,0x66,0x2c,0x0a,0x7d,0x0a,0x0a,0x40,0x66,0x72,0x61,0x67,
+    0x6d,0x65,0x6e,0x74,0x0a,0x66,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x28,0x40,0x6c,0x6f,
+    0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x30,0x29,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x5f,0x70,0x61,0x72,0x61,0x6d,0x20,0x3a,0x20,0x76,0x65,0x63,
+    0x33,0x66,0x2c,0x20,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x31,0x29,
+    0x20,0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,
+    0x6e,0x5f,0x70,0x61,0x72,0x61,0x6d,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x2c,
+    0x20,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x32,0x29,0x20,0x66,0x5f,
+    0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x5f,0x70,0x61,0x72,0x61,0x6d,0x20,0x3a,
+    0x20,0x76,0x65,0x63,0x32,0x66,0x29,0x20,0x2d,0x3e,0x20,0x6d,0x61,0x69,0x6e,0x5f,
+    0x6f,0x75,0x74,0x20,0x7b,0x0a,0x20,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,
+    0x6f,0x6e,0x20,0x3d,0x20,0x66,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,
+    0x70,0x61,0x72,0x61,0x6d,0x3b,0x0a,0x20,0x20,0x66,0x5f,0x6c,0x69,0x67,0x68,0x74,
+    0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x66,0x5f,0x6c,0x69,
+    0x67,0x68,0x74,0x5f,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x70,0x61,0x72,
+    0x61,0x6d,0x3b,0x0a,0x20,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,
+    0x20,0x3d,0x20,0x66,0x5f,0x74,0x65,0x78,0x63,0x6f,0x6f,0x72,0x64,0x5f,0x70,0x61,
+    0x72,0x61,0x6d,0x3b,0x0a,0x20,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x31,0x28,0x29,0x3b,
+    0x0a,0x20,0x20,0x72,0x65,0x74,0x75,0x72,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,
+    0x75,0x74,0x28,0x63,0x6f,0x6c,0x6f,0x72,0x29,0x3b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+/*
+    #version 430
+
+    uniform vec4 sky_vs_params[4];
+    layout(location = 0) in vec2 position;
+    layout(location = 0) out vec3 f_direction;
+
+    void main()
+    {
+        gl_Position = vec4(position, 0.0, 1.0);
+        vec4 _37 = mat4(sky_vs_params[0], sky_vs_params[1], sky_vs_params[2], sky_vs_params[3]) * vec4(position, 1.0, 1.0);
+        f_direction = _37.xyz / vec3(_37.w);
+    }
+
+*/
+pub const SKY_VERTEX_SOURCE_GLSL430: [u8; 351] = [
+    0x23,0x76,0x65,0x72,0x73,0x69,0x6f,0x6e,0x20,0x34,0x33,0x30,0x0a,0x0a,0x75,0x6e,
+    0x69,0x66,0x6f,0x72,0x6d,0x20,0x76,0x65,0x63,0x34,0x20,0x73,0x6b,0x79,0x5f,0x76,
+    0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x34,0x5d,0x3b,0x0a,0x6c,0x61,0x79,
+    0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x30,
+    0x29,0x20,0x69,0x6e,0x20,0x76,0x65,0x63,0x32,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,
+    0x6f,0x6e,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,
+    0x69,0x6f,0x6e,0x20,0x3d,0x20,0x30,0x29,0x20,0x6f,0x75,0x74,0x20,0x76,0x65,0x63,
+    0x33,0x20,0x66,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x0a,
+    0x76,0x6f,0x69,0x64,0x20,0x6d,0x61,0x69,0x6e,0x28,0x29,0x0a,0x7b,0x0a,0x20,0x20,
+    0x20,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,
+    0x76,0x65,0x63,0x34,0x28,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x2c,0x20,0x30,
+    0x2e,0x30,0x2c,0x20,0x31,0x2e,0x30,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x76,0x65,
+    0x63,0x34,0x20,0x5f,0x33,0x37,0x20,0x3d,0x20,0x6d,0x61,0x74,0x34,0x28,0x73,0x6b,
+    0x79,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x30,0x5d,0x2c,0x20,
+    0x73,0x6b,0x79,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x31,0x5d,
+    0x2c,0x20,0x73,0x6b,0x79,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,
+    0x32,0x5d,0x2c,0x20,0x73,0x6b,0x79,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,
+    0x73,0x5b,0x33,0x5d,0x29,0x20,0x2a,0x20,0x76,0x65,0x63,0x34,0x28,0x70,0x6f,0x73,
+    0x69,0x74,0x69,0x6f,0x6e,0x2c,0x20,0x31,0x2e,0x30,0x2c,0x20,0x31,0x2e,0x30,0x29,
+    0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,
+    0x6e,0x20,0x3d,0x20,0x5f,0x33,0x37,0x2e,0x78,0x79,0x7a,0x20,0x2f,0x20,0x76,0x65,
+    0x63,0x33,0x28,0x5f,0x33,0x37,0x2e,0x77,0x29,0x3b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+/*
+    #version 430
+
+    uniform vec4 sky_fs_params[3];
+    layout(location = 0) in vec3 f_direction;
+    layout(location = 0) out vec4 color;
+
+    void main()
+    {
+        vec3 _12 = normalize(f_direction);
+        color = vec4(mix(sky_fs_params[1].xyz, sky_fs_params[0].xyz, vec3(sqrt(max(_12.y, 0.0)))) + vec3(pow(max(dot(_12, sky_fs_params[2].xyz), 0.0), sky_fs_params[0].w) * sky_fs_params[1].w), 1.0);
+    }
+
+*/
+pub const SKY_FRAG_SOURCE_GLSL430: [u8; 378] = [
+    0x23,0x76,0x65,0x72,0x73,0x69,0x6f,0x6e,0x20,0x34,0x33,0x30,0x0a,0x0a,0x75,0x6e,
+    0x69,0x66,0x6f,0x72,0x6d,0x20,0x76,0x65,0x63,0x34,0x20,0x73,0x6b,0x79,0x5f,0x66,
+    0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x33,0x5d,0x3b,0x0a,0x6c,0x61,0x79,
+    0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x30,
+    0x29,0x20,0x69,0x6e,0x20,0x76,0x65,0x63,0x33,0x20,0x66,0x5f,0x64,0x69,0x72,0x65,
+    0x63,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,
+    0x63,0x61,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x30,0x29,0x20,0x6f,0x75,0x74,0x20,
+    0x76,0x65,0x63,0x34,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,0x0a,0x76,0x6f,0x69,
+    0x64,0x20,0x6d,0x61,0x69,0x6e,0x28,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x76,
+    0x65,0x63,0x33,0x20,0x5f,0x31,0x32,0x20,0x3d,0x20,0x6e,0x6f,0x72,0x6d,0x61,0x6c,
+    0x69,0x7a,0x65,0x28,0x66,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,0x29,
+    0x3b,0x0a,0x20,0x20,0x20,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3d,0x20,0x76,0x65,
+    0x63,0x34,0x28,0x6d,0x69,0x78,0x28,0x73,0x6b,0x79,0x5f,0x66,0x73,0x5f,0x70,0x61,
+    0x72,0x61,0x6d,0x73,0x5b,0x31,0x5d,0x2e,0x78,0x79,0x7a,0x2c,0x20,0x73,0x6b,0x79,
+    0x5f,0x66,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x30,0x5d,0x2e,0x78,0x79,
+    0x7a,0x2c,0x20,0x76,0x65,0x63,0x33,0x28,0x73,0x71,0x72,0x74,0x28,0x6d,0x61,0x78,
+    0x28,0x5f,0x31,0x32,0x2e,0x79,0x2c,0x20,0x30,0x2e,0x30,0x29,0x29,0x29,0x29,0x20,
+    0x2b,0x20,0x76,0x65,0x63,0x33,0x28,0x70,0x6f,0x77,0x28,0x6d,0x61,0x78,0x28,0x64,
+    0x6f,0x74,0x28,0x5f,0x31,0x32,0x2c,0x20,0x73,0x6b,0x79,0x5f,0x66,0x73,0x5f,0x70,
+    0x61,0x72,0x61,0x6d,0x73,0x5b,0x32,0x5d,0x2e,0x78,0x79,0x7a,0x29,0x2c,0x20,0x30,
+    0x2e,0x30,0x29,0x2c,0x20,0x73,0x6b,0x79,0x5f,0x66,0x73,0x5f,0x70,0x61,0x72,0x61,
+    0x6d,0x73,0x5b,0x30,0x5d,0x2e,0x77,0x29,0x20,0x2a,0x20,0x73,0x6b,0x79,0x5f,0x66,
+    0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x31,0x5d,0x2e,0x77,0x29,0x2c,0x20,
+    0x31,0x2e,0x30,0x29,0x3b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+/*
+    cbuffer sky_vs_params : register(b0)
+    {
+        row_major float4x4 _31_inverse_view_projection : packoffset(c0);
+    };
+
+
+    static float4 gl_Position;
+    static float2 position;
+    static float3 f_direction;
+
+    struct SPIRV_Cross_Input
+    {
+        float2 position : TEXCOORD0;
+    };
+
+    struct SPIRV_Cross_Output
+    {
+        float3 f_direction : TEXCOORD0;
+        float4 gl_Position : SV_Position;
+    };
+
+    void vert_main()
+    {
+        gl_Position = float4(position, 0.0f, 1.0f);
+        float4 _37 = mul(float4(position, 1.0f, 1.0f), _31_inverse_view_projection);
+        f_direction = _37.xyz / _37.w.xxx;
+    }
+
+    SPIRV_Cross_Output main(SPIRV_Cross_Input stage_input)
+    {
+        position = stage_input.position;
+        vert_main();
+        SPIRV_Cross_Output stage_output;
+        stage_output.gl_Position = gl_Position;
+        stage_output.f_direction = f_direction;
+        return stage_output;
+    }
+*/
+pub const SKY_VERTEX_SOURCE_HLSL5: [u8; 816] = [
+    0x63,0x62,0x75,0x66,0x66,0x65,0x72,0x20,0x73,0x6b,0x79,0x5f,0x76,0x73,0x5f,0x70,
+    0x61,0x72,0x61,0x6d,0x73,0x20,0x3a,0x20,0x72,0x65,0x67,0x69,0x73,0x74,0x65,0x72,
+    0x28,0x62,0x30,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x72,0x6f,0x77,0x5f,0x6d,
+    0x61,0x6a,0x6f,0x72,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x78,0x34,0x20,0x5f,0x33,
+    0x31,0x5f,0x69,0x6e,0x76,0x65,0x72,0x73,0x65,0x5f,0x76,0x69,0x65,0x77,0x5f,0x70,
+    0x72,0x6f,0x6a,0x65,0x63,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,
+    0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,0x30,0x29,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,
+    0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x67,
+    0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x73,0x74,0x61,0x74,
+    0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,0x32,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,
+    0x6f,0x6e,0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,
+    0x33,0x20,0x66,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x0a,
+    0x73,0x74,0x72,0x75,0x63,0x74,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,
+    0x73,0x73,0x5f,0x49,0x6e,0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,
+    0x6c,0x6f,0x61,0x74,0x32,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,
+    0x20,0x54,0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,0x30,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,
+    0x73,0x74,0x72,0x75,0x63,0x74,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,
+    0x73,0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,
+    0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x66,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,
+    0x6f,0x6e,0x20,0x3a,0x20,0x54,0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,0x30,0x3b,0x0a,
+    0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x67,0x6c,0x5f,0x50,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x53,0x56,0x5f,0x50,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,0x76,0x6f,0x69,0x64,0x20,0x76,
+    0x65,0x72,0x74,0x5f,0x6d,0x61,0x69,0x6e,0x28,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,
+    0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x66,
+    0x6c,0x6f,0x61,0x74,0x34,0x28,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x2c,0x20,
+    0x30,0x2e,0x30,0x66,0x2c,0x20,0x31,0x2e,0x30,0x66,0x29,0x3b,0x0a,0x20,0x20,0x20,
+    0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x5f,0x33,0x37,0x20,0x3d,0x20,0x6d,0x75,
+    0x6c,0x28,0x66,0x6c,0x6f,0x61,0x74,0x34,0x28,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,
+    0x6e,0x2c,0x20,0x31,0x2e,0x30,0x66,0x2c,0x20,0x31,0x2e,0x30,0x66,0x29,0x2c,0x20,
+    0x5f,0x33,0x31,0x5f,0x69,0x6e,0x76,0x65,0x72,0x73,0x65,0x5f,0x76,0x69,0x65,0x77,
+    0x5f,0x70,0x72,0x6f,0x6a,0x65,0x63,0x74,0x69,0x6f,0x6e,0x29,0x3b,0x0a,0x20,0x20,
+    0x20,0x20,0x66,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,
+    0x5f,0x33,0x37,0x2e,0x78,0x79,0x7a,0x20,0x2f,0x20,0x5f,0x33,0x37,0x2e,0x77,0x2e,
+    0x78,0x78,0x78,0x3b,0x0a,0x7d,0x0a,0x0a,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,
+    0x6f,0x73,0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x20,0x6d,0x61,0x69,0x6e,0x28,
+    0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x49,0x6e,0x70,0x75,
+    0x74,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x29,0x0a,0x7b,
+    0x0a,0x20,0x20,0x20,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,
+    0x73,0x74,0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x2e,0x70,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x20,0x20,0x76,0x65,0x72,0x74,0x5f,0x6d,
+    0x61,0x69,0x6e,0x28,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x53,0x50,0x49,0x52,0x56,
+    0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x20,0x73,0x74,
+    0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x3b,0x0a,0x20,0x20,0x20,0x20,
+    0x73,0x74,0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x2e,0x67,0x6c,0x5f,
+    0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x67,0x6c,0x5f,0x50,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x20,0x20,0x73,0x74,0x61,0x67,
+    0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x2e,0x66,0x5f,0x64,0x69,0x72,0x65,0x63,
+    0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x66,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,
+    0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x20,0x20,0x72,0x65,0x74,0x75,0x72,0x6e,0x20,0x73,
+    0x74,0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x3b,0x0a,0x7d,0x0a,0x00,
+];
+/*
+    cbuffer sky_fs_params : register(b1)
+    {
+        float3 _27_zenith_color : packoffset(c0);
+        float _27_sun_sharpness : packoffset(c0.w);
+        float3 _27_horizon_color : packoffset(c1);
+        float _27_sun_intensity : packoffset(c1.w);
+        float3 _27_sun_direction : packoffset(c2);
+    };
+
+
+    static float3 f_direction;
+    static float4 color;
+
+    struct SPIRV_Cross_Input
+    {
+        float3 f_direction : TEXCOORD0;
+    };
+
+    struct SPIRV_Cross_Output
+    {
+        float4 color : SV_Target0;
+    };
+
+    void frag_main()
+    {
+        float3 _12 = normalize(f_direction);
+        color = float4(lerp(_27_horizon_color, _27_zenith_color, sqrt(max(_12.y, 0.0f)).xxx) + (pow(max(dot(_12, _27_sun_direction), 0.0f), _27_sun_sharpness) * _27_sun_intensity).xxx, 1.0f);
+    }
+
+    SPIRV_Cross_Output main(SPIRV_Cross_Input stage_input)
+    {
+        f_direction = stage_input.f_direction;
+        frag_main();
+        SPIRV_Cross_Output stage_output;
+        stage_output.color = color;
+        return stage_output;
+    }
+*/
+pub const SKY_FRAG_SOURCE_HLSL5: [u8; 924] = [
+    0x63,0x62,0x75,0x66,0x66,0x65,0x72,0x20,0x73,0x6b,0x79,0x5f,0x66,0x73,0x5f,0x70,
+    0x61,0x72,0x61,0x6d,0x73,0x20,0x3a,0x20,0x72,0x65,0x67,0x69,0x73,0x74,0x65,0x72,
+    0x28,0x62,0x31,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,
+    0x33,0x20,0x5f,0x32,0x37,0x5f,0x7a,0x65,0x6e,0x69,0x74,0x68,0x5f,0x63,0x6f,0x6c,
+    0x6f,0x72,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,
+    0x63,0x30,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x20,0x5f,
+    0x32,0x37,0x5f,0x73,0x75,0x6e,0x5f,0x73,0x68,0x61,0x72,0x70,0x6e,0x65,0x73,0x73,
+    0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,0x30,
+    0x2e,0x77,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,
+    0x5f,0x32,0x37,0x5f,0x68,0x6f,0x72,0x69,0x7a,0x6f,0x6e,0x5f,0x63,0x6f,0x6c,0x6f,
+    0x72,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,
+    0x31,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x20,0x5f,0x32,
+    0x37,0x5f,0x73,0x75,0x6e,0x5f,0x69,0x6e,0x74,0x65,0x6e,0x73,0x69,0x74,0x79,0x20,
+    0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,0x31,0x2e,
+    0x77,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x5f,
+    0x32,0x37,0x5f,0x73,0x75,0x6e,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,
+    0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,0x32,
+    0x29,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,
+    0x6c,0x6f,0x61,0x74,0x33,0x20,0x66,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,
+    0x6e,0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,
+    0x20,0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,
+    0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x49,0x6e,0x70,0x75,
+    0x74,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x66,
+    0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x54,0x45,0x58,
+    0x43,0x4f,0x4f,0x52,0x44,0x30,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,
+    0x63,0x74,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x4f,
+    0x75,0x74,0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,
+    0x74,0x34,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3a,0x20,0x53,0x56,0x5f,0x54,0x61,
+    0x72,0x67,0x65,0x74,0x30,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,0x76,0x6f,0x69,0x64,0x20,
+    0x66,0x72,0x61,0x67,0x5f,0x6d,0x61,0x69,0x6e,0x28,0x29,0x0a,0x7b,0x0a,0x20,0x20,
+    0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x5f,0x31,0x32,0x20,0x3d,0x20,0x6e,
+    0x6f,0x72,0x6d,0x61,0x6c,0x69,0x7a,0x65,0x28,0x66,0x5f,0x64,0x69,0x72,0x65,0x63,
+    0x74,0x69,0x6f,0x6e,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x63,0x6f,0x6c,0x6f,0x72,
+    0x20,0x3d,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x28,0x6c,0x65,0x72,0x70,0x28,0x5f,
+    0x32,0x37,0x5f,0x68,0x6f,0x72,0x69,0x7a,0x6f,0x6e,0x5f,0x63,0x6f,0x6c,0x6f,0x72,
+    0x2c,0x20,0x5f,0x32,0x37,0x5f,0x7a,0x65,0x6e,0x69,0x74,0x68,0x5f,0x63,0x6f,0x6c,
+    0x6f,0x72,0x2c,0x20,0x73,0x71,0x72,0x74,0x28,0x6d,0x61,0x78,0x28,0x5f,0x31,0x32,
+    0x2e,0x79,0x2c,0x20,0x30,0x2e,0x30,0x66,0x29,0x29,0x2e,0x78,0x78,0x78,0x29,0x20,
+    0x2b,0x20,0x28,0x70,0x6f,0x77,0x28,0x6d,0x61,0x78,0x28,0x64,0x6f,0x74,0x28,0x5f,
+    0x31,0x32,0x2c,0x20,0x5f,0x32,0x37,0x5f,0x73,0x75,0x6e,0x5f,0x64,0x69,0x72,0x65,
+    0x63,0x74,0x69,0x6f,0x6e,0x29,0x2c,0x20,0x30,0x2e,0x30,0x66,0x29,0x2c,0x20,0x5f,
+    0x32,0x37,0x5f,0x73,0x75,0x6e,0x5f,0x73,0x68,0x61,0x72,0x70,0x6e,0x65,0x73,0x73,
+    0x29,0x20,0x2a,0x20,0x5f,0x32,0x37,0x5f,0x73,0x75,0x6e,0x5f,0x69,0x6e,0x74,0x65,
+    0x6e,0x73,0x69,0x74,0x79,0x29,0x2e,0x78,0x78,0x78,0x2c,0x20,0x31,0x2e,0x30,0x66,
+    0x29,0x3b,0x0a,0x7d,0x0a,0x0a,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,
+    0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x20,0x6d,0x61,0x69,0x6e,0x28,0x53,0x50,
+    0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x49,0x6e,0x70,0x75,0x74,0x20,
+    0x73,0x74,0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x29,0x0a,0x7b,0x0a,0x20,
+    0x20,0x20,0x20,0x66,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,0x20,0x3d,
+    0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x2e,0x66,0x5f,0x64,
+    0x69,0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x72,
+    0x61,0x67,0x5f,0x6d,0x61,0x69,0x6e,0x28,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x53,
+    0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,
+    0x74,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x3b,0x0a,
+    0x20,0x20,0x20,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,
+    0x2e,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3d,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,
+    0x20,0x20,0x20,0x20,0x72,0x65,0x74,0x75,0x72,0x6e,0x20,0x73,0x74,0x61,0x67,0x65,
+    0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x3b,0x0a,0x7d,0x0a,0x00,
+];
+/*
+    diagnostic(off, derivative_uniformity);
+
+    struct sky_vs_params {
+      /_ @offset(0) _/
+      inverse_view_projection : mat4x4f,
+    }
+
+    @group(0) @binding(0) var<uniform> x_31 : sky_vs_params;
+
+    var<private> gl_Position : vec4f;
+
+    var<private> position_1 : vec2f;
+
+    var<private> f_direction : vec3f;
+
+    fn main_1() {
+      let x_22 : vec2f = position_1;
+      gl_Position = vec4f(x_22.x, x_22.y, 0.0f, 1.0f);
+      let x_35 : mat4x4f = x_31.inverse_view_projection;
+      let x_37 : vec4f = (x_35 * vec4f(x_22.x, x_22.y, 1.0f, 1.0f));
+      f_direction = (x_37.xyz / vec3f(x_37.w));
+      return;
+    }
+
+    struct main_out {
+      @builtin(position)
+      gl_Position : vec4f,
+      @location(0)
+      f_direction_1 : vec3f,
+    }
+
+    @vertex
+    fn main(@location(0) position_1_param : vec2f) -> main_out {
+      position_1 = position_1_param;
+      main_1();
+      return main_out(gl_Position, f_direction);
+    }
+
+*/
+pub const SKY_VERTEX_SOURCE_WGSL: [u8; 826] = [
+    0x64,0x69,0x61,0x67,0x6e,0x6f,0x73,0x74,0x69,0x63,0x28,0x6f,0x66,0x66,0x2c,0x20,
+    0x64,0x65,0x72,0x69,0x76,0x61,0x74,0x69,0x76,0x65,0x5f,0x75,0x6e,0x69,0x66,0x6f,
+    0x72,0x6d,0x69,0x74,0x79,0x29,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,
+    0x73,0x6b,0x79,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x20,0x7b,0x0a,
+    0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x30,0x29,0x20,
+    0x2a,0x2f,0x0a,0x20,0x20,0x69,0x6e,0x76,0x65,0x72,0x73,0x65,0x5f,0x76,0x69,0x65,
+    0x77,0x5f,0x70,0x72,0x6f,0x6a,0x65,0x63,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x6d,
+    0x61,0x74,0x34,0x78,0x34,0x66,0x2c,0x0a,0x7d,0x0a,0x0a,0x40,0x67,0x72,0x6f,0x75,
+    0x70,0x28,0x30,0x29,0x20,0x40,0x62,0x69,0x6e,0x64,0x69,0x6e,0x67,0x28,0x30,0x29,
+    0x20,0x76,0x61,0x72,0x3c,0x75,0x6e,0x69,0x66,0x6f,0x72,0x6d,0x3e,0x20,0x78,0x5f,
+    0x33,0x31,0x20,0x3a,0x20,0x73,0x6b,0x79,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,
+    0x6d,0x73,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,
+    0x3e,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,
+    0x76,0x65,0x63,0x34,0x66,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,
+    0x61,0x74,0x65,0x3e,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x20,
+    0x3a,0x20,0x76,0x65,0x63,0x32,0x66,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,
+    0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x66,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,
+    0x6f,0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x3b,0x0a,0x0a,0x66,0x6e,0x20,
+    0x6d,0x61,0x69,0x6e,0x5f,0x31,0x28,0x29,0x20,0x7b,0x0a,0x20,0x20,0x6c,0x65,0x74,
+    0x20,0x78,0x5f,0x32,0x32,0x20,0x3a,0x20,0x76,0x65,0x63,0x32,0x66,0x20,0x3d,0x20,
+    0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x3b,0x0a,0x20,0x20,0x67,0x6c,
+    0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x76,0x65,0x63,0x34,
+    0x66,0x28,0x78,0x5f,0x32,0x32,0x2e,0x78,0x2c,0x20,0x78,0x5f,0x32,0x32,0x2e,0x79,
+    0x2c,0x20,0x30,0x2e,0x30,0x66,0x2c,0x20,0x31,0x2e,0x30,0x66,0x29,0x3b,0x0a,0x20,
+    0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x33,0x35,0x20,0x3a,0x20,0x6d,0x61,0x74,0x34,
+    0x78,0x34,0x66,0x20,0x3d,0x20,0x78,0x5f,0x33,0x31,0x2e,0x69,0x6e,0x76,0x65,0x72,
+    0x73,0x65,0x5f,0x76,0x69,0x65,0x77,0x5f,0x70,0x72,0x6f,0x6a,0x65,0x63,0x74,0x69,
+    0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x33,0x37,0x20,0x3a,
+    0x20,0x76,0x65,0x63,0x34,0x66,0x20,0x3d,0x20,0x28,0x78,0x5f,0x33,0x35,0x20,0x2a,
+    0x20,0x76,0x65,0x63,0x34,0x66,0x28,0x78,0x5f,0x32,0x32,0x2e,0x78,0x2c,0x20,0x78,
+    0x5f,0x32,0x32,0x2e,0x79,0x2c,0x20,0x31,0x2e,0x30,0x66,0x2c,0x20,0x31,0x2e,0x30,
+    0x66,0x29,0x29,0x3b,0x0a,0x20,0x20,0x66,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,
+    0x6f,0x6e,0x20,0x3d,0x20,0x28,0x78,0x5f,0x33,0x37,0x2e,0x78,0x79,0x7a,0x20,0x2f,
+    0x20,0x76,0x65,0x63,0x33,0x66,0x28,0x78,0x5f,0x33,0x37,0x2e,0x77,0x29,0x29,0x3b,
+    0x0a,0x20,0x20,0x72,0x65,0x74,0x75,0x72,0x6e,0x3b,0x0a,0x7d,0x0a,0x0a,0x73,0x74,
+    0x72,0x75,0x63,0x74,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,0x74,0x20,0x7b,0x0a,
+    0x20,0x20,0x40,0x62,0x75,0x69,0x6c,0x74,0x69,0x6e,0x28,0x70,0x6f,0x73,0x69,0x74,
+    0x69,0x6f,0x6e,0x29,0x0a,0x20,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,
+    0x6f,0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x2c,0x0a,0x20,0x20,0x40,0x6c,
+    0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x30,0x29,0x0a,0x20,0x20,0x66,0x5f,0x64,
+    0x69,0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x20,0x3a,0x20,0x76,0x65,0x63,
+    0x33,0x66,0x2c,0x0a,0x7d,0x0a,0x0a,0x40,0x76,0x65,0x72,0x74,0x65,0x78,0x0a,0x66,
+    0x6e,0x20,0x6d,0x61,0x69,0x6e,0x28,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,
+    0x28,0x30,0x29,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x5f,0x70,
+    0x61,0x72,0x61,0x6d,0x20,0x3a,0x20,0x76,0x65,0x63,0x32,0x66,0x29,0x20,0x2d,0x3e,
+    0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,0x74,0x20,0x7b,0x0a,0x20,0x20,0x70,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x20,0x3d,0x20,0x70,0x6f,0x73,0x69,0x74,
+    0x69,0x6f,0x6e,0x5f,0x31,0x5f,0x70,0x61,0x72,0x61,0x6d,0x3b,0x0a,0x20,0x20,0x6d,
+    0x61,0x69,0x6e,0x5f,0x31,0x28,0x29,0x3b,0x0a,0x20,0x20,0x72,0x65,0x74,0x75,0x72,
+    0x6e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,0x74,0x28,0x67,0x6c,0x5f,0x50,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x2c,0x20,0x66,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,
+    0x69,0x6f,0x6e,0x29,0x3b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+/*
+    diagnostic(off, derivative_uniformity);
+
+    struct sky_fs_params {
+      /_ @offset(0) _/
+      zenith_color : vec3f,
+      /_ @offset(12) _/
+      sun_sharpness : f32,
+      /_ @offset(16) _/
+      horizon_color : vec3f,
+      /_ @offset(28) _/
+      sun_intensity : f32,
+      /_ @offset(32) _/
+      sun_direction : vec3f,
+    }
+
+    @group(0) @binding(1) var<uniform> x_27 : sky_fs_params;
+
+    var<private> f_direction : vec3f;
+
+    var<private> color : vec4f;
+
+    fn main_1() {
+      let x_12 : vec3f = normalize(f_direction);
+      let x_40 : f32 = sqrt(max(x_12.y, 0.0f));
+      let x_60 : f32 = (pow(max(dot(x_12, x_27.sun_direction), 0.0f), x_27.sun_sharpness) * x_27.sun_intensity);
+      let x_64 : vec3f = (mix(x_27.horizon_color, x_27.zenith_color, vec3f(x_40)) + vec3f(x_60));
+      color = vec4f(x_64.x, x_64.y, x_64.z, 1.0f);
+      return;
+    }
+
+    struct main_out {
+      @location(0)
+      color_1 : vec4f,
+    }
+
+    @fragment
+    fn main(@location(0) f_direction_param : vec3f) -> main_out {
+      f_direction = f_direction_param;
+      main_1();
+      return main_out(color);
+    }
+
+*/
+pub const SKY_FRAG_SOURCE_WGSL: [u8; 978] = [
+    0x64,0x69,0x61,0x67,0x6e,0x6f,0x73,0x74,0x69,0x63,0x28,0x6f,0x66,0x66,0x2c,0x20,
+    0x64,0x65,0x72,0x69,0x76,0x61,0x74,0x69,0x76,0x65,0x5f,0x75,0x6e,0x69,0x66,0x6f,
+    0x72,0x6d,0x69,0x74,0x79,0x29,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,
+    0x73,0x6b,0x79,0x5f,0x66,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x20,0x7b,0x0a,
+    0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x30,0x29,0x20,
+    0x2a,0x2f,0x0a,0x20,0x20,0x7a,0x65,0x6e,0x69,0x74,0x68,0x5f,0x63,0x6f,0x6c,0x6f,
+    0x72,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x2c,0x0a,0x20,0x20,0x2f,0x2a,0x20,
+    0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x31,0x32,0x29,0x20,0x2a,0x2f,0x0a,0x20,
+    0x20,0x73,0x75,0x6e,0x5f,0x73,0x68,0x61,0x72,0x70,0x6e,0x65,0x73,0x73,0x20,0x3a,
+    0x20,0x66,0x33,0x32,0x2c,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,
+    0x65,0x74,0x28,0x31,0x36,0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,0x68,0x6f,0x72,0x69,
+    0x7a,0x6f,0x6e,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,
+    0x66,0x2c,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,
+    0x32,0x38,0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,0x73,0x75,0x6e,0x5f,0x69,0x6e,0x74,
+    0x65,0x6e,0x73,0x69,0x74,0x79,0x20,0x3a,0x20,0x66,0x33,0x32,0x2c,0x0a,0x20,0x20,
+    0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x33,0x32,0x29,0x20,0x2a,
+    0x2f,0x0a,0x20,0x20,0x73,0x75,0x6e,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,
+    0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x2c,0x0a,0x7d,0x0a,0x0a,0x40,0x67,
+    0x72,0x6f,0x75,0x70,0x28,0x30,0x29,0x20,0x40,0x62,0x69,0x6e,0x64,0x69,0x6e,0x67,
+    0x28,0x31,0x29,0x20,0x76,0x61,0x72,0x3c,0x75,0x6e,0x69,0x66,0x6f,0x72,0x6d,0x3e,
+    0x20,0x78,0x5f,0x32,0x37,0x20,0x3a,0x20,0x73,0x6b,0x79,0x5f,0x66,0x73,0x5f,0x70,
+    0x61,0x72,0x61,0x6d,0x73,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,
+    0x61,0x74,0x65,0x3e,0x20,0x66,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,
+    0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,
+    0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3a,0x20,
+    0x76,0x65,0x63,0x34,0x66,0x3b,0x0a,0x0a,0x66,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x5f,
+    0x31,0x28,0x29,0x20,0x7b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x31,0x32,
+    0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x20,0x3d,0x20,0x6e,0x6f,0x72,0x6d,0x61,
+    0x6c,0x69,0x7a,0x65,0x28,0x66,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,
+    0x29,0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x34,0x30,0x20,0x3a,0x20,
+    0x66,0x33,0x32,0x20,0x3d,0x20,0x73,0x71,0x72,0x74,0x28,0x6d,0x61,0x78,0x28,0x78,
+    0x5f,0x31,0x32,0x2e,0x79,0x2c,0x20,0x30,0x2e,0x30,0x66,0x29,0x29,0x3b,0x0a,0x20,
+    0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x36,0x30,0x20,0x3a,0x20,0x66,0x33,0x32,0x20,
+    0x3d,0x20,0x28,0x70,0x6f,0x77,0x28,0x6d,0x61,0x78,0x28,0x64,0x6f,0x74,0x28,0x78,
+    0x5f,0x31,0x32,0x2c,0x20,0x78,0x5f,0x32,0x37,0x2e,0x73,0x75,0x6e,0x5f,0x64,0x69,
+    0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,0x29,0x2c,0x20,0x30,0x2e,0x30,0x66,0x29,0x2c,
+    0x20,0x78,0x5f,0x32,0x37,0x2e,0x73,0x75,0x6e,0x5f,0x73,0x68,0x61,0x72,0x70,0x6e,
+    0x65,0x73,0x73,0x29,0x20,0x2a,0x20,0x78,0x5f,0x32,0x37,0x2e,0x73,0x75,0x6e,0x5f,
+    0x69,0x6e,0x74,0x65,0x6e,0x73,0x69,0x74,0x79,0x29,0x3b,0x0a,0x20,0x20,0x6c,0x65,
+    0x74,0x20,0x78,0x5f,0x36,0x34,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x20,0x3d,
+    0x20,0x28,0x6d,0x69,0x78,0x28,0x78,0x5f,0x32,0x37,0x2e,0x68,0x6f,0x72,0x69,0x7a,
+    0x6f,0x6e,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x2c,0x20,0x78,0x5f,0x32,0x37,0x2e,0x7a,
+    0x65,0x6e,0x69,0x74,0x68,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x2c,0x20,0x76,0x65,0x63,
+    0x33,0x66,0x28,0x78,0x5f,0x34,0x30,0x29,0x29,0x20,0x2b,0x20,0x76,0x65,0x63,0x33,
+    0x66,0x28,0x78,0x5f,0x36,0x30,0x29,0x29,0x3b,0x0a,0x20,0x20,0x63,0x6f,0x6c,0x6f,
+    0x72,0x20,0x3d,0x20,0x76,0x65,0x63,0x34,0x66,0x28,0x78,0x5f,0x36,0x34,0x2e,0x78,
+    0x2c,0x20,0x78,0x5f,0x36,0x34,0x2e,0x79,0x2c,0x20,0x78,0x5f,0x36,0x34,0x2e,0x7a,
+    0x2c,0x20,0x31,0x2e,0x30,0x66,0x29,0x3b,0x0a,0x20,0x20,0x72,0x65,0x74,0x75,0x72,
+    0x6e,0x3b,0x0a,0x7d,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,0x6d,0x61,0x69,
+    0x6e,0x5f,0x6f,0x75,0x74,0x20,0x7b,0x0a,0x20,0x20,0x40,0x6c,0x6f,0x63,0x61,0x74,
+    0x69,0x6f,0x6e,0x28,0x30,0x29,0x0a,0x20,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x5f,0x31,
+    0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x2c,0x0a,0x7d,0x0a,0x0a,0x40,0x66,0x72,
+    0x61,0x67,0x6d,0x65,0x6e,0x74,0x0a,0x66,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x28,0x40,
+    0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x30,0x29,0x20,0x66,0x5f,0x64,0x69,
+    0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,0x5f,0x70,0x61,0x72,0x61,0x6d,0x20,0x3a,0x20,
+    0x76,0x65,0x63,0x33,0x66,0x29,0x20,0x2d,0x3e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,
+    0x75,0x74,0x20,0x7b,0x0a,0x20,0x20,0x66,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,
+    0x6f,0x6e,0x20,0x3d,0x20,0x66,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,
+    0x5f,0x70,0x61,0x72,0x61,0x6d,0x3b,0x0a,0x20,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x31,
+    0x28,0x29,0x3b,0x0a,0x20,0x20,0x72,0x65,0x74,0x75,0x72,0x6e,0x20,0x6d,0x61,0x69,
+    0x6e,0x5f,0x6f,0x75,0x74,0x28,0x63,0x6f,0x6c,0x6f,0x72,0x29,0x3b,0x0a,0x7d,0x0a,
+    0x0a,0x00,
+];
+/*
+    #version 430
+
+    uniform vec4 line_vs_params[4];
+    layout(location = 0) in vec3 position;
+    layout(location = 0) out vec4 f_color;
+    layout(location = 1) in vec4 v_color;
+
+    void main()
+    {
+        gl_Position = mat4(line_vs_params[0], line_vs_params[1], line_vs_params[2], line_vs_params[3]) * vec4(position, 1.0);
+        f_color = v_color;
+    }
+
+*/
+pub const LINE_VERTEX_SOURCE_GLSL430: [u8; 326] = [
+    0x23,0x76,0x65,0x72,0x73,0x69,0x6f,0x6e,0x20,0x34,0x33,0x30,0x0a,0x0a,0x75,0x6e,
+    0x69,0x66,0x6f,0x72,0x6d,0x20,0x76,0x65,0x63,0x34,0x20,0x6c,0x69,0x6e,0x65,0x5f,
+    0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x34,0x5d,0x3b,0x0a,0x6c,0x61,
+    0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,
+    0x30,0x29,0x20,0x69,0x6e,0x20,0x76,0x65,0x63,0x33,0x20,0x70,0x6f,0x73,0x69,0x74,
+    0x69,0x6f,0x6e,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,
+    0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x30,0x29,0x20,0x6f,0x75,0x74,0x20,0x76,0x65,
+    0x63,0x34,0x20,0x66,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,0x6c,0x61,0x79,0x6f,
+    0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x31,0x29,
+    0x20,0x69,0x6e,0x20,0x76,0x65,0x63,0x34,0x20,0x76,0x5f,0x63,0x6f,0x6c,0x6f,0x72,
+    0x3b,0x0a,0x0a,0x76,0x6f,0x69,0x64,0x20,0x6d,0x61,0x69,0x6e,0x28,0x29,0x0a,0x7b,
+    0x0a,0x20,0x20,0x20,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,
+    0x20,0x3d,0x20,0x6d,0x61,0x74,0x34,0x28,0x6c,0x69,0x6e,0x65,0x5f,0x76,0x73,0x5f,
+    0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x30,0x5d,0x2c,0x20,0x6c,0x69,0x6e,0x65,0x5f,
+    0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x31,0x5d,0x2c,0x20,0x6c,0x69,
+    0x6e,0x65,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x32,0x5d,0x2c,
+    0x20,0x6c,0x69,0x6e,0x65,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,
+    0x33,0x5d,0x29,0x20,0x2a,0x20,0x76,0x65,0x63,0x34,0x28,0x70,0x6f,0x73,0x69,0x74,
+    0x69,0x6f,0x6e,0x2c,0x20,0x31,0x2e,0x30,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,
+    0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3d,0x20,0x76,0x5f,0x63,0x6f,0x6c,0x6f,0x72,
+    0x3b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+/*
+    #version 430
+
+    layout(location = 0) out vec4 color;
+    layout(location = 0) in vec4 f_color;
+
+    void main()
+    {
+        color = f_color;
+    }
+
+*/
+pub const LINE_FRAG_SOURCE_GLSL430: [u8; 129] = [
+    0x23,0x76,0x65,0x72,0x73,0x69,0x6f,0x6e,0x20,0x34,0x33,0x30,0x0a,0x0a,0x6c,0x61,
+    0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,
+    0x30,0x29,0x20,0x6f,0x75,0x74,0x20,0x76,0x65,0x63,0x34,0x20,0x63,0x6f,0x6c,0x6f,
+    0x72,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,
+    0x6f,0x6e,0x20,0x3d,0x20,0x30,0x29,0x20,0x69,0x6e,0x20,0x76,0x65,0x63,0x34,0x20,
+    0x66,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,0x0a,0x76,0x6f,0x69,0x64,0x20,0x6d,
+    0x61,0x69,0x6e,0x28,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x63,0x6f,0x6c,0x6f,
+    0x72,0x20,0x3d,0x20,0x66,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,0x7d,0x0a,0x0a,
+    0x00,
+];
+/*
+    cbuffer line_vs_params : register(b0)
+    {
+        row_major float4x4 _19_view_projection : packoffset(c0);
+    };
+
+
+    static float4 gl_Position;
+    static float3 position;
+    static float4 f_color;
+    static float4 v_color;
+
+    struct SPIRV_Cross_Input
+    {
+        float3 position : TEXCOORD0;
+        float4 v_color : TEXCOORD1;
+    };
+
+    struct SPIRV_Cross_Output
+    {
+        float4 f_color : TEXCOORD0;
+        float4 gl_Position : SV_Position;
+    };
+
+    void vert_main()
+    {
+        gl_Position = mul(float4(position, 1.0f), _19_view_projection);
+        f_color = v_color;
+    }
+
+    SPIRV_Cross_Output main(SPIRV_Cross_Input stage_input)
+    {
+        position = stage_input.position;
+        v_color = stage_input.v_color;
+        vert_main();
+        SPIRV_Cross_Output stage_output;
+        stage_output.gl_Position = gl_Position;
+        stage_output.f_color = f_color;
+        return stage_output;
+    }
+*/
+pub const LINE_VERTEX_SOURCE_HLSL5: [u8; 806] = [
+    0x63,0x62,0x75,0x66,0x66,0x65,0x72,0x20,0x6c,0x69,0x6e,0x65,0x5f,0x76,0x73,0x5f,
+    0x70,0x61,0x72,0x61,0x6d,0x73,0x20,0x3a,0x20,0x72,0x65,0x67,0x69,0x73,0x74,0x65,
+    0x72,0x28,0x62,0x30,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x72,0x6f,0x77,0x5f,
+    0x6d,0x61,0x6a,0x6f,0x72,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x78,0x34,0x20,0x5f,
+    0x31,0x39,0x5f,0x76,0x69,0x65,0x77,0x5f,0x70,0x72,0x6f,0x6a,0x65,0x63,0x74,0x69,
+    0x6f,0x6e,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,
+    0x63,0x30,0x29,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,
+    0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,
+    0x69,0x6f,0x6e,0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,
+    0x74,0x33,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x73,0x74,0x61,
+    0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x66,0x5f,0x63,0x6f,0x6c,
+    0x6f,0x72,0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,
+    0x34,0x20,0x76,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,
+    0x63,0x74,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x49,
+    0x6e,0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,
+    0x33,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x54,0x45,0x58,
+    0x43,0x4f,0x4f,0x52,0x44,0x30,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,
+    0x74,0x34,0x20,0x76,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3a,0x20,0x54,0x45,0x58,
+    0x43,0x4f,0x4f,0x52,0x44,0x31,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,
+    0x63,0x74,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x4f,
+    0x75,0x74,0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,
+    0x74,0x34,0x20,0x66,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3a,0x20,0x54,0x45,0x58,
+    0x43,0x4f,0x4f,0x52,0x44,0x30,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,
+    0x74,0x34,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,
+    0x20,0x53,0x56,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x7d,0x3b,
+    0x0a,0x0a,0x76,0x6f,0x69,0x64,0x20,0x76,0x65,0x72,0x74,0x5f,0x6d,0x61,0x69,0x6e,
+    0x28,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x6d,0x75,0x6c,0x28,0x66,0x6c,0x6f,0x61,0x74,
+    0x34,0x28,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x2c,0x20,0x31,0x2e,0x30,0x66,
+    0x29,0x2c,0x20,0x5f,0x31,0x39,0x5f,0x76,0x69,0x65,0x77,0x5f,0x70,0x72,0x6f,0x6a,
+    0x65,0x63,0x74,0x69,0x6f,0x6e,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x5f,0x63,
+    0x6f,0x6c,0x6f,0x72,0x20,0x3d,0x20,0x76,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,
+    0x7d,0x0a,0x0a,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x4f,
+    0x75,0x74,0x70,0x75,0x74,0x20,0x6d,0x61,0x69,0x6e,0x28,0x53,0x50,0x49,0x52,0x56,
+    0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x49,0x6e,0x70,0x75,0x74,0x20,0x73,0x74,0x61,
+    0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,
+    0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x73,0x74,0x61,0x67,0x65,
+    0x5f,0x69,0x6e,0x70,0x75,0x74,0x2e,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,
+    0x0a,0x20,0x20,0x20,0x20,0x76,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3d,0x20,0x73,
+    0x74,0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x2e,0x76,0x5f,0x63,0x6f,0x6c,
+    0x6f,0x72,0x3b,0x0a,0x20,0x20,0x20,0x20,0x76,0x65,0x72,0x74,0x5f,0x6d,0x61,0x69,
+    0x6e,0x28,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,
+    0x72,0x6f,0x73,0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x20,0x73,0x74,0x61,0x67,
+    0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x3b,0x0a,0x20,0x20,0x20,0x20,0x73,0x74,
+    0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x2e,0x67,0x6c,0x5f,0x50,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x20,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,
+    0x6f,0x75,0x74,0x70,0x75,0x74,0x2e,0x66,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3d,
+    0x20,0x66,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,0x20,0x20,0x20,0x20,0x72,0x65,
+    0x74,0x75,0x72,0x6e,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,
+    0x74,0x3b,0x0a,0x7d,0x0a,0x00,
+];
+/*
+    static float4 color;
+    static float4 f_color;
+
+    struct SPIRV_Cross_Input
+    {
+        float4 f_color : TEXCOORD0;
+    };
+
+    struct SPIRV_Cross_Output
+    {
+        float4 color : SV_Target0;
+    };
+
+    void frag_main()
+    {
+        color = f_color;
+    }
+
+    SPIRV_Cross_Output main(SPIRV_Cross_Input stage_input)
+    {
+        f_color = stage_input.f_color;
+        frag_main();
+        SPIRV_Cross_Output stage_output;
+        stage_output.color = color;
+        return stage_output;
+    }
+*/
+pub const LINE_FRAG_SOURCE_HLSL5: [u8; 420] = [
+    0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x63,0x6f,
+    0x6c,0x6f,0x72,0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,
+    0x74,0x34,0x20,0x66,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,0x0a,0x73,0x74,0x72,
+    0x75,0x63,0x74,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,
+    0x49,0x6e,0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,
+    0x74,0x34,0x20,0x66,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3a,0x20,0x54,0x45,0x58,
+    0x43,0x4f,0x4f,0x52,0x44,0x30,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,
+    0x63,0x74,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x4f,
+    0x75,0x74,0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,
+    0x74,0x34,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3a,0x20,0x53,0x56,0x5f,0x54,0x61,
+    0x72,0x67,0x65,0x74,0x30,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,0x76,0x6f,0x69,0x64,0x20,
+    0x66,0x72,0x61,0x67,0x5f,0x6d,0x61,0x69,0x6e,0x28,0x29,0x0a,0x7b,0x0a,0x20,0x20,
+    0x20,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3d,0x20,0x66,0x5f,0x63,0x6f,0x6c,0x6f,
+    0x72,0x3b,0x0a,0x7d,0x0a,0x0a,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,
+    0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x20,0x6d,0x61,0x69,0x6e,0x28,0x53,0x50,
+    0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x49,0x6e,0x70,0x75,0x74,0x20,
+    0x73,0x74,0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x29,0x0a,0x7b,0x0a,0x20,
+    0x20,0x20,0x20,0x66,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3d,0x20,0x73,0x74,0x61,
+    0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x2e,0x66,0x5f,0x63,0x6f,0x6c,0x6f,0x72,
+    0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x72,0x61,0x67,0x5f,0x6d,0x61,0x69,0x6e,0x28,
+    0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,
+    0x73,0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,
+    0x6f,0x75,0x74,0x70,0x75,0x74,0x3b,0x0a,0x20,0x20,0x20,0x20,0x73,0x74,0x61,0x67,
+    0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x2e,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3d,
+    0x20,0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,0x20,0x20,0x20,0x20,0x72,0x65,0x74,0x75,
+    0x72,0x6e,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x3b,
+    0x0a,0x7d,0x0a,0x00,
+];
+/*
+    diagnostic(off, derivative_uniformity);
+
+    struct line_vs_params {
+      /_ @offset(0) _/
+      view_projection : mat4x4f,
+    }
+
+    @group(0) @binding(0) var<uniform> x_19 : line_vs_params;
+
+    var<private> position_1 : vec3f;
+
+    var<private> f_color : vec4f;
+
+    var<private> v_color : vec4f;
+
+    var<private> gl_Position : vec4f;
+
+    fn main_1() {
+      let x_22 : mat4x4f = x_19.view_projection;
+      let x_26 : vec3f = position_1;
+      gl_Position = (x_22 * vec4f(x_26.x, x_26.y, x_26.z, 1.0f));
+      let x_37 : vec4f = v_color;
+      f_color = x_37;
+      return;
+    }
+
+    struct main_out {
+      @builtin(position)
+      gl_Position : vec4f,
+      @location(0)
+      f_color_1 : vec4f,
+    }
+
+    @vertex
+    fn main(@location(0) position_1_param : vec3f, @location(1) v_color_param : vec4f) -> main_out {
+      position_1 = position_1_param;
+      v_color = v_color_param;
+      main_1();
+      return main_out(gl_Position, f_color);
+    }
+
+*/
+pub const LINE_VERTEX_SOURCE_WGSL: [u8; 844] = [
+    0x64,0x69,0x61,0x67,0x6e,0x6f,0x73,0x74,0x69,0x63,0x28,0x6f,0x66,0x66,0x2c,0x20,
+    0x64,0x65,0x72,0x69,0x76,0x61,0x74,0x69,0x76,0x65,0x5f,0x75,0x6e,0x69,0x66,0x6f,
+    0x72,0x6d,0x69,0x74,0x79,0x29,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,
+    0x6c,0x69,0x6e,0x65,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x20,0x7b,
+    0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x30,0x29,
+    0x20,0x2a,0x2f,0x0a,0x20,0x20,0x76,0x69,0x65,0x77,0x5f,0x70,0x72,0x6f,0x6a,0x65,
+    0x63,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x6d,0x61,0x74,0x34,0x78,0x34,0x66,0x2c,
+    0x0a,0x7d,0x0a,0x0a,0x40,0x67,0x72,0x6f,0x75,0x70,0x28,0x30,0x29,0x20,0x40,0x62,
+    0x69,0x6e,0x64,0x69,0x6e,0x67,0x28,0x30,0x29,0x20,0x76,0x61,0x72,0x3c,0x75,0x6e,
+    0x69,0x66,0x6f,0x72,0x6d,0x3e,0x20,0x78,0x5f,0x31,0x39,0x20,0x3a,0x20,0x6c,0x69,
+    0x6e,0x65,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x3b,0x0a,0x0a,0x76,
+    0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x70,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x5f,0x31,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x3b,0x0a,
+    0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x66,0x5f,
+    0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x3b,0x0a,0x0a,
+    0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x76,0x5f,0x63,
+    0x6f,0x6c,0x6f,0x72,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x3b,0x0a,0x0a,0x76,
+    0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x67,0x6c,0x5f,0x50,
+    0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x3b,
+    0x0a,0x0a,0x66,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x31,0x28,0x29,0x20,0x7b,0x0a,
+    0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x32,0x32,0x20,0x3a,0x20,0x6d,0x61,0x74,
+    0x34,0x78,0x34,0x66,0x20,0x3d,0x20,0x78,0x5f,0x31,0x39,0x2e,0x76,0x69,0x65,0x77,
+    0x5f,0x70,0x72,0x6f,0x6a,0x65,0x63,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x6c,
+    0x65,0x74,0x20,0x78,0x5f,0x32,0x36,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x20,
+    0x3d,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x3b,0x0a,0x20,0x20,
+    0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x28,0x78,
+    0x5f,0x32,0x32,0x20,0x2a,0x20,0x76,0x65,0x63,0x34,0x66,0x28,0x78,0x5f,0x32,0x36,
+    0x2e,0x78,0x2c,0x20,0x78,0x5f,0x32,0x36,0x2e,0x79,0x2c,0x20,0x78,0x5f,0x32,0x36,
+    0x2e,0x7a,0x2c,0x20,0x31,0x2e,0x30,0x66,0x29,0x29,0x3b,0x0a,0x20,0x20,0x6c,0x65,
+    0x74,0x20,0x78,0x5f,0x33,0x37,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x20,0x3d,
+    0x20,0x76,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,0x20,0x20,0x66,0x5f,0x63,0x6f,
+    0x6c,0x6f,0x72,0x20,0x3d,0x20,0x78,0x5f,0x33,0x37,0x3b,0x0a,0x20,0x20,0x72,0x65,
+    0x74,0x75,0x72,0x6e,0x3b,0x0a,0x7d,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,
+    0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,0x74,0x20,0x7b,0x0a,0x20,0x20,0x40,0x62,0x75,
+    0x69,0x6c,0x74,0x69,0x6e,0x28,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x29,0x0a,
+    0x20,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,
+    0x76,0x65,0x63,0x34,0x66,0x2c,0x0a,0x20,0x20,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,
+    0x6f,0x6e,0x28,0x30,0x29,0x0a,0x20,0x20,0x66,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x5f,
+    0x31,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x2c,0x0a,0x7d,0x0a,0x0a,0x40,0x76,
+    0x65,0x72,0x74,0x65,0x78,0x0a,0x66,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x28,0x40,0x6c,
+    0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x30,0x29,0x20,0x70,0x6f,0x73,0x69,0x74,
+    0x69,0x6f,0x6e,0x5f,0x31,0x5f,0x70,0x61,0x72,0x61,0x6d,0x20,0x3a,0x20,0x76,0x65,
+    0x63,0x33,0x66,0x2c,0x20,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x31,
+    0x29,0x20,0x76,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x5f,0x70,0x61,0x72,0x61,0x6d,0x20,
+    0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x29,0x20,0x2d,0x3e,0x20,0x6d,0x61,0x69,0x6e,
+    0x5f,0x6f,0x75,0x74,0x20,0x7b,0x0a,0x20,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,
+    0x6e,0x5f,0x31,0x20,0x3d,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,
+    0x5f,0x70,0x61,0x72,0x61,0x6d,0x3b,0x0a,0x20,0x20,0x76,0x5f,0x63,0x6f,0x6c,0x6f,
+    0x72,0x20,0x3d,0x20,0x76,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x5f,0x70,0x61,0x72,0x61,
+    0x6d,0x3b,0x0a,0x20,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x31,0x28,0x29,0x3b,0x0a,0x20,
+    0x20,0x72,0x65,0x74,0x75,0x72,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,0x74,
+    0x28,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x2c,0x20,0x66,0x5f,
+    0x63,0x6f,0x6c,0x6f,0x72,0x29,0x3b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+/*
+    diagnostic(off, derivative_uniformity);
+
+    var<private> color : vec4f;
+
+    var<private> f_color : vec4f;
+
+    fn main_1() {
+      let x_12 : vec4f = f_color;
+      color = x_12;
+      return;
+    }
+
+    struct main_out {
+      @location(0)
+      color_1 : vec4f,
+    }
+
+    @fragment
+    fn main(@location(0) f_color_param : vec4f) -> main_out {
+      f_color = f_color_param;
+      main_1();
+      return main_out(color);
+    }
+
+*/
+pub const LINE_FRAG_SOURCE_WGSL: [u8; 366] = [
+    0x64,0x69,0x61,0x67,0x6e,0x6f,0x73,0x74,0x69,0x63,0x28,0x6f,0x66,0x66,0x2c,0x20,
+    0x64,0x65,0x72,0x69,0x76,0x61,0x74,0x69,0x76,0x65,0x5f,0x75,0x6e,0x69,0x66,0x6f,
+    0x72,0x6d,0x69,0x74,0x79,0x29,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,
+    0x76,0x61,0x74,0x65,0x3e,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3a,0x20,0x76,0x65,
+    0x63,0x34,0x66,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,
+    0x65,0x3e,0x20,0x66,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3a,0x20,0x76,0x65,0x63,
+    0x34,0x66,0x3b,0x0a,0x0a,0x66,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x31,0x28,0x29,
+    0x20,0x7b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x31,0x32,0x20,0x3a,0x20,
+    0x76,0x65,0x63,0x34,0x66,0x20,0x3d,0x20,0x66,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x3b,
+    0x0a,0x20,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3d,0x20,0x78,0x5f,0x31,0x32,0x3b,
+    0x0a,0x20,0x20,0x72,0x65,0x74,0x75,0x72,0x6e,0x3b,0x0a,0x7d,0x0a,0x0a,0x73,0x74,
+    0x72,0x75,0x63,0x74,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,0x74,0x20,0x7b,0x0a,
+    0x20,0x20,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x30,0x29,0x0a,0x20,
+    0x20,0x63,0x6f,0x6c,0x6f,0x72,0x5f,0x31,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,
+    0x2c,0x0a,0x7d,0x0a,0x0a,0x40,0x66,0x72,0x61,0x67,0x6d,0x65,0x6e,0x74,0x0a,0x66,
+    0x6e,0x20,0x6d,0x61,0x69,0x6e,0x28,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,
+    0x28,0x30,0x29,0x20,0x66,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x5f,0x70,0x61,0x72,0x61,
+    0x6d,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x29,0x20,0x2d,0x3e,0x20,0x6d,0x61,
+    0x69,0x6e,0x5f,0x6f,0x75,0x74,0x20,0x7b,0x0a,0x20,0x20,0x66,0x5f,0x63,0x6f,0x6c,
+    0x6f,0x72,0x20,0x3d,0x20,0x66,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x5f,0x70,0x61,0x72,
+    0x61,0x6d,0x3b,0x0a,0x20,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x31,0x28,0x29,0x3b,0x0a,
+    0x20,0x20,0x72,0x65,0x74,0x75,0x72,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,
+    0x74,0x28,0x63,0x6f,0x6c,0x6f,0x72,0x29,0x3b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+/*
+    #version 430
+
+    uniform vec4 ghost_vs_params[8];
+    layout(location = 0) in vec3 position;
+    layout(location = 0) out vec3 f_normal;
+    layout(location = 1) in vec3 v_normal;
+
+    void main()
+    {
+        mat4 _21 = mat4(ghost_vs_params[0], ghost_vs_params[1], ghost_vs_params[2], ghost_vs_params[3]);
+        gl_Position = (mat4(ghost_vs_params[4], ghost_vs_params[5], ghost_vs_params[6], ghost_vs_params[7]) * _21) * vec4(position, 1.0);
+        f_normal = mat3(_21[0].xyz, _21[1].xyz, _21[2].xyz) * v_normal;
+    }
+
+*/
+pub const GHOST_VERTEX_SOURCE_GLSL430: [u8; 487] = [
+    0x23,0x76,0x65,0x72,0x73,0x69,0x6f,0x6e,0x20,0x34,0x33,0x30,0x0a,0x0a,0x75,0x6e,
+    0x69,0x66,0x6f,0x72,0x6d,0x20,0x76,0x65,0x63,0x34,0x20,0x67,0x68,0x6f,0x73,0x74,
+    0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x38,0x5d,0x3b,0x0a,0x6c,
+    0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x20,0x3d,
+    0x20,0x30,0x29,0x20,0x69,0x6e,0x20,0x76,0x65,0x63,0x33,0x20,0x70,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,
+    0x61,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x30,0x29,0x20,0x6f,0x75,0x74,0x20,0x76,
+    0x65,0x63,0x33,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x3b,0x0a,0x6c,0x61,
+    0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,
+    0x31,0x29,0x20,0x69,0x6e,0x20,0x76,0x65,0x63,0x33,0x20,0x76,0x5f,0x6e,0x6f,0x72,
+    0x6d,0x61,0x6c,0x3b,0x0a,0x0a,0x76,0x6f,0x69,0x64,0x20,0x6d,0x61,0x69,0x6e,0x28,
+    0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x6d,0x61,0x74,0x34,0x20,0x5f,0x32,0x31,
+    0x20,0x3d,0x20,0x6d,0x61,0x74,0x34,0x28,0x67,0x68,0x6f,0x73,0x74,0x5f,0x76,0x73,
+    0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x30,0x5d,0x2c,0x20,0x67,0x68,0x6f,0x73,
+    0x74,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x31,0x5d,0x2c,0x20,
+    0x67,0x68,0x6f,0x73,0x74,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,
+    0x32,0x5d,0x2c,0x20,0x67,0x68,0x6f,0x73,0x74,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,
+    0x61,0x6d,0x73,0x5b,0x33,0x5d,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x67,0x6c,0x5f,
+    0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x28,0x6d,0x61,0x74,0x34,
+    0x28,0x67,0x68,0x6f,0x73,0x74,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,
+    0x5b,0x34,0x5d,0x2c,0x20,0x67,0x68,0x6f,0x73,0x74,0x5f,0x76,0x73,0x5f,0x70,0x61,
+    0x72,0x61,0x6d,0x73,0x5b,0x35,0x5d,0x2c,0x20,0x67,0x68,0x6f,0x73,0x74,0x5f,0x76,
+    0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x36,0x5d,0x2c,0x20,0x67,0x68,0x6f,
+    0x73,0x74,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x37,0x5d,0x29,
+    0x20,0x2a,0x20,0x5f,0x32,0x31,0x29,0x20,0x2a,0x20,0x76,0x65,0x63,0x34,0x28,0x70,
+    0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x2c,0x20,0x31,0x2e,0x30,0x29,0x3b,0x0a,0x20,
+    0x20,0x20,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3d,0x20,0x6d,0x61,
+    0x74,0x33,0x28,0x5f,0x32,0x31,0x5b,0x30,0x5d,0x2e,0x78,0x79,0x7a,0x2c,0x20,0x5f,
+    0x32,0x31,0x5b,0x31,0x5d,0x2e,0x78,0x79,0x7a,0x2c,0x20,0x5f,0x32,0x31,0x5b,0x32,
+    0x5d,0x2e,0x78,0x79,0x7a,0x29,0x20,0x2a,0x20,0x76,0x5f,0x6e,0x6f,0x72,0x6d,0x61,
+    0x6c,0x3b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+/*
+    #version 430
+
+    uniform vec4 ghost_fs_params[2];
+    layout(location = 0) in vec3 f_normal;
+    layout(location = 0) out vec4 color;
+
+    void main()
+    {
+        color = vec4(ghost_fs_params[0].xyz * (ghost_fs_params[1].w + ((1.0 - ghost_fs_params[1].w) * abs(dot(normalize(f_normal), ghost_fs_params[1].xyz)))), ghost_fs_params[0].w);
+    }
+
+*/
+pub const GHOST_FRAG_SOURCE_GLSL430: [u8; 320] = [
+    0x23,0x76,0x65,0x72,0x73,0x69,0x6f,0x6e,0x20,0x34,0x33,0x30,0x0a,0x0a,0x75,0x6e,
+    0x69,0x66,0x6f,0x72,0x6d,0x20,0x76,0x65,0x63,0x34,0x20,0x67,0x68,0x6f,0x73,0x74,
+    0x5f,0x66,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x32,0x5d,0x3b,0x0a,0x6c,
+    0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x20,0x3d,
+    0x20,0x30,0x29,0x20,0x69,0x6e,0x20,0x76,0x65,0x63,0x33,0x20,0x66,0x5f,0x6e,0x6f,
+    0x72,0x6d,0x61,0x6c,0x3b,0x0a,0x6c,0x61,0x79,0x6f,0x75,0x74,0x28,0x6c,0x6f,0x63,
+    0x61,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x30,0x29,0x20,0x6f,0x75,0x74,0x20,0x76,
+    0x65,0x63,0x34,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,0x0a,0x76,0x6f,0x69,0x64,
+    0x20,0x6d,0x61,0x69,0x6e,0x28,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x63,0x6f,
+    0x6c,0x6f,0x72,0x20,0x3d,0x20,0x76,0x65,0x63,0x34,0x28,0x67,0x68,0x6f,0x73,0x74,
+    0x5f,0x66,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x30,0x5d,0x2e,0x78,0x79,
+    0x7a,0x20,0x2a,0x20,0x28,0x67,0x68,0x6f,0x73,0x74,0x5f,0x66,0x73,0x5f,0x70,0x61,
+    0x72,0x61,0x6d,0x73,0x5b,0x31,0x5d,0x2e,0x77,0x20,0x2b,0x20,0x28,0x28,0x31,0x2e,
+    0x30,0x20,0x2d,0x20,0x67,0x68,0x6f,0x73,0x74,0x5f,0x66,0x73,0x5f,0x70,0x61,0x72,
+    0x61,0x6d,0x73,0x5b,0x31,0x5d,0x2e,0x77,0x29,0x20,0x2a,0x20,0x61,0x62,0x73,0x28,
+    0x64,0x6f,0x74,0x28,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x69,0x7a,0x65,0x28,0x66,0x5f,
+    0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x29,0x2c,0x20,0x67,0x68,0x6f,0x73,0x74,0x5f,0x66,
+    0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x5b,0x31,0x5d,0x2e,0x78,0x79,0x7a,0x29,
+    0x29,0x29,0x29,0x2c,0x20,0x67,0x68,0x6f,0x73,0x74,0x5f,0x66,0x73,0x5f,0x70,0x61,
+    0x72,0x61,0x6d,0x73,0x5b,0x30,0x5d,0x2e,0x77,0x29,0x3b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+/*
+    cbuffer ghost_vs_params : register(b0)
+    {
+        row_major float4x4 _19_model : packoffset(c0);
+        row_major float4x4 _19_view_projection : packoffset(c4);
+    };
+
+
+    static float4 gl_Position;
+    static float3 position;
+    static float3 f_normal;
+    static float3 v_normal;
+
+    struct SPIRV_Cross_Input
+    {
+        float3 position : TEXCOORD0;
+        float3 v_normal : TEXCOORD1;
+    };
+
+    struct SPIRV_Cross_Output
+    {
+        float3 f_normal : TEXCOORD0;
+        float4 gl_Position : SV_Position;
+    };
+
+    void vert_main()
+    {
+        gl_Position = mul(float4(position, 1.0f), mul(_19_model, _19_view_projection));
+        f_normal = mul(v_normal, float3x3(_19_model[0].xyz, _19_model[1].xyz, _19_model[2].xyz));
+    }
+
+    SPIRV_Cross_Output main(SPIRV_Cross_Input stage_input)
+    {
+        position = stage_input.position;
+        v_normal = stage_input.v_normal;
+        vert_main();
+        SPIRV_Cross_Output stage_output;
+        stage_output.gl_Position = gl_Position;
+        stage_output.f_normal = f_normal;
+        return stage_output;
+    }
+*/
+pub const GHOST_VERTEX_SOURCE_HLSL5: [u8; 953] = [
+    0x63,0x62,0x75,0x66,0x66,0x65,0x72,0x20,0x67,0x68,0x6f,0x73,0x74,0x5f,0x76,0x73,
+    0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x20,0x3a,0x20,0x72,0x65,0x67,0x69,0x73,0x74,
+    0x65,0x72,0x28,0x62,0x30,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x72,0x6f,0x77,
+    0x5f,0x6d,0x61,0x6a,0x6f,0x72,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x78,0x34,0x20,
+    0x5f,0x31,0x39,0x5f,0x6d,0x6f,0x64,0x65,0x6c,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,
+    0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,0x30,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,
+    0x72,0x6f,0x77,0x5f,0x6d,0x61,0x6a,0x6f,0x72,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,
+    0x78,0x34,0x20,0x5f,0x31,0x39,0x5f,0x76,0x69,0x65,0x77,0x5f,0x70,0x72,0x6f,0x6a,
+    0x65,0x63,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,
+    0x73,0x65,0x74,0x28,0x63,0x34,0x29,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,0x0a,0x73,0x74,
+    0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x67,0x6c,0x5f,0x50,
+    0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,
+    0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,
+    0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x66,
+    0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x3b,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,
+    0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x76,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x3b,
+    0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,
+    0x72,0x6f,0x73,0x73,0x5f,0x49,0x6e,0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,0x20,0x20,
+    0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,
+    0x20,0x3a,0x20,0x54,0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,0x30,0x3b,0x0a,0x20,0x20,
+    0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x76,0x5f,0x6e,0x6f,0x72,0x6d,0x61,
+    0x6c,0x20,0x3a,0x20,0x54,0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,0x31,0x3b,0x0a,0x7d,
+    0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,
+    0x43,0x72,0x6f,0x73,0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,
+    0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,
+    0x61,0x6c,0x20,0x3a,0x20,0x54,0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,0x30,0x3b,0x0a,
+    0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x67,0x6c,0x5f,0x50,0x6f,
+    0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x53,0x56,0x5f,0x50,0x6f,0x73,0x69,
+    0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x7d,0x3b,0x0a,0x0a,0x76,0x6f,0x69,0x64,0x20,0x76,
+    0x65,0x72,0x74,0x5f,0x6d,0x61,0x69,0x6e,0x28,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,
+    0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x6d,
+    0x75,0x6c,0x28,0x66,0x6c,0x6f,0x61,0x74,0x34,0x28,0x70,0x6f,0x73,0x69,0x74,0x69,
+    0x6f,0x6e,0x2c,0x20,0x31,0x2e,0x30,0x66,0x29,0x2c,0x20,0x6d,0x75,0x6c,0x28,0x5f,
+    0x31,0x39,0x5f,0x6d,0x6f,0x64,0x65,0x6c,0x2c,0x20,0x5f,0x31,0x39,0x5f,0x76,0x69,
+    0x65,0x77,0x5f,0x70,0x72,0x6f,0x6a,0x65,0x63,0x74,0x69,0x6f,0x6e,0x29,0x29,0x3b,
+    0x0a,0x20,0x20,0x20,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3d,0x20,
+    0x6d,0x75,0x6c,0x28,0x76,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x2c,0x20,0x66,0x6c,
+    0x6f,0x61,0x74,0x33,0x78,0x33,0x28,0x5f,0x31,0x39,0x5f,0x6d,0x6f,0x64,0x65,0x6c,
+    0x5b,0x30,0x5d,0x2e,0x78,0x79,0x7a,0x2c,0x20,0x5f,0x31,0x39,0x5f,0x6d,0x6f,0x64,
+    0x65,0x6c,0x5b,0x31,0x5d,0x2e,0x78,0x79,0x7a,0x2c,0x20,0x5f,0x31,0x39,0x5f,0x6d,
+    0x6f,0x64,0x65,0x6c,0x5b,0x32,0x5d,0x2e,0x78,0x79,0x7a,0x29,0x29,0x3b,0x0a,0x7d,
+    0x0a,0x0a,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x4f,0x75,
+    0x74,0x70,0x75,0x74,0x20,0x6d,0x61,0x69,0x6e,0x28,0x53,0x50,0x49,0x52,0x56,0x5f,
+    0x43,0x72,0x6f,0x73,0x73,0x5f,0x49,0x6e,0x70,0x75,0x74,0x20,0x73,0x74,0x61,0x67,
+    0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x70,
+    0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,
+    0x69,0x6e,0x70,0x75,0x74,0x2e,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,
+    0x20,0x20,0x20,0x20,0x76,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3d,0x20,0x73,
+    0x74,0x61,0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x2e,0x76,0x5f,0x6e,0x6f,0x72,
+    0x6d,0x61,0x6c,0x3b,0x0a,0x20,0x20,0x20,0x20,0x76,0x65,0x72,0x74,0x5f,0x6d,0x61,
+    0x69,0x6e,0x28,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,
+    0x43,0x72,0x6f,0x73,0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x20,0x73,0x74,0x61,
+    0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x3b,0x0a,0x20,0x20,0x20,0x20,0x73,
+    0x74,0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x2e,0x67,0x6c,0x5f,0x50,
+    0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,
+    0x69,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x20,0x20,0x73,0x74,0x61,0x67,0x65,
+    0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x2e,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,
+    0x20,0x3d,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x3b,0x0a,0x20,0x20,0x20,
+    0x20,0x72,0x65,0x74,0x75,0x72,0x6e,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x6f,0x75,
+    0x74,0x70,0x75,0x74,0x3b,0x0a,0x7d,0x0a,0x00,
+];
+/*
+    cbuffer ghost_fs_params : register(b1)
+    {
+        float4 _25_ghost_color : packoffset(c0);
+        float3 _25_light_direction : packoffset(c1);
+        float _25_ambient : packoffset(c1.w);
+    };
+
+
+    static float3 f_normal;
+    static float4 color;
+
+    struct SPIRV_Cross_Input
+    {
+        float3 f_normal : TEXCOORD0;
+    };
+
+    struct SPIRV_Cross_Output
+    {
+        float4 color : SV_Target0;
+    };
+
+    void frag_main()
+    {
+        color = float4(_25_ghost_color.xyz * (_25_ambient + ((1.0f - _25_ambient) * abs(dot(normalize(f_normal), _25_light_direction)))), _25_ghost_color.w);
+    }
+
+    SPIRV_Cross_Output main(SPIRV_Cross_Input stage_input)
+    {
+        f_normal = stage_input.f_normal;
+        frag_main();
+        SPIRV_Cross_Output stage_output;
+        stage_output.color = color;
+        return stage_output;
+    }
+*/
+pub const GHOST_FRAG_SOURCE_HLSL5: [u8; 739] = [
+    0x63,0x62,0x75,0x66,0x66,0x65,0x72,0x20,0x67,0x68,0x6f,0x73,0x74,0x5f,0x66,0x73,
+    0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x20,0x3a,0x20,0x72,0x65,0x67,0x69,0x73,0x74,
+    0x65,0x72,0x28,0x62,0x31,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,
+    0x61,0x74,0x34,0x20,0x5f,0x32,0x35,0x5f,0x67,0x68,0x6f,0x73,0x74,0x5f,0x63,0x6f,
+    0x6c,0x6f,0x72,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,
+    0x28,0x63,0x30,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,
+    0x20,0x5f,0x32,0x35,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x64,0x69,0x72,0x65,0x63,
+    0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x70,0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,
+    0x74,0x28,0x63,0x31,0x29,0x3b,0x0a,0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,
+    0x20,0x5f,0x32,0x35,0x5f,0x61,0x6d,0x62,0x69,0x65,0x6e,0x74,0x20,0x3a,0x20,0x70,
+    0x61,0x63,0x6b,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x63,0x31,0x2e,0x77,0x29,0x3b,
+    0x0a,0x7d,0x3b,0x0a,0x0a,0x0a,0x73,0x74,0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,
+    0x61,0x74,0x33,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x3b,0x0a,0x73,0x74,
+    0x61,0x74,0x69,0x63,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x63,0x6f,0x6c,0x6f,
+    0x72,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,0x53,0x50,0x49,0x52,0x56,
+    0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x49,0x6e,0x70,0x75,0x74,0x0a,0x7b,0x0a,0x20,
+    0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x33,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,
+    0x61,0x6c,0x20,0x3a,0x20,0x54,0x45,0x58,0x43,0x4f,0x4f,0x52,0x44,0x30,0x3b,0x0a,
+    0x7d,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,0x53,0x50,0x49,0x52,0x56,
+    0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x0a,0x7b,0x0a,
+    0x20,0x20,0x20,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x20,0x63,0x6f,0x6c,0x6f,0x72,
+    0x20,0x3a,0x20,0x53,0x56,0x5f,0x54,0x61,0x72,0x67,0x65,0x74,0x30,0x3b,0x0a,0x7d,
+    0x3b,0x0a,0x0a,0x76,0x6f,0x69,0x64,0x20,0x66,0x72,0x61,0x67,0x5f,0x6d,0x61,0x69,
+    0x6e,0x28,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x20,
+    0x3d,0x20,0x66,0x6c,0x6f,0x61,0x74,0x34,0x28,0x5f,0x32,0x35,0x5f,0x67,0x68,0x6f,
+    0x73,0x74,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x2e,0x78,0x79,0x7a,0x20,0x2a,0x20,0x28,
+    0x5f,0x32,0x35,0x5f,0x61,0x6d,0x62,0x69,0x65,0x6e,0x74,0x20,0x2b,0x20,0x28,0x28,
+    0x31,0x2e,0x30,0x66,0x20,0x2d,0x20,0x5f,0x32,0x35,0x5f,0x61,0x6d,0x62,0x69,0x65,
+    0x6e,0x74,0x29,0x20,0x2a,0x20,0x61,0x62,0x73,0x28,0x64,0x6f,0x74,0x28,0x6e,0x6f,
+    0x72,0x6d,0x61,0x6c,0x69,0x7a,0x65,0x28,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,
+    0x29,0x2c,0x20,0x5f,0x32,0x35,0x5f,0x6c,0x69,0x67,0x68,0x74,0x5f,0x64,0x69,0x72,
+    0x65,0x63,0x74,0x69,0x6f,0x6e,0x29,0x29,0x29,0x29,0x2c,0x20,0x5f,0x32,0x35,0x5f,
+    0x67,0x68,0x6f,0x73,0x74,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x2e,0x77,0x29,0x3b,0x0a,
+    0x7d,0x0a,0x0a,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x4f,
+    0x75,0x74,0x70,0x75,0x74,0x20,0x6d,0x61,0x69,0x6e,0x28,0x53,0x50,0x49,0x52,0x56,
+    0x5f,0x43,0x72,0x6f,0x73,0x73,0x5f,0x49,0x6e,0x70,0x75,0x74,0x20,0x73,0x74,0x61,
+    0x67,0x65,0x5f,0x69,0x6e,0x70,0x75,0x74,0x29,0x0a,0x7b,0x0a,0x20,0x20,0x20,0x20,
+    0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3d,0x20,0x73,0x74,0x61,0x67,0x65,
+    0x5f,0x69,0x6e,0x70,0x75,0x74,0x2e,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x3b,
+    0x0a,0x20,0x20,0x20,0x20,0x66,0x72,0x61,0x67,0x5f,0x6d,0x61,0x69,0x6e,0x28,0x29,
+    0x3b,0x0a,0x20,0x20,0x20,0x20,0x53,0x50,0x49,0x52,0x56,0x5f,0x43,0x72,0x6f,0x73,
+    0x73,0x5f,0x4f,0x75,0x74,0x70,0x75,0x74,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x6f,
+    0x75,0x74,0x70,0x75,0x74,0x3b,0x0a,0x20,0x20,0x20,0x20,0x73,0x74,0x61,0x67,0x65,
+    0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x2e,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3d,0x20,
+    0x63,0x6f,0x6c,0x6f,0x72,0x3b,0x0a,0x20,0x20,0x20,0x20,0x72,0x65,0x74,0x75,0x72,
+    0x6e,0x20,0x73,0x74,0x61,0x67,0x65,0x5f,0x6f,0x75,0x74,0x70,0x75,0x74,0x3b,0x0a,
+    0x7d,0x0a,0x00,
+];
+/*
+    diagnostic(off, derivative_uniformity);
+
+    struct ghost_vs_params {
+      /_ @offset(0) _/
+      model : mat4x4f,
+      /_ @offset(64) _/
+      view_projection : mat4x4f,
+    }
+
+    @group(0) @binding(0) var<uniform> x_19 : ghost_vs_params;
+
+    var<private> position_1 : vec3f;
+
+    var<private> f_normal : vec3f;
+
+    var<private> v_normal : vec3f;
+
+    var<private> gl_Position : vec4f;
+
+    fn main_1() {
+      let x_21 : mat4x4f = x_19.model;
+      let x_25 : mat4x4f = x_19.view_projection;
+      let x_30 : vec3f = position_1;
+      gl_Position = ((x_25 * x_21) * vec4f(x_30.x, x_30.y, x_30.z, 1.0f));
+      let x_47 : vec3f = v_normal;
+      f_normal = (mat3x3f(x_21[0].xyz, x_21[1].xyz, x_21[2].xyz) * x_47);
+      return;
+    }
+
+    struct main_out {
+      @builtin(position)
+      gl_Position : vec4f,
+      @location(0)
+      f_normal_1 : vec3f,
+    }
+
+    @vertex
+    fn main(@location(0) position_1_param : vec3f, @location(1) v_normal_param : vec3f) -> main_out {
+      position_1 = position_1_param;
+      v_normal = v_normal_param;
+      main_1();
+      return main_out(gl_Position, f_normal);
+    }
+
+*/
+pub const GHOST_VERTEX_SOURCE_WGSL: [u8; 989] = [
+    0x64,0x69,0x61,0x67,0x6e,0x6f,0x73,0x74,0x69,0x63,0x28,0x6f,0x66,0x66,0x2c,0x20,
+    0x64,0x65,0x72,0x69,0x76,0x61,0x74,0x69,0x76,0x65,0x5f,0x75,0x6e,0x69,0x66,0x6f,
+    0x72,0x6d,0x69,0x74,0x79,0x29,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,
+    0x67,0x68,0x6f,0x73,0x74,0x5f,0x76,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x20,
+    0x7b,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x30,
+    0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,0x6d,0x6f,0x64,0x65,0x6c,0x20,0x3a,0x20,0x6d,
+    0x61,0x74,0x34,0x78,0x34,0x66,0x2c,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,
+    0x66,0x73,0x65,0x74,0x28,0x36,0x34,0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,0x76,0x69,
+    0x65,0x77,0x5f,0x70,0x72,0x6f,0x6a,0x65,0x63,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,
+    0x6d,0x61,0x74,0x34,0x78,0x34,0x66,0x2c,0x0a,0x7d,0x0a,0x0a,0x40,0x67,0x72,0x6f,
+    0x75,0x70,0x28,0x30,0x29,0x20,0x40,0x62,0x69,0x6e,0x64,0x69,0x6e,0x67,0x28,0x30,
+    0x29,0x20,0x76,0x61,0x72,0x3c,0x75,0x6e,0x69,0x66,0x6f,0x72,0x6d,0x3e,0x20,0x78,
+    0x5f,0x31,0x39,0x20,0x3a,0x20,0x67,0x68,0x6f,0x73,0x74,0x5f,0x76,0x73,0x5f,0x70,
+    0x61,0x72,0x61,0x6d,0x73,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,0x69,0x76,
+    0x61,0x74,0x65,0x3e,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x20,
+    0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,
+    0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,
+    0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,
+    0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x76,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,
+    0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x3b,0x0a,0x0a,0x76,0x61,0x72,0x3c,0x70,0x72,
+    0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,
+    0x6f,0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x3b,0x0a,0x0a,0x66,0x6e,0x20,
+    0x6d,0x61,0x69,0x6e,0x5f,0x31,0x28,0x29,0x20,0x7b,0x0a,0x20,0x20,0x6c,0x65,0x74,
+    0x20,0x78,0x5f,0x32,0x31,0x20,0x3a,0x20,0x6d,0x61,0x74,0x34,0x78,0x34,0x66,0x20,
+    0x3d,0x20,0x78,0x5f,0x31,0x39,0x2e,0x6d,0x6f,0x64,0x65,0x6c,0x3b,0x0a,0x20,0x20,
+    0x6c,0x65,0x74,0x20,0x78,0x5f,0x32,0x35,0x20,0x3a,0x20,0x6d,0x61,0x74,0x34,0x78,
+    0x34,0x66,0x20,0x3d,0x20,0x78,0x5f,0x31,0x39,0x2e,0x76,0x69,0x65,0x77,0x5f,0x70,
+    0x72,0x6f,0x6a,0x65,0x63,0x74,0x69,0x6f,0x6e,0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,
+    0x20,0x78,0x5f,0x33,0x30,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x20,0x3d,0x20,
+    0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x3b,0x0a,0x20,0x20,0x67,0x6c,
+    0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3d,0x20,0x28,0x28,0x78,0x5f,
+    0x32,0x35,0x20,0x2a,0x20,0x78,0x5f,0x32,0x31,0x29,0x20,0x2a,0x20,0x76,0x65,0x63,
+    0x34,0x66,0x28,0x78,0x5f,0x33,0x30,0x2e,0x78,0x2c,0x20,0x78,0x5f,0x33,0x30,0x2e,
+    0x79,0x2c,0x20,0x78,0x5f,0x33,0x30,0x2e,0x7a,0x2c,0x20,0x31,0x2e,0x30,0x66,0x29,
+    0x29,0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x34,0x37,0x20,0x3a,0x20,
+    0x76,0x65,0x63,0x33,0x66,0x20,0x3d,0x20,0x76,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,
+    0x3b,0x0a,0x20,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x20,0x3d,0x20,0x28,
+    0x6d,0x61,0x74,0x33,0x78,0x33,0x66,0x28,0x78,0x5f,0x32,0x31,0x5b,0x30,0x5d,0x2e,
+    0x78,0x79,0x7a,0x2c,0x20,0x78,0x5f,0x32,0x31,0x5b,0x31,0x5d,0x2e,0x78,0x79,0x7a,
+    0x2c,0x20,0x78,0x5f,0x32,0x31,0x5b,0x32,0x5d,0x2e,0x78,0x79,0x7a,0x29,0x20,0x2a,
+    0x20,0x78,0x5f,0x34,0x37,0x29,0x3b,0x0a,0x20,0x20,0x72,0x65,0x74,0x75,0x72,0x6e,
+    0x3b,0x0a,0x7d,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,0x6d,0x61,0x69,0x6e,
+    0x5f,0x6f,0x75,0x74,0x20,0x7b,0x0a,0x20,0x20,0x40,0x62,0x75,0x69,0x6c,0x74,0x69,
+    0x6e,0x28,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x29,0x0a,0x20,0x20,0x67,0x6c,
+    0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,
+    0x66,0x2c,0x0a,0x20,0x20,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x30,
+    0x29,0x0a,0x20,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x5f,0x31,0x20,0x3a,
+    0x20,0x76,0x65,0x63,0x33,0x66,0x2c,0x0a,0x7d,0x0a,0x0a,0x40,0x76,0x65,0x72,0x74,
+    0x65,0x78,0x0a,0x66,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x28,0x40,0x6c,0x6f,0x63,0x61,
+    0x74,0x69,0x6f,0x6e,0x28,0x30,0x29,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,
+    0x5f,0x31,0x5f,0x70,0x61,0x72,0x61,0x6d,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,
+    0x2c,0x20,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x31,0x29,0x20,0x76,
+    0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x5f,0x70,0x61,0x72,0x61,0x6d,0x20,0x3a,0x20,
+    0x76,0x65,0x63,0x33,0x66,0x29,0x20,0x2d,0x3e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,
+    0x75,0x74,0x20,0x7b,0x0a,0x20,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,
+    0x31,0x20,0x3d,0x20,0x70,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x5f,0x31,0x5f,0x70,
+    0x61,0x72,0x61,0x6d,0x3b,0x0a,0x20,0x20,0x76,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,
+    0x20,0x3d,0x20,0x76,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x5f,0x70,0x61,0x72,0x61,
+    0x6d,0x3b,0x0a,0x20,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x31,0x28,0x29,0x3b,0x0a,0x20,
+    0x20,0x72,0x65,0x74,0x75,0x72,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,0x74,
+    0x28,0x67,0x6c,0x5f,0x50,0x6f,0x73,0x69,0x74,0x69,0x6f,0x6e,0x2c,0x20,0x66,0x5f,
+    0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x29,0x3b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+/*
+    diagnostic(off, derivative_uniformity);
+
+    struct ghost_fs_params {
+      /_ @offset(0) _/
+      ghost_color : vec4f,
+      /_ @offset(16) _/
+      light_direction : vec3f,
+      /_ @offset(28) _/
+      ambient : f32,
+    }
+
+    @group(0) @binding(1) var<uniform> x_25 : ghost_fs_params;
+
+    var<private> f_normal : vec3f;
+
+    var<private> color : vec4f;
+
+    fn main_1() {
+      let x_20 : f32 = abs(dot(normalize(f_normal), x_25.light_direction));
+      let x_35 : f32 = x_25.ambient;
+      let x_44 : vec3f = (x_25.ghost_color.xyz * (x_35 + ((1.0f - x_35) * x_20)));
+      color = vec4f(x_44.x, x_44.y, x_44.z, x_25.ghost_color.w);
+      return;
+    }
+
+    struct main_out {
+      @location(0)
+      color_1 : vec4f,
+    }
+
+    @fragment
+    fn main(@location(0) f_normal_param : vec3f) -> main_out {
+      f_normal = f_normal_param;
+      main_1();
+      return main_out(color);
+    }
+
+*/
+pub const GHOST_FRAG_SOURCE_WGSL: [u8; 783] = [
+    0x64,0x69,0x61,0x67,0x6e,0x6f,0x73,0x74,0x69,0x63,0x28,0x6f,0x66,0x66,0x2c,0x20,
+    0x64,0x65,0x72,0x69,0x76,0x61,0x74,0x69,0x76,0x65,0x5f,0x75,0x6e,0x69,0x66,0x6f,
+    0x72,0x6d,0x69,0x74,0x79,0x29,0x3b,0x0a,0x0a,0x73,0x74,0x72,0x75,0x63,0x74,0x20,
+    0x67,0x68,0x6f,0x73,0x74,0x5f,0x66,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x20,
+    0x7b,0x0a,0x20,0x20,0x2f,0x2a,0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x30,
+    0x29,0x20,0x2a,0x2f,0x0a,0x20,0x20,0x67,0x68,0x6f,0x73,0x74,0x5f,0x63,0x6f,0x6c,
+    0x6f,0x72,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x2c,0x0a,0x20,0x20,0x2f,0x2a,
+    0x20,0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x31,0x36,0x29,0x20,0x2a,0x2f,0x0a,
+    0x20,0x20,0x6c,0x69,0x67,0x68,0x74,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,
+    0x6e,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x2c,0x0a,0x20,0x20,0x2f,0x2a,0x20,
+    0x40,0x6f,0x66,0x66,0x73,0x65,0x74,0x28,0x32,0x38,0x29,0x20,0x2a,0x2f,0x0a,0x20,
+    0x20,0x61,0x6d,0x62,0x69,0x65,0x6e,0x74,0x20,0x3a,0x20,0x66,0x33,0x32,0x2c,0x0a,
+    0x7d,0x0a,0x0a,0x40,0x67,0x72,0x6f,0x75,0x70,0x28,0x30,0x29,0x20,0x40,0x62,0x69,
+    0x6e,0x64,0x69,0x6e,0x67,0x28,0x31,0x29,0x20,0x76,0x61,0x72,0x3c,0x75,0x6e,0x69,
+    0x66,0x6f,0x72,0x6d,0x3e,0x20,0x78,0x5f,0x32,0x35,0x20,0x3a,0x20,0x67,0x68,0x6f,
+    0x73,0x74,0x5f,0x66,0x73,0x5f,0x70,0x61,0x72,0x61,0x6d,0x73,0x3b,0x0a,0x0a,0x76,
+    0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x66,0x5f,0x6e,0x6f,
+    0x72,0x6d,0x61,0x6c,0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x3b,0x0a,0x0a,0x76,
+    0x61,0x72,0x3c,0x70,0x72,0x69,0x76,0x61,0x74,0x65,0x3e,0x20,0x63,0x6f,0x6c,0x6f,
+    0x72,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x3b,0x0a,0x0a,0x66,0x6e,0x20,0x6d,
+    0x61,0x69,0x6e,0x5f,0x31,0x28,0x29,0x20,0x7b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,
+    0x78,0x5f,0x32,0x30,0x20,0x3a,0x20,0x66,0x33,0x32,0x20,0x3d,0x20,0x61,0x62,0x73,
+    0x28,0x64,0x6f,0x74,0x28,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x69,0x7a,0x65,0x28,0x66,
+    0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x29,0x2c,0x20,0x78,0x5f,0x32,0x35,0x2e,0x6c,
+    0x69,0x67,0x68,0x74,0x5f,0x64,0x69,0x72,0x65,0x63,0x74,0x69,0x6f,0x6e,0x29,0x29,
+    0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x33,0x35,0x20,0x3a,0x20,0x66,
+    0x33,0x32,0x20,0x3d,0x20,0x78,0x5f,0x32,0x35,0x2e,0x61,0x6d,0x62,0x69,0x65,0x6e,
+    0x74,0x3b,0x0a,0x20,0x20,0x6c,0x65,0x74,0x20,0x78,0x5f,0x34,0x34,0x20,0x3a,0x20,
+    0x76,0x65,0x63,0x33,0x66,0x20,0x3d,0x20,0x28,0x78,0x5f,0x32,0x35,0x2e,0x67,0x68,
+    0x6f,0x73,0x74,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x2e,0x78,0x79,0x7a,0x20,0x2a,0x20,
+    0x28,0x78,0x5f,0x33,0x35,0x20,0x2b,0x20,0x28,0x28,0x31,0x2e,0x30,0x66,0x20,0x2d,
+    0x20,0x78,0x5f,0x33,0x35,0x29,0x20,0x2a,0x20,0x78,0x5f,0x32,0x30,0x29,0x29,0x29,
+    0x3b,0x0a,0x20,0x20,0x63,0x6f,0x6c,0x6f,0x72,0x20,0x3d,0x20,0x76,0x65,0x63,0x34,
+    0x66,0x28,0x78,0x5f,0x34,0x34,0x2e,0x78,0x2c,0x20,0x78,0x5f,0x34,0x34,0x2e,0x79,
+    0x2c,0x20,0x78,0x5f,0x34,0x34,0x2e,0x7a,0x2c,0x20,0x78,0x5f,0x32,0x35,0x2e,0x67,
+    0x68,0x6f,0x73,0x74,0x5f,0x63,0x6f,0x6c,0x6f,0x72,0x2e,0x77,0x29,0x3b,0x0a,0x20,
+    0x20,0x72,0x65,0x74,0x75,0x72,0x6e,0x3b,0x0a,0x7d,0x0a,0x0a,0x73,0x74,0x72,0x75,
+    0x63,0x74,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,0x75,0x74,0x20,0x7b,0x0a,0x20,0x20,
+    0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x30,0x29,0x0a,0x20,0x20,0x63,
+    0x6f,0x6c,0x6f,0x72,0x5f,0x31,0x20,0x3a,0x20,0x76,0x65,0x63,0x34,0x66,0x2c,0x0a,
+    0x7d,0x0a,0x0a,0x40,0x66,0x72,0x61,0x67,0x6d,0x65,0x6e,0x74,0x0a,0x66,0x6e,0x20,
+    0x6d,0x61,0x69,0x6e,0x28,0x40,0x6c,0x6f,0x63,0x61,0x74,0x69,0x6f,0x6e,0x28,0x30,
+    0x29,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x5f,0x70,0x61,0x72,0x61,0x6d,
+    0x20,0x3a,0x20,0x76,0x65,0x63,0x33,0x66,0x29,0x20,0x2d,0x3e,0x20,0x6d,0x61,0x69,
+    0x6e,0x5f,0x6f,0x75,0x74,0x20,0x7b,0x0a,0x20,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,
+    0x61,0x6c,0x20,0x3d,0x20,0x66,0x5f,0x6e,0x6f,0x72,0x6d,0x61,0x6c,0x5f,0x70,0x61,
+    0x72,0x61,0x6d,0x3b,0x0a,0x20,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x31,0x28,0x29,0x3b,
+    0x0a,0x20,0x20,0x72,0x65,0x74,0x75,0x72,0x6e,0x20,0x6d,0x61,0x69,0x6e,0x5f,0x6f,
+    0x75,0x74,0x28,0x63,0x6f,0x6c,0x6f,0x72,0x29,0x3b,0x0a,0x7d,0x0a,0x0a,0x00,
+];
+pub fn texture_shader_desc(backend: sg::Backend) -> sg::ShaderDesc {
+    let mut desc = sg::ShaderDesc::new();
+    desc.label = c"texture_shader".as_ptr();
+    match backend {
+        sg::Backend::Glcore => {
+            desc.vertex_func.source = &TEXTURE_VERTEX_SOURCE_GLSL430 as *const _ as *const _;
+            desc.vertex_func.entry = c"main".as_ptr();
+            desc.fragment_func.source = &TEXTURE_FRAG_SOURCE_GLSL430 as *const _ as *const _;
+            desc.fragment_func.entry = c"main".as_ptr();
+            desc.attrs[0].glsl_name = c"position".as_ptr();
+            desc.attrs[1].glsl_name = c"v_normal".as_ptr();
+            desc.attrs[2].glsl_name = c"v_texcoord".as_ptr();
+            desc.uniform_blocks[0].stage = sg::ShaderStage::Vertex;
+            desc.uniform_blocks[0].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[0].size = 192;
+            desc.uniform_blocks[0].glsl_uniforms[0]._type = sg::UniformType::Float4;
+            desc.uniform_blocks[0].glsl_uniforms[0].array_count = 12;
+            desc.uniform_blocks[0].glsl_uniforms[0].glsl_name = c"vs_params".as_ptr();
+            desc.uniform_blocks[1].stage = sg::ShaderStage::Fragment;
+            desc.uniform_blocks[1].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[1].size = 64;
+            desc.uniform_blocks[1].glsl_uniforms[0]._type = sg::UniformType::Float4;
+            desc.uniform_blocks[1].glsl_uniforms[0].array_count = 4;
+            desc.uniform_blocks[1].glsl_uniforms[0].glsl_name = c"fs_params".as_ptr();
+            desc.images[0].stage = sg::ShaderStage::Fragment;
+            desc.images[0].multisampled = false;
+            desc.images[0].image_type = sg::ImageType::Dim2;
+            desc.images[0].sample_type = sg::ImageSampleType::Float;
+            desc.samplers[1].stage = sg::ShaderStage::Fragment;
+            desc.samplers[1].sampler_type = sg::SamplerType::Filtering;
+            desc.image_sampler_pairs[0].stage = sg::ShaderStage::Fragment;
+            desc.image_sampler_pairs[0].image_slot = 0;
+            desc.image_sampler_pairs[0].sampler_slot = 1;
+            desc.image_sampler_pairs[0].glsl_name = c"tex_samp".as_ptr();
+        },
+        sg::Backend::D3d11 => {
+            desc.vertex_func.source = &TEXTURE_VERTEX_SOURCE_HLSL5 as *const _ as *const _;
+            desc.vertex_func.d3d11_target = c"vs_5_0".as_ptr();
+            desc.vertex_func.entry = c"main".as_ptr();
+            desc.fragment_func.source = &TEXTURE_FRAG_SOURCE_HLSL5 as *const _ as *const _;
+            desc.fragment_func.d3d11_target = c"ps_5_0".as_ptr();
+            desc.fragment_func.entry = c"main".as_ptr();
+            desc.attrs[0].hlsl_sem_name = c"TEXCOORD".as_ptr();
+            desc.attrs[0].hlsl_sem_index = 0;
+            desc.attrs[1].hlsl_sem_name = c"TEXCOORD".as_ptr();
+            desc.attrs[1].hlsl_sem_index = 1;
+            desc.attrs[2].hlsl_sem_name = c"TEXCOORD".as_ptr();
+            desc.attrs[2].hlsl_sem_index = 2;
+            desc.uniform_blocks[0].stage = sg::ShaderStage::Vertex;
+            desc.uniform_blocks[0].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[0].size = 192;
+            desc.uniform_blocks[0].hlsl_register_b_n = 0;
+            desc.uniform_blocks[1].stage = sg::ShaderStage::Fragment;
+            desc.uniform_blocks[1].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[1].size = 64;
+            desc.uniform_blocks[1].hlsl_register_b_n = 1;
+            desc.images[0].stage = sg::ShaderStage::Fragment;
+            desc.images[0].multisampled = false;
+            desc.images[0].image_type = sg::ImageType::Dim2;
+            desc.images[0].sample_type = sg::ImageSampleType::Float;
+            desc.images[0].hlsl_register_t_n = 0;
+            desc.samplers[1].stage = sg::ShaderStage::Fragment;
+            desc.samplers[1].sampler_type = sg::SamplerType::Filtering;
+            desc.samplers[1].hlsl_register_s_n = 0;
+            desc.image_sampler_pairs[0].stage = sg::ShaderStage::Fragment;
+            desc.image_sampler_pairs[0].image_slot = 0;
+            desc.image_sampler_pairs[0].sampler_slot = 1;
+        },
+        sg::Backend::Wgpu => {
+            desc.vertex_func.source = &TEXTURE_VERTEX_SOURCE_WGSL as *const _ as *const _;
+            desc.vertex_func.entry = c"main".as_ptr();
+            desc.fragment_func.source = &TEXTURE_FRAG_SOURCE_WGSL as *const _ as *const _;
+            desc.fragment_func.entry = c"main".as_ptr();
+            desc.uniform_blocks[0].stage = sg::ShaderStage::Vertex;
+            desc.uniform_blocks[0].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[0].size = 192;
+            desc.uniform_blocks[0].wgsl_group0_binding_n = 0;
+            desc.uniform_blocks[1].stage = sg::ShaderStage::Fragment;
+            desc.uniform_blocks[1].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[1].size = 64;
+            desc.uniform_blocks[1].wgsl_group0_binding_n = 1;
+            desc.images[0].stage = sg::ShaderStage::Fragment;
+            desc.images[0].multisampled = false;
+            desc.images[0].image_type = sg::ImageType::Dim2;
+            desc.images[0].sample_type = sg::ImageSampleType::Float;
+            desc.images[0].wgsl_group1_binding_n = 64;
+            desc.samplers[1].stage = sg::ShaderStage::Fragment;
+            desc.samplers[1].sampler_type = sg::SamplerType::Filtering;
+            desc.samplers[1].wgsl_group1_binding_n = 80;
+            desc.image_sampler_pairs[0].stage = sg::ShaderStage::Fragment;
+            desc.image_sampler_pairs[0].image_slot = 0;
+            desc.image_sampler_pairs[0].sampler_slot = 1;
+        },
+        _ => {},
+    }
+    desc
+}
+pub fn shadow_shader_desc(backend: sg::Backend) -> sg::ShaderDesc {
+    let mut desc = sg::ShaderDesc::new();
+    desc.label = c"shadow_shader".as_ptr();
+    match backend {
+        sg::Backend::Glcore => {
+            desc.vertex_func.source = &SHADOW_VERTEX_SOURCE_GLSL430 as *const _ as *const _;
+            desc.vertex_func.entry = c"main".as_ptr();
+            desc.fragment_func.source = &SHADOW_FRAG_SOURCE_GLSL430 as *const _ as *const _;
+            desc.fragment_func.entry = c"main".as_ptr();
+            desc.attrs[0].glsl_name = c"position".as_ptr();
+            desc.uniform_blocks[0].stage = sg::ShaderStage::Vertex;
+            desc.uniform_blocks[0].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[0].size = 64;
+            desc.uniform_blocks[0].glsl_uniforms[0]._type = sg::UniformType::Float4;
+            desc.uniform_blocks[0].glsl_uniforms[0].array_count = 4;
+            desc.uniform_blocks[0].glsl_uniforms[0].glsl_name = c"shadow_vs_params".as_ptr();
+        },
+        sg::Backend::D3d11 => {
+            desc.vertex_func.source = &SHADOW_VERTEX_SOURCE_HLSL5 as *const _ as *const _;
+            desc.vertex_func.d3d11_target = c"vs_5_0".as_ptr();
+            desc.vertex_func.entry = c"main".as_ptr();
+            desc.fragment_func.source = &SHADOW_FRAG_SOURCE_HLSL5 as *const _ as *const _;
+            desc.fragment_func.d3d11_target = c"ps_5_0".as_ptr();
+            desc.fragment_func.entry = c"main".as_ptr();
+            desc.attrs[0].hlsl_sem_name = c"TEXCOORD".as_ptr();
+            desc.attrs[0].hlsl_sem_index = 0;
+            desc.uniform_blocks[0].stage = sg::ShaderStage::Vertex;
+            desc.uniform_blocks[0].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[0].size = 64;
+            desc.uniform_blocks[0].hlsl_register_b_n = 0;
+        },
+        sg::Backend::Wgpu => {
+            desc.vertex_func.source = &SHADOW_VERTEX_SOURCE_WGSL as *const _ as *const _;
+            desc.vertex_func.entry = c"main".as_ptr();
+            desc.fragment_func.source = &SHADOW_FRAG_SOURCE_WGSL as *const _ as *const _;
+            desc.fragment_func.entry = c"main".as_ptr();
+            desc.uniform_blocks[0].stage = sg::ShaderStage::Vertex;
+            desc.uniform_blocks[0].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[0].size = 64;
+            desc.uniform_blocks[0].wgsl_group0_binding_n = 0;
+        },
+        _ => {},
+    }
+    desc
+}
+pub fn ground_shader_desc(backend: sg::Backend) -> sg::ShaderDesc {
+    let mut desc = sg::ShaderDesc::new();
+    desc.label = c"ground_shader".as_ptr();
+    match backend {
+        sg::Backend::Glcore => {
+            desc.vertex_func.source = &GROUND_VERTEX_SOURCE_GLSL430 as *const _ as *const _;
+            desc.vertex_func.entry = c"main".as_ptr();
+            desc.fragment_func.source = &GROUND_FRAG_SOURCE_GLSL430 as *const _ as *const _;
+            desc.fragment_func.entry = c"main".as_ptr();
+            desc.attrs[0].glsl_name = c"position".as_ptr();
+            desc.uniform_blocks[0].stage = sg::ShaderStage::Vertex;
+            desc.uniform_blocks[0].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[0].size = 160;
+            desc.uniform_blocks[0].glsl_uniforms[0]._type = sg::UniformType::Float4;
+            desc.uniform_blocks[0].glsl_uniforms[0].array_count = 10;
+            desc.uniform_blocks[0].glsl_uniforms[0].glsl_name = c"ground_vs_params".as_ptr();
+            desc.uniform_blocks[1].stage = sg::ShaderStage::Fragment;
+            desc.uniform_blocks[1].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[1].size = 64;
+            desc.uniform_blocks[1].glsl_uniforms[0]._type = sg::UniformType::Float4;
+            desc.uniform_blocks[1].glsl_uniforms[0].array_count = 4;
+            desc.uniform_blocks[1].glsl_uniforms[0].glsl_name = c"ground_fs_params".as_ptr();
+            desc.images[0].stage = sg::ShaderStage::Fragment;
+            desc.images[0].multisampled = false;
+            desc.images[0].image_type = sg::ImageType::Dim2;
+            desc.images[0].sample_type = sg::ImageSampleType::Depth;
+            desc.images[1].stage = sg::ShaderStage::Fragment;
+            desc.images[1].multisampled = false;
+            desc.images[1].image_type = sg::ImageType::Dim2;
+            desc.images[1].sample_type = sg::ImageSampleType::Float;
+            desc.samplers[0].stage = sg::ShaderStage::Fragment;
+            desc.samplers[0].sampler_type = sg::SamplerType::Comparison;
+            desc.samplers[1].stage = sg::ShaderStage::Fragment;
+            desc.samplers[1].sampler_type = sg::SamplerType::Filtering;
+            desc.image_sampler_pairs[0].stage = sg::ShaderStage::Fragment;
+            desc.image_sampler_pairs[0].image_slot = 0;
+            desc.image_sampler_pairs[0].sampler_slot = 0;
+            desc.image_sampler_pairs[0].glsl_name = c"shadow_map_shadow_sampler".as_ptr();
+            desc.image_sampler_pairs[1].stage = sg::ShaderStage::Fragment;
+            desc.image_sampler_pairs[1].image_slot = 1;
+            desc.image_sampler_pairs[1].sampler_slot = 1;
+            desc.image_sampler_pairs[1].glsl_name = c"grid_grid_sampler".as_ptr();
+        },
+        sg::Backend::D3d11 => {
+            desc.vertex_func.source = &GROUND_VERTEX_SOURCE_HLSL5 as *const _ as *const _;
+            desc.vertex_func.d3d11_target = c"vs_5_0".as_ptr();
+            desc.vertex_func.entry = c"main".as_ptr();
+            desc.fragment_func.source = &GROUND_FRAG_SOURCE_HLSL5 as *const _ as *const _;
+            desc.fragment_func.d3d11_target = c"ps_5_0".as_ptr();
+            desc.fragment_func.entry = c"main".as_ptr();
+            desc.attrs[0].hlsl_sem_name = c"TEXCOORD".as_ptr();
+            desc.attrs[0].hlsl_sem_index = 0;
+            desc.uniform_blocks[0].stage = sg::ShaderStage::Vertex;
+            desc.uniform_blocks[0].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[0].size = 160;
+            desc.uniform_blocks[0].hlsl_register_b_n = 0;
+            desc.uniform_blocks[1].stage = sg::ShaderStage::Fragment;
+            desc.uniform_blocks[1].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[1].size = 64;
+            desc.uniform_blocks[1].hlsl_register_b_n = 1;
+            desc.images[0].stage = sg::ShaderStage::Fragment;
+            desc.images[0].multisampled = false;
+            desc.images[0].image_type = sg::ImageType::Dim2;
+            desc.images[0].sample_type = sg::ImageSampleType::Depth;
+            desc.images[0].hlsl_register_t_n = 0;
+            desc.images[1].stage = sg::ShaderStage::Fragment;
+            desc.images[1].multisampled = false;
+            desc.images[1].image_type = sg::ImageType::Dim2;
+            desc.images[1].sample_type = sg::ImageSampleType::Float;
+            desc.images[1].hlsl_register_t_n = 1;
+            desc.samplers[0].stage = sg::ShaderStage::Fragment;
+            desc.samplers[0].sampler_type = sg::SamplerType::Comparison;
+            desc.samplers[0].hlsl_register_s_n = 0;
+            desc.samplers[1].stage = sg::ShaderStage::Fragment;
+            desc.samplers[1].sampler_type = sg::SamplerType::Filtering;
+            desc.samplers[1].hlsl_register_s_n = 1;
+            desc.image_sampler_pairs[0].stage = sg::ShaderStage::Fragment;
+            desc.image_sampler_pairs[0].image_slot = 0;
+            desc.image_sampler_pairs[0].sampler_slot = 0;
+            desc.image_sampler_pairs[1].stage = sg::ShaderStage::Fragment;
+            desc.image_sampler_pairs[1].image_slot = 1;
+            desc.image_sampler_pairs[1].sampler_slot = 1;
+        },
+        sg::Backend::Wgpu => {
+            desc.vertex_func.source = &GROUND_VERTEX_SOURCE_WGSL as *const _ as *const _;
+            desc.vertex_func.entry = c"main".as_ptr();
+            desc.fragment_func.source = &GROUND_FRAG_SOURCE_WGSL as *const _ as *const _;
+            desc.fragment_func.entry = c"main".as_ptr();
+            desc.uniform_blocks[0].stage = sg::ShaderStage::Vertex;
+            desc.uniform_blocks[0].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[0].size = 160;
+            desc.uniform_blocks[0].wgsl_group0_binding_n = 0;
+            desc.uniform_blocks[1].stage = sg::ShaderStage::Fragment;
+            desc.uniform_blocks[1].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[1].size = 64;
+            desc.uniform_blocks[1].wgsl_group0_binding_n = 1;
+            desc.images[0].stage = sg::ShaderStage::Fragment;
+            desc.images[0].multisampled = false;
+            desc.images[0].image_type = sg::ImageType::Dim2;
+            desc.images[0].sample_type = sg::ImageSampleType::Depth;
+            desc.images[0].wgsl_group1_binding_n = 64;
+            desc.images[1].stage = sg::ShaderStage::Fragment;
+            desc.images[1].multisampled = false;
+            desc.images[1].image_type = sg::ImageType::Dim2;
+            desc.images[1].sample_type = sg::ImageSampleType::Float;
+            desc.images[1].wgsl_group1_binding_n = 65;
+            desc.samplers[0].stage = sg::ShaderStage::Fragment;
+            desc.samplers[0].sampler_type = sg::SamplerType::Comparison;
+            desc.samplers[0].wgsl_group1_binding_n = 80;
+            desc.samplers[1].stage = sg::ShaderStage::Fragment;
+            desc.samplers[1].sampler_type = sg::SamplerType::Filtering;
+            desc.samplers[1].wgsl_group1_binding_n = 81;
+            desc.image_sampler_pairs[0].stage = sg::ShaderStage::Fragment;
+            desc.image_sampler_pairs[0].image_slot = 0;
+            desc.image_sampler_pairs[0].sampler_slot = 0;
+            desc.image_sampler_pairs[1].stage = sg::ShaderStage::Fragment;
+            desc.image_sampler_pairs[1].image_slot = 1;
+            desc.image_sampler_pairs[1].sampler_slot = 1;
+        },
+        _ => {},
+    }
+    desc
+}
+pub fn sky_shader_desc(backend: sg::Backend) -> sg::ShaderDesc {
+    let mut desc = sg::ShaderDesc::new();
+    desc.label = c"sky_shader".as_ptr();
+    match backend {
+        sg::Backend::Glcore => {
+            desc.vertex_func.source = &SKY_VERTEX_SOURCE_GLSL430 as *const _ as *const _;
+            desc.vertex_func.entry = c"main".as_ptr();
+            desc.fragment_func.source = &SKY_FRAG_SOURCE_GLSL430 as *const _ as *const _;
+            desc.fragment_func.entry = c"main".as_ptr();
+            desc.attrs[0].glsl_name = c"position".as_ptr();
+            desc.uniform_blocks[0].stage = sg::ShaderStage::Vertex;
+            desc.uniform_blocks[0].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[0].size = 64;
+            desc.uniform_blocks[0].glsl_uniforms[0]._type = sg::UniformType::Float4;
+            desc.uniform_blocks[0].glsl_uniforms[0].array_count = 4;
+            desc.uniform_blocks[0].glsl_uniforms[0].glsl_name = c"sky_vs_params".as_ptr();
+            desc.uniform_blocks[1].stage = sg::ShaderStage::Fragment;
+            desc.uniform_blocks[1].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[1].size = 48;
+            desc.uniform_blocks[1].glsl_uniforms[0]._type = sg::UniformType::Float4;
+            desc.uniform_blocks[1].glsl_uniforms[0].array_count = 3;
+            desc.uniform_blocks[1].glsl_uniforms[0].glsl_name = c"sky_fs_params".as_ptr();
+        },
+        sg::Backend::D3d11 => {
+            desc.vertex_func.source = &SKY_VERTEX_SOURCE_HLSL5 as *const _ as *const _;
+            desc.vertex_func.d3d11_target = c"vs_5_0".as_ptr();
+            desc.vertex_func.entry = c"main".as_ptr();
+            desc.fragment_func.source = &SKY_FRAG_SOURCE_HLSL5 as *const _ as *const _;
+            desc.fragment_func.d3d11_target = c"ps_5_0".as_ptr();
+            desc.fragment_func.entry = c"main".as_ptr();
+            desc.attrs[0].hlsl_sem_name = c"TEXCOORD".as_ptr();
+            desc.attrs[0].hlsl_sem_index = 0;
+            desc.uniform_blocks[0].stage = sg::ShaderStage::Vertex;
+            desc.uniform_blocks[0].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[0].size = 64;
+            desc.uniform_blocks[0].hlsl_register_b_n = 0;
+            desc.uniform_blocks[1].stage = sg::ShaderStage::Fragment;
+            desc.uniform_blocks[1].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[1].size = 48;
+            desc.uniform_blocks[1].hlsl_register_b_n = 1;
+        },
+        sg::Backend::Wgpu => {
+            desc.vertex_func.source = &SKY_VERTEX_SOURCE_WGSL as *const _ as *const _;
+            desc.vertex_func.entry = c"main".as_ptr();
+            desc.fragment_func.source = &SKY_FRAG_SOURCE_WGSL as *const _ as *const _;
+            desc.fragment_func.entry = c"main".as_ptr();
+            desc.uniform_blocks[0].stage = sg::ShaderStage::Vertex;
+            desc.uniform_blocks[0].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[0].size = 64;
+            desc.uniform_blocks[0].wgsl_group0_binding_n = 0;
+            desc.uniform_blocks[1].stage = sg::ShaderStage::Fragment;
+            desc.uniform_blocks[1].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[1].size = 48;
+            desc.uniform_blocks[1].wgsl_group0_binding_n = 1;
+        },
+        _ => {},
+    }
+    desc
+}
+pub fn line_shader_desc(backend: sg::Backend) -> sg::ShaderDesc {
+    let mut desc = sg::ShaderDesc::new();
+    desc.label = c"line_shader".as_ptr();
+    match backend {
+        sg::Backend::Glcore => {
+            desc.vertex_func.source = &LINE_VERTEX_SOURCE_GLSL430 as *const _ as *const _;
+            desc.vertex_func.entry = c"main".as_ptr();
+            desc.fragment_func.source = &LINE_FRAG_SOURCE_GLSL430 as *const _ as *const _;
+            desc.fragment_func.entry = c"main".as_ptr();
+            desc.attrs[0].glsl_name = c"position".as_ptr();
+            desc.attrs[1].glsl_name = c"v_color".as_ptr();
+            desc.uniform_blocks[0].stage = sg::ShaderStage::Vertex;
+            desc.uniform_blocks[0].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[0].size = 64;
+            desc.uniform_blocks[0].glsl_uniforms[0]._type = sg::UniformType::Float4;
+            desc.uniform_blocks[0].glsl_uniforms[0].array_count = 4;
+            desc.uniform_blocks[0].glsl_uniforms[0].glsl_name = c"line_vs_params".as_ptr();
+        },
+        sg::Backend::D3d11 => {
+            desc.vertex_func.source = &LINE_VERTEX_SOURCE_HLSL5 as *const _ as *const _;
+            desc.vertex_func.d3d11_target = c"vs_5_0".as_ptr();
+            desc.vertex_func.entry = c"main".as_ptr();
+            desc.fragment_func.source = &LINE_FRAG_SOURCE_HLSL5 as *const _ as *const _;
+            desc.fragment_func.d3d11_target = c"ps_5_0".as_ptr();
+            desc.fragment_func.entry = c"main".as_ptr();
+            desc.attrs[0].hlsl_sem_name = c"TEXCOORD".as_ptr();
+            desc.attrs[0].hlsl_sem_index = 0;
+            desc.attrs[1].hlsl_sem_name = c"TEXCOORD".as_ptr();
+            desc.attrs[1].hlsl_sem_index = 1;
+            desc.uniform_blocks[0].stage = sg::ShaderStage::Vertex;
+            desc.uniform_blocks[0].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[0].size = 64;
+            desc.uniform_blocks[0].hlsl_register_b_n = 0;
+        },
+        sg::Backend::Wgpu => {
+            desc.vertex_func.source = &LINE_VERTEX_SOURCE_WGSL as *const _ as *const _;
+            desc.vertex_func.entry = c"main".as_ptr();
+            desc.fragment_func.source = &LINE_FRAG_SOURCE_WGSL as *const _ as *const _;
+            desc.fragment_func.entry = c"main".as_ptr();
+            desc.uniform_blocks[0].stage = sg::ShaderStage::Vertex;
+            desc.uniform_blocks[0].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[0].size = 64;
+            desc.uniform_blocks[0].wgsl_group0_binding_n = 0;
+        },
+        _ => {},
+    }
+    desc
+}
+pub fn ghost_shader_desc(backend: sg::Backend) -> sg::ShaderDesc {
+    let mut desc = sg::ShaderDesc::new();
+    desc.label = c"ghost_shader".as_ptr();
+    match backend {
+        sg::Backend::Glcore => {
+            desc.vertex_func.source = &GHOST_VERTEX_SOURCE_GLSL430 as *const _ as *const _;
+            desc.vertex_func.entry = c"main".as_ptr();
+            desc.fragment_func.source = &GHOST_FRAG_SOURCE_GLSL430 as *const _ as *const _;
+            desc.fragment_func.entry = c"main".as_ptr();
+            desc.attrs[0].glsl_name = c"position".as_ptr();
+            desc.attrs[1].glsl_name = c"v_normal".as_ptr();
+            desc.uniform_blocks[0].stage = sg::ShaderStage::Vertex;
+            desc.uniform_blocks[0].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[0].size = 128;
+            desc.uniform_blocks[0].glsl_uniforms[0]._type = sg::UniformType::Float4;
+            desc.uniform_blocks[0].glsl_uniforms[0].array_count = 8;
+            desc.uniform_blocks[0].glsl_uniforms[0].glsl_name = c"ghost_vs_params".as_ptr();
+            desc.uniform_blocks[1].stage = sg::ShaderStage::Fragment;
+            desc.uniform_blocks[1].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[1].size = 32;
+            desc.uniform_blocks[1].glsl_uniforms[0]._type = sg::UniformType::Float4;
+            desc.uniform_blocks[1].glsl_uniforms[0].array_count = 2;
+            desc.uniform_blocks[1].glsl_uniforms[0].glsl_name = c"ghost_fs_params".as_ptr();
+        },
+        sg::Backend::D3d11 => {
+            desc.vertex_func.source = &GHOST_VERTEX_SOURCE_HLSL5 as *const _ as *const _;
+            desc.vertex_func.d3d11_target = c"vs_5_0".as_ptr();
+            desc.vertex_func.entry = c"main".as_ptr();
+            desc.fragment_func.source = &GHOST_FRAG_SOURCE_HLSL5 as *const _ as *const _;
+            desc.fragment_func.d3d11_target = c"ps_5_0".as_ptr();
+            desc.fragment_func.entry = c"main".as_ptr();
+            desc.attrs[0].hlsl_sem_name = c"TEXCOORD".as_ptr();
+            desc.attrs[0].hlsl_sem_index = 0;
+            desc.attrs[1].hlsl_sem_name = c"TEXCOORD".as_ptr();
+            desc.attrs[1].hlsl_sem_index = 1;
+            desc.uniform_blocks[0].stage = sg::ShaderStage::Vertex;
+            desc.uniform_blocks[0].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[0].size = 128;
+            desc.uniform_blocks[0].hlsl_register_b_n = 0;
+            desc.uniform_blocks[1].stage = sg::ShaderStage::Fragment;
+            desc.uniform_blocks[1].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[1].size = 32;
+            desc.uniform_blocks[1].hlsl_register_b_n = 1;
+        },
+        sg::Backend::Wgpu => {
+            desc.vertex_func.source = &GHOST_VERTEX_SOURCE_WGSL as *const _ as *const _;
+            desc.vertex_func.entry = c"main".as_ptr();
+            desc.fragment_func.source = &GHOST_FRAG_SOURCE_WGSL as *const _ as *const _;
+            desc.fragment_func.entry = c"main".as_ptr();
+            desc.uniform_blocks[0].stage = sg::ShaderStage::Vertex;
+            desc.uniform_blocks[0].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[0].size = 128;
+            desc.uniform_blocks[0].wgsl_group0_binding_n = 0;
+            desc.uniform_blocks[1].stage = sg::ShaderStage::Fragment;
+            desc.uniform_blocks[1].layout = sg::UniformLayout::Std140;
+            desc.uniform_blocks[1].size = 32;
+            desc.uniform_blocks[1].wgsl_group0_binding_n = 1;
+        },
+        _ => {},
+    }
+    desc
+}
@@ -0,0 +1,140 @@
+use glam as glm;
+
+use sokol::gfx;
+
+use crate::Vertex;
+use crate::lighting::Lighting;
+use crate::model::Model;
+use crate::shaders;
+
+const SIZE: i32 = 2048;
+// room around each aircraft the shadow map covers, in world units
+const MARGIN: f32 = 15.;
+// how far back along the light the depth pass looks from, and how deep it reaches, both beyond
+// the sphere around the aircraft
+const DISTANCE: f32 = 50.;
+const DEPTH: f32 = 400.;
+
+// depth of the aircraft as seen from the sun, rendered each frame before the main pass
+#[derive(Default)]
+pub struct ShadowMap {
+    pub image: gfx::Image,
+    pub sampler: gfx::Sampler,
+    pub light_view_projection: glm::Mat4,
+    attachments: gfx::Attachments,
+    pipeline: gfx::Pipeline,
+    bindings: gfx::Bindings,
+    pass_action: gfx::PassAction,
+}
+
+impl ShadowMap {
+    // `vertices` and `indices` are the aircraft buffers, laid out as `Model::vertices` and
+    // `Model::indices`
+    pub fn init(&mut self, vertices: gfx::Buffer, indices: gfx::Buffer) {
+        self.image = gfx::make_image(&gfx::ImageDesc {
+            render_target: true,
+            width: SIZE,
+            height: SIZE,
+            pixel_format: gfx::PixelFormat::Depth,
+            sample_count: 1,
+            label: c"shadow map".as_ptr(),
+            ..Default::default()
+        });
+        self.sampler = gfx::make_sampler(&gfx::SamplerDesc {
+            min_filter: gfx::Filter::Linear,
+            mag_filter: gfx::Filter::Linear,
+            wrap_u: gfx::Wrap::ClampToEdge,
+            wrap_v: gfx::Wrap::ClampToEdge,
+            compare: gfx::CompareFunc::LessEqual,
+            ..Default::default()
+        });
+        self.attachments = gfx::make_attachments(&gfx::AttachmentsDesc {
+            depth_stencil: gfx::AttachmentDesc {
+                image: self.image,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        self.pipeline = gfx::make_pipeline(&gfx::PipelineDesc {
+            shader: gfx::make_shader(&shaders::shadow_shader_desc(gfx::query_backend())),
+            layout: {
+                let mut layout = gfx::VertexLayoutState::new();
+                layout.buffers[0].stride = std::mem::size_of::<Vertex>() as i32;
+                layout.attrs[shaders::ATTR_SHADOW_POSITION].format = gfx::VertexFormat::Float3;
+                layout
+            },
+            depth: gfx::DepthState {
+                pixel_format: gfx::PixelFormat::Depth,
+                compare: gfx::CompareFunc::LessEqual,
+                write_enabled: true,
+                bias: 1.,
+                bias_slope_scale: 1.,
+                ..Default::default()
+            },
+            index_type: gfx::IndexType::Uint32,
+            color_count: 0,
+            sample_count: 1,
+            cull_mode: gfx::CullMode::None,
+            ..Default::default()
+        });
+        self.bindings.vertex_buffers[0] = vertices;
+        self.bindings.index_buffer = indices;
+        self.pass_action.depth = gfx::DepthAttachmentAction {
+            load_action: gfx::LoadAction::Clear,
+            clear_value: 1.,
+            ..Default::default()
+        };
+    }
+
+    // aims the light's orthographic projection at a square around every aircraft, wide enough for
+    // the sphere holding their `positions` whichever way the light falls
+    pub fn update(&mut self, lighting: &Lighting, positions: &[glm::Vec3]) {
+        let (low, high) = positions.iter().fold(
+            (glm::Vec3::splat(f32::MAX), glm::Vec3::splat(f32::MIN)),
+            |(low, high), &position| (low.min(position), high.max(position)),
+        );
+        let (center, extent) = if positions.is_empty() {
+            (glm::Vec3::ZERO, MARGIN)
+        } else {
+            ((low + high) / 2., (high - low).length() / 2. + MARGIN)
+        };
+        let direction = lighting.direction();
+        let up = if direction.y.abs() > 0.99 {
+            glm::Vec3::X
+        } else {
+            glm::Vec3::Y
+        };
+        let view = glm::Mat4::look_at_rh(center - direction * (DISTANCE + extent), center, up);
+        // gl clips depth to -1..1, the other backends to 0..1, see `ground_frag`
+        let projection = match gfx::query_backend() {
+            gfx::Backend::Glcore => {
+                glm::Mat4::orthographic_rh_gl(-extent, extent, -extent, extent, 0.1, DEPTH + extent)
+            }
+            _ => glm::Mat4::orthographic_rh(-extent, extent, -extent, extent, 0.1, DEPTH + extent),
+        };
+        self.light_view_projection = projection * view;
+    }
+
+    // `aircraft` places each mesh of `model` once per aircraft, see `Model::mesh_transforms`
+    pub fn draw(&self, model: &Model, aircraft: &[Vec<glm::Mat4>]) {
+        gfx::begin_pass(&gfx::Pass {
+            action: self.pass_action,
+            attachments: self.attachments,
+            ..Default::default()
+        });
+        gfx::apply_pipeline(self.pipeline);
+        gfx::apply_bindings(&self.bindings);
+        let meshes = aircraft
+            .iter()
+            .flat_map(|transforms| model.meshes.iter().zip(transforms));
+        for (mesh, transform) in meshes {
+            let params = shaders::ShadowVsParams {
+                light_model_view_projection: (self.light_view_projection * *transform)
+                    .to_cols_array(),
+            };
+            gfx::apply_uniforms(shaders::UB_SHADOW_VS_PARAMS, &gfx::value_as_range(&params));
+            gfx::draw(mesh.first, mesh.count, 1);
+        }
+        gfx::end_pass();
+    }
+}
@@ -0,0 +1,75 @@
+use glam as glm;
+
+use sokol::gfx;
+
+use crate::camera::Camera;
+use crate::lighting::Lighting;
+use crate::shaders;
+
+// the ground fades into the horizon color, so the two meet without a seam
+pub const HORIZON: [f32; 3] = [0.78, 0.85, 0.92];
+const ZENITH: [f32; 3] = [0.2, 0.42, 0.78];
+// higher is a smaller sun disc
+const SUN_SHARPNESS: f32 = 900.;
+
+#[derive(Default)]
+pub struct Sky {
+    pipeline: gfx::Pipeline,
+    bindings: gfx::Bindings,
+}
+
+impl Sky {
+    pub fn init(&mut self) {
+        // one triangle covering the whole screen
+        let vertices: [f32; 6] = [-1., -1., 3., -1., -1., 3.];
+        self.bindings.vertex_buffers[0] = gfx::make_buffer(&gfx::BufferDesc {
+            data: gfx::slice_as_range(&vertices),
+            label: c"sky verts".as_ptr(),
+            ..Default::default()
+        });
+        self.pipeline = gfx::make_pipeline(&gfx::PipelineDesc {
+            shader: gfx::make_shader(&shaders::sky_shader_desc(gfx::query_backend())),
+            layout: {
+                let mut layout = gfx::VertexLayoutState::new();
+                layout.attrs[shaders::ATTR_SKY_POSITION].format = gfx::VertexFormat::Float2;
+                layout
+            },
+            // drawn first and never written to depth, everything else lands in front of it
+            depth: gfx::DepthState {
+                write_enabled: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+    }
+
+    pub fn draw(&self, camera: &Camera, lighting: &Lighting) {
+        // the sky is infinitely far away, so only the camera's rotation matters
+        let rotation = glm::Mat4::look_to_rh(glm::Vec3::ZERO, camera.front, camera.up);
+        let vs_params = shaders::SkyVsParams {
+            inverse_view_projection: (camera.projection_matrix() * rotation)
+                .inverse()
+                .to_cols_array(),
+        };
+        let fs_params = shaders::SkyFsParams {
+            zenith_color: ZENITH,
+            sun_sharpness: SUN_SHARPNESS,
+            horizon_color: HORIZON,
+            sun_intensity: 1.,
+            sun_direction: (-lighting.direction()).to_array(),
+            _pad_44: [0; 4],
+        };
+        gfx::apply_pipeline(self.pipeline);
+        gfx::apply_bindings(&self.bindings);
+        gfx::apply_uniforms(shaders::UB_SKY_VS_PARAMS, &gfx::value_as_range(&vs_params));
+        gfx::apply_uniforms(shaders::UB_SKY_FS_PARAMS, &gfx::value_as_range(&fs_params));
+        gfx::draw(0, 3, 1);
+    }
+}
+
+// what `sky_frag` draws looking along `direction`, for the software renderer
+pub fn color(direction: glm::Vec3, sun_direction: glm::Vec3) -> glm::Vec3 {
+    let sky = glm::Vec3::from(HORIZON).lerp(glm::Vec3::from(ZENITH), direction.y.max(0.).sqrt());
+    let sun = direction.dot(sun_direction).max(0.).powf(SUN_SHARPNESS);
+    sky + glm::Vec3::splat(sun)
+}
@@ -0,0 +1,91 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use glam as glm;
+use serde::Deserialize;
+
+use sandbox_core::simulation::Simulation;
+
+use crate::mapping::StateMapping;
+use crate::model::Model;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Axis {
+    Pitch,
+    Roll,
+    Yaw,
+}
+
+// a moving part, turned right-handedly about the hinge line running from its first point to its
+// second, in the coordinates of its node. it turns by `gain` times the command on `axis`, up to
+// `limit` degrees either way
+#[derive(Debug, Deserialize)]
+struct Surface {
+    node: String,
+    axis: Axis,
+    hinge: [[f32; 3]; 2],
+    #[serde(default = "unit_gain")]
+    gain: f32,
+    limit: f32,
+}
+
+fn unit_gain() -> f32 {
+    1.
+}
+
+#[derive(Debug, Deserialize)]
+struct SurfaceFile {
+    // command, in plant force units, that takes a surface with a gain of 1 to its limit
+    full_scale: f32,
+    surfaces: Vec<Surface>,
+}
+
+// elevators, ailerons, rudders and flaps of the model, deflected every frame by the controller's
+// command. they are described in `<model>.surfaces.json` next to the model file
+#[derive(Default)]
+pub struct ControlSurfaces {
+    // the node each surface moves
+    surfaces: Vec<(usize, Surface)>,
+    full_scale: f32,
+}
+
+impl ControlSurfaces {
+    // none when the model has no sidecar file
+    pub fn load(model_path: &Path, model: &Model) -> Result<ControlSurfaces, Box<dyn Error>> {
+        let path = model_path.with_extension("surfaces.json");
+        if !path.exists() {
+            return Ok(ControlSurfaces::default());
+        }
+        let file: SurfaceFile = serde_json::from_str(&fs::read_to_string(&path)?)?;
+        let mut surfaces = Vec::with_capacity(file.surfaces.len());
+        for surface in file.surfaces {
+            let node = model.find_node(&surface.node).ok_or(format!(
+                "{}: the model has no part `{}`",
+                path.display(),
+                surface.node
+            ))?;
+            surfaces.push((node, surface));
+        }
+        Ok(ControlSurfaces {
+            surfaces,
+            full_scale: file.full_scale,
+        })
+    }
+
+    // poses the surfaces of `model` for the force the controller currently commands
+    pub fn update(&self, simulation: &Simulation, mapping: &StateMapping, model: &mut Model) {
+        let force = simulation.control.force * simulation.plant.control_gain;
+        // a faulted controller leaves the surfaces centered
+        let force = if force.is_finite() { force } else { 0. };
+        for (node, surface) in &self.surfaces {
+            let command = mapping.surface_command(surface.axis, force) / self.full_scale;
+            let angle = (command * surface.gain).clamp(-1., 1.) * surface.limit;
+            let [from, to] = surface.hinge.map(glm::Vec3::from);
+            model.nodes[*node].pose = glm::Mat4::from_translation(from)
+                * glm::Mat4::from_axis_angle((to - from).normalize(), angle.to_radians())
+                * glm::Mat4::from_translation(-from);
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::VecDeque;
+
+use glam as glm;
+
+use sokol::app as sap;
+use sokol::gfx;
+
+use crate::camera::{Camera, Inputs};
+use crate::lighting::Lighting;
+use crate::model::Model;
+use crate::shaders;
+use crate::{Transform, Vertex};
+
+// one point per rendered frame, about half a minute at 60 fps
+const TRAIL_POINTS: usize = 2048;
+// the reference path also runs this far ahead of the aircraft
+const AHEAD: f32 = 40.;
+
+// one per vehicle, in the order of their tints
+const TRAIL_COLORS: [[f32; 4]; 5] = [
+    [1., 0.55, 0.15, 1.],
+    [1., 0.3, 0.3, 1.],
+    [0.35, 0.55, 1., 1.],
+    [0.4, 0.95, 0.4, 1.],
+    [1., 0.9, 0.3, 1.],
+];
+const REFERENCE_COLOR: [f32; 4] = [0.35, 0.85, 1., 0.8];
+const GHOST_COLOR: [f32; 4] = [0.35, 0.85, 1., 0.35];
+
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+struct LineVertex {
+    position: [f32; 3],
+    color: [f32; 4],
+}
+
+// where each aircraft and the reference were, along with the distance flown at the time
+struct TrailPoint {
+    travel: f32,
+    aircraft: Vec<glm::Vec3>,
+    reference: glm::Vec3,
+}
+
+// the flown paths as fading lines, the commanded one as a line and a translucent ghost aircraft
+pub struct Trails {
+    pub visible: bool,
+    points: VecDeque<TrailPoint>,
+    // how many paths the vertex buffer has room for
+    aircraft: usize,
+    vertices: Vec<LineVertex>,
+    line_pipeline: gfx::Pipeline,
+    line_bindings: gfx::Bindings,
+    ghost_pipeline: gfx::Pipeline,
+    ghost_bindings: gfx::Bindings,
+}
+
+impl Default for Trails {
+    fn default() -> Trails {
+        Trails {
+            visible: true,
+            points: VecDeque::with_capacity(TRAIL_POINTS),
+            aircraft: 1,
+            vertices: Vec::new(),
+            line_pipeline: gfx::Pipeline::default(),
+            line_bindings: gfx::Bindings::default(),
+            ghost_pipeline: gfx::Pipeline::default(),
+            ghost_bindings: gfx::Bindings::default(),
+        }
+    }
+}
+
+fn blended() -> [gfx::ColorTargetState; 4] {
+    let mut colors = [gfx::ColorTargetState::default(); 4];
+    colors[0].blend = gfx::BlendState {
+        enabled: true,
+        src_factor_rgb: gfx::BlendFactor::SrcAlpha,
+        dst_factor_rgb: gfx::BlendFactor::OneMinusSrcAlpha,
+        ..Default::default()
+    };
+    colors
+}
+
+impl Trails {
+    // `vertices` and `indices` are the aircraft buffers, laid out as `Model::vertices` and
+    // `Model::indices`. there is a path for each of the `aircraft`
+    pub fn init(&mut self, vertices: gfx::Buffer, indices: gfx::Buffer, aircraft: usize) {
+        // the paths and reference points plus the one ahead
+        let max_vertices = (aircraft + 1) * TRAIL_POINTS + 1;
+        self.aircraft = aircraft;
+        self.vertices = Vec::with_capacity(max_vertices);
+        self.line_bindings.vertex_buffers[0] = gfx::make_buffer(&gfx::BufferDesc {
+            size: max_vertices * std::mem::size_of::<LineVertex>(),
+            usage: gfx::Usage::Stream,
+            label: c"trail verts".as_ptr(),
+            ..Default::default()
+        });
+        // translucent, so tested against the scene's depth but never written to it
+        let depth = gfx::DepthState {
+            compare: gfx::CompareFunc::LessEqual,
+            ..Default::default()
+        };
+        self.line_pipeline = gfx::make_pipeline(&gfx::PipelineDesc {
+            shader: gfx::make_shader(&shaders::line_shader_desc(gfx::query_backend())),
+            layout: {
+                let mut layout = gfx::VertexLayoutState::new();
+                layout.attrs[shaders::ATTR_LINE_POSITION].format = gfx::VertexFormat::Float3;
+                layout.attrs[shaders::ATTR_LINE_V_COLOR].format = gfx::VertexFormat::Float4;
+                layout
+            },
+            primitive_type: gfx::PrimitiveType::LineStrip,
+            colors: blended(),
+            depth,
+            ..Default::default()
+        });
+
+        self.ghost_bindings.vertex_buffers[0] = vertices;
+        self.ghost_bindings.index_buffer = indices;
+        self.ghost_pipeline = gfx::make_pipeline(&gfx::PipelineDesc {
+            shader: gfx::make_shader(&shaders::ghost_shader_desc(gfx::query_backend())),
+            layout: {
+                let mut layout = gfx::VertexLayoutState::new();
+                layout.buffers[0].stride = std::mem::size_of::<Vertex>() as i32;
+                layout.attrs[shaders::ATTR_GHOST_POSITION].format = gfx::VertexFormat::Float3;
+                layout.attrs[shaders::ATTR_GHOST_V_NORMAL].format = gfx::VertexFormat::Float3;
+                layout
+            },
+            colors: blended(),
+            depth,
+            index_type: gfx::IndexType::Uint32,
+            cull_mode: gfx::CullMode::None,
+            ..Default::default()
+        });
+    }
+
+    pub fn handle_inputs(&mut self, inputs: &mut Inputs) {
+        if inputs.take_press(sap::Keycode::T) {
+            self.visible = !self.visible;
+        }
+    }
+
+    // `travel` is how far the aircraft have flown along +x when they were at `aircraft`, in the
+    // order of the vehicles
+    pub fn push(&mut self, travel: f32, aircraft: Vec<glm::Vec3>, reference: &Transform) {
+        if self.points.len() == TRAIL_POINTS {
+            self.points.pop_front();
+        }
+        self.points.push_back(TrailPoint {
+            travel,
+            aircraft,
+            reference: reference.position,
+        });
+    }
+
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+
+    // fills the vertex buffer, once a frame before any `draw`. `reference` is where the setpoint
+    // puts the aircraft now
+    pub fn update(&mut self, travel: f32, reference: &Transform) {
+        if !self.visible {
+            return;
+        }
+        // the world slides back as the aircraft flies on, older points with it
+        let behind = |point: &TrailPoint, position: glm::Vec3| {
+            position + glm::Vec3::X * (point.travel - travel)
+        };
+        let count = self.points.len();
+        self.vertices.clear();
+        for aircraft in 0..self.aircraft {
+            let [r, g, b, a] = TRAIL_COLORS[aircraft % TRAIL_COLORS.len()];
+            for (i, point) in self.points.iter().enumerate() {
+                let fade = (i + 1) as f32 / count as f32;
+                let position = point
+                    .aircraft
+                    .get(aircraft)
+                    .copied()
+                    .unwrap_or(point.reference);
+                self.vertices.push(LineVertex {
+                    position: behind(point, position).to_array(),
+                    color: [r, g, b, a * fade],
+                });
+            }
+        }
+        for point in &self.points {
+            self.vertices.push(LineVertex {
+                position: behind(point, point.reference).to_array(),
+                color: REFERENCE_COLOR,
+            });
+        }
+        self.vertices.push(LineVertex {
+            position: (reference.position + reference.rotation * glm::Vec3::X * AHEAD).to_array(),
+            color: REFERENCE_COLOR,
+        });
+        gfx::update_buffer(
+            self.line_bindings.vertex_buffers[0],
+            &gfx::slice_as_range(&self.vertices),
+        );
+    }
+
+    // draws into the current pass after the opaque geometry, `reference` as given to `update`
+    pub fn draw(&self, camera: &Camera, reference: &Transform, model: &Model, lighting: &Lighting) {
+        if !self.visible {
+            return;
+        }
+        let view_projection = camera.projection_matrix() * camera.view_matrix();
+        let count = self.points.len();
+
+        gfx::apply_pipeline(self.line_pipeline);
+        gfx::apply_bindings(&self.line_bindings);
+        let vs_params = shaders::LineVsParams {
+            view_projection: view_projection.to_cols_array(),
+        };
+        gfx::apply_uniforms(shaders::UB_LINE_VS_PARAMS, &gfx::value_as_range(&vs_params));
+        for aircraft in 0..self.aircraft {
+            gfx::draw(aircraft * count, count, 1);
+        }
+        gfx::draw(self.aircraft * count, count + 1, 1);
+
+        gfx::apply_pipeline(self.ghost_pipeline);
+        gfx::apply_bindings(&self.ghost_bindings);
+        let fs_params = shaders::GhostFsParams {
+            ghost_color: GHOST_COLOR,
+            light_direction: lighting.direction().to_array(),
+            ambient: lighting.ambient,
+        };
+        gfx::apply_uniforms(
+            shaders::UB_GHOST_FS_PARAMS,
+            &gfx::value_as_range(&fs_params),
+        );
+        for (mesh, transform) in model.meshes.iter().zip(model.mesh_transforms(reference)) {
+            let vs_params = shaders::GhostVsParams {
+                model: transform.to_cols_array(),
+                view_projection: view_projection.to_cols_array(),
+            };
+            gfx::apply_uniforms(
+                shaders::UB_GHOST_VS_PARAMS,
+                &gfx::value_as_range(&vs_params),
+            );
+            gfx::draw(mesh.first, mesh.count, 1);
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use glam as glm;
+
+use sandbox_core::simulation::Simulation;
+use sokol::app as sap;
+use sokol::debugtext as sdtx;
+use sokol::gl as sgl;
+
+use crate::Transform;
+use crate::camera::{Camera, Inputs};
+use crate::mapping::StateMapping;
+
+// size of a debugtext character cell in pixels, as in the hud
+const CELL: f32 = 16.;
+// arrows longer than this are clipped, the label still shows the true magnitude
+const MAX_LENGTH: f32 = 15.;
+// sideways spacing between force arrows that act along the same axis
+const SPREAD: f32 = 0.8;
+const HEAD: f32 = 0.6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorKind {
+    // commanded force after the plant's control gain
+    Control,
+    // the plant's constant bias force, the stand-in for gravity or trim
+    Bias,
+    // the random disturbance, the stand-in for wind gusts
+    Disturbance,
+    // everything above, what actually accelerates the plant
+    Net,
+    Velocity,
+}
+
+impl VectorKind {
+    pub const ALL: [VectorKind; 5] = [
+        VectorKind::Control,
+        VectorKind::Bias,
+        VectorKind::Disturbance,
+        VectorKind::Net,
+        VectorKind::Velocity,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            VectorKind::Control => "control",
+            VectorKind::Bias => "bias",
+            VectorKind::Disturbance => "disturbance",
+            VectorKind::Net => "net force",
+            VectorKind::Velocity => "velocity",
+        }
+    }
+
+    fn color(self) -> [f32; 3] {
+        match self {
+            VectorKind::Control => [1., 0.55, 0.15],
+            VectorKind::Bias => [0.75, 0.45, 1.],
+            VectorKind::Disturbance => [0.3, 0.85, 1.],
+            VectorKind::Net => [1., 1., 1.],
+            VectorKind::Velocity => [0.3, 1., 0.45],
+        }
+    }
+}
+
+// arrows at the aircraft for the forces acting on the plant and its velocity
+pub struct Vectors {
+    pub visible: bool,
+    pub enabled: [bool; VectorKind::ALL.len()],
+    // world units per unit of plant force, and per world unit per second
+    pub force_scale: f32,
+    pub velocity_scale: f32,
+}
+
+impl Default for Vectors {
+    fn default() -> Vectors {
+        Vectors {
+            visible: true,
+            enabled: [true; VectorKind::ALL.len()],
+            force_scale: 0.05,
+            velocity_scale: 0.5,
+        }
+    }
+}
+
+impl Vectors {
+    pub fn handle_inputs(&mut self, inputs: &mut Inputs) {
+        if inputs.take_press(sap::Keycode::V) {
+            self.visible = !self.visible;
+        }
+    }
+
+    // queues sokol-gl and debugtext commands, drawn by the `sgl::draw` and `sdtx::draw` of the
+    // pass. arrows are not depth tested so the model never hides them. `column` is the left edge
+    // and width of the viewport the camera draws into
+    pub fn draw(
+        &self,
+        camera: &Camera,
+        column: (f32, f32),
+        height: f32,
+        simulation: &Simulation,
+        mapping: &StateMapping,
+        transform: &Transform,
+    ) {
+        if !self.visible {
+            return;
+        }
+        let (left, width) = column;
+        let (origin, axis) = mapping.force_axis(transform);
+        let control = simulation.control.force * simulation.plant.control_gain;
+        let bias = simulation.plant.bias_force;
+        let disturbance = simulation.disturbance;
+
+        sgl::defaults();
+        sgl::load_default_pipeline();
+        sgl::matrix_mode_projection();
+        sgl::perspective(camera.fov, camera.aspect_ratio, camera.near, camera.far);
+        sgl::matrix_mode_modelview();
+        let center = camera.position + camera.front;
+        sgl::lookat(
+            camera.position.x,
+            camera.position.y,
+            camera.position.z,
+            center.x,
+            center.y,
+            center.z,
+            camera.up.x,
+            camera.up.y,
+            camera.up.z,
+        );
+        sdtx::canvas(sap::widthf() / 2., height / 2.);
+        sdtx::origin(0., 0.);
+
+        let view_projection = camera.projection_matrix() * camera.view_matrix();
+        let forces = VectorKind::ALL.len() as f32 - 1.;
+        for (i, kind) in VectorKind::ALL.into_iter().enumerate() {
+            if !self.enabled[i] {
+                continue;
+            }
+            let side = camera.right * (i as f32 - (forces - 1.) / 2.) * SPREAD;
+            let (from, vector, magnitude) = match kind {
+                VectorKind::Control => (origin + side, axis * control, control),
+                VectorKind::Bias => (origin + side, axis * bias, bias),
+                VectorKind::Disturbance => (origin + side, axis * disturbance, disturbance),
+                VectorKind::Net => {
+                    let net = control + bias + disturbance;
+                    (origin + side, axis * net, net)
+                }
+                VectorKind::Velocity => {
+                    let velocity = mapping.velocity(&simulation.state);
+                    (transform.position, velocity, velocity.length())
+                }
+            };
+            let scale = match kind {
+                VectorKind::Velocity => self.velocity_scale,
+                _ => self.force_scale,
+            };
+            let vector = (vector * scale).clamp_length_max(MAX_LENGTH);
+            if vector.length_squared() < 1e-6 {
+                continue;
+            }
+            let tip = from + vector;
+            arrow(from, tip, camera.front, kind.color());
+
+            // label next to the tip, unless it is behind the camera
+            let clip = view_projection * tip.extend(1.);
+            if clip.w > 0. {
+                let ndc = clip.truncate() / clip.w;
+                let x = left + (ndc.x * 0.5 + 0.5) * width;
+                let y = (0.5 - ndc.y * 0.5) * height;
+                let [r, g, b] = kind.color();
+                sdtx::color3f(r, g, b);
+                sdtx::pos(x / CELL + 0.5, y / CELL - 0.5);
+                sdtx::puts(&format!("{} {magnitude:.2}", kind.name()));
+            }
+        }
+    }
+}
+
+// a shaft with a head facing the camera
+fn arrow(from: glm::Vec3, tip: glm::Vec3, view: glm::Vec3, [r, g, b]: [f32; 3]) {
+    let vector = tip - from;
+    let length = vector.length();
+    let direction = vector / length;
+    let head = HEAD.min(length * 0.5);
+    let side = direction.cross(view).normalize_or_zero() * head * 0.5;
+    let back = tip - direction * head;
+    sgl::begin_lines();
+    sgl::c3f(r, g, b);
+    for (start, end) in [(from, tip), (tip, back + side), (tip, back - side)] {
+        sgl::v3f(start.x, start.y, start.z);
+        sgl::v3f(end.x, end.y, end.z);
+    }
+    sgl::end();
+}
@@ -1,13 +1,4 @@
-typedef struct State {
-    float position;
-    float velocity;
-    int tick;
-    float delta_time;
-} State;
-
-typedef struct Control {
-    float force;
-} Control;
+#include "sandbox.h"
 
 Control controller(const State *state) {
     Control controller;
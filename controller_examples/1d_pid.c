@@ -1,22 +1,13 @@
-typedef struct State {
-    float position;
-    float velocity;
-    int tick;
-    float delta_time;
-} State;
-
-typedef struct Control {
-    float force;
-} Control;
+#include "sandbox.h"
 
 static float integral = 0.;
 
 Control controller(const State *state) {
     Control controller = {.force = 0};
 
-    float kp = 7.;
-    float kd = 3.;
-    float ki = 0.5;
+    float kp = sandbox_tunable("kp", 7., 0., 20.);
+    float kd = sandbox_tunable("kd", 3., 0., 10.);
+    float ki = sandbox_tunable("ki", 0.5, 0., 5.);
 
     if (state->tick % 100 == 0) {
         integral = 0;
@@ -27,6 +18,8 @@ Control controller(const State *state) {
     controller.force += -kd * state->velocity;
     controller.force += -ki * integral;
 
+    sandbox_publish("integral", integral);
+
     return controller;
 }
 
@@ -0,0 +1,38 @@
+#ifndef SANDBOX_H
+#define SANDBOX_H
+
+#include <stddef.h>
+
+// what a controller sees of the plant every tick. the sandbox hands it over by pointer, so the
+// layout has to stay in step with `State` in backend/sandbox-core/src/simulation.rs
+typedef struct State {
+    float position;
+    float velocity;
+    float acceleration;
+    int tick;
+    float delta_time;
+} State;
+
+_Static_assert(offsetof(State, tick) == 12 && offsetof(State, delta_time) == 16 &&
+                   sizeof(State) == 20,
+               "State does not match the sandbox's");
+
+// what a controller returns every tick
+typedef struct Control {
+    float force;
+} Control;
+
+// the function every controller defines
+Control controller(const State *state);
+
+// functions the sandbox provides to controllers
+
+// shows `value` under `name` in the viewer's plots, call it every tick with the same names
+void sandbox_publish(const char *name, float value);
+
+// current value of the tunable parameter `name`, `initial` until it is changed from a scenario
+// file, `--set name=value` or the viewer's slider, which spans `min` to `max`. call it every tick
+// instead of hard-coding the constant
+float sandbox_tunable(const char *name, float initial, float min, float max);
+
+#endif